@@ -4,13 +4,16 @@ use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 use fixnum::{ops::*, FixedPoint};
 
+#[cfg(feature = "i32")]
+type F32p4 = FixedPoint<i32, typenum::U4>;
 #[cfg(feature = "i64")]
 type F64p9 = FixedPoint<i64, typenum::U9>;
 #[cfg(feature = "i128")]
 type F128p18 = FixedPoint<i128, typenum::U18>;
 
 macro_rules! define_bench {
-    ($fp:tt) => {
+    // `$dividend` is the `~1e5` dividend of `rdiv`, which fits the layout.
+    ($fp:tt, $dividend:literal) => {
         #[allow(non_snake_case)]
         fn $fp(c: &mut Criterion) {
             let mut group = c.benchmark_group(stringify!($fp));
@@ -40,7 +43,7 @@ macro_rules! define_bench {
 
             let mut rdiv = |mode| {
                 group.bench_function(format!("rdiv (~1e5/~1e4, {:?})", mode), |b| {
-                    let lhs = black_box($fp::try_from(987656i32).unwrap());
+                    let lhs = black_box($fp::try_from($dividend).unwrap());
                     let rhs = black_box($fp::try_from(54321i32).unwrap());
                     b.iter(move || lhs.rdiv(rhs, mode))
                 });
@@ -128,6 +131,16 @@ macro_rules! define_bench {
                 b.iter(move || $fp::try_from(value))
             });
 
+            group.bench_function("from_str (12345)", |b| {
+                let value = black_box("12345");
+                b.iter(move || value.parse::<$fp>())
+            });
+
+            group.bench_function("from_str (12.345)", |b| {
+                let value = black_box("12.345");
+                b.iter(move || value.parse::<$fp>())
+            });
+
             group.bench_function("from_decimal(12345, -3)", |b| {
                 let decimal = black_box((12345, -3));
                 b.iter(move || $fp::from_decimal(decimal.0, decimal.1))
@@ -148,16 +161,21 @@ macro_rules! define_bench {
     };
 }
 
+#[cfg(feature = "i32")]
+define_bench!(F32p4, 98765i32);
 #[cfg(feature = "i64")]
-define_bench!(F64p9);
+define_bench!(F64p9, 987656i32);
 #[cfg(feature = "i128")]
-define_bench!(F128p18);
-
-#[cfg(all(feature = "i64", feature = "i128"))]
-criterion_group!(benches, F64p9, F128p18);
-#[cfg(not(feature = "i128"))]
-criterion_group!(benches, F64p9);
-#[cfg(not(feature = "i64"))]
-criterion_group!(benches, F128p18);
+define_bench!(F128p18, 987656i32);
+
+fn layouts(c: &mut Criterion) {
+    #[cfg(feature = "i32")]
+    F32p4(c);
+    #[cfg(feature = "i64")]
+    F64p9(c);
+    #[cfg(feature = "i128")]
+    F128p18(c);
+}
 
+criterion_group!(benches, layouts);
 criterion_main!(benches);
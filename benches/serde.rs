@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use fixnum::{fixnum, ops::*, FixedPoint};
 
+#[cfg(feature = "i32")]
+type F32p4 = FixedPoint<i32, typenum::U4>;
 #[cfg(feature = "i64")]
 type F64p9 = FixedPoint<i64, typenum::U9>;
 #[cfg(feature = "i128")]
@@ -103,16 +105,21 @@ macro_rules! define_bench {
     };
 }
 
+#[cfg(feature = "i32")]
+define_bench!(F32p4, 4);
 #[cfg(feature = "i64")]
 define_bench!(F64p9, 9);
 #[cfg(feature = "i128")]
 define_bench!(F128p18, 18);
 
-#[cfg(all(feature = "i64", feature = "i128"))]
-criterion_group!(benches, F64p9, F128p18);
-#[cfg(not(feature = "i128"))]
-criterion_group!(benches, F64p9);
-#[cfg(not(feature = "i64"))]
-criterion_group!(benches, F128p18);
+fn layouts(c: &mut Criterion) {
+    #[cfg(feature = "i32")]
+    F32p4(c);
+    #[cfg(feature = "i64")]
+    F64p9(c);
+    #[cfg(feature = "i128")]
+    F128p18(c);
+}
 
+criterion_group!(benches, layouts);
 criterion_main!(benches);
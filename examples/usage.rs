@@ -22,6 +22,7 @@ impl_op!(Price [csub] Price = PriceDelta);
 impl_op!(Price [cadd] PriceDelta = Price);
 impl_op!(Price [rdiv] Price = Ratio);
 impl_op!(Price [rmul] Ratio = Price);
+impl_op!(Price [rmul_div] Ratio, Ratio = Price);
 impl_op!(PriceDelta [cadd] PriceDelta = PriceDelta);
 impl_op!(Amount [cadd] Amount = Amount);
 impl_op!(Amount [csub] Amount = Amount);
@@ -43,5 +44,8 @@ fn main() -> Result<(), ArithmeticError> {
     let half = price.rmul(fp!(0.5), RoundMode::Ceil)?;
     assert_eq!(half, fp!(2.125));
 
+    let scaled = price.rmul_div(Ratio(fp!(3)), Ratio(fp!(4)), RoundMode::Floor)?;
+    assert_eq!(scaled, Price(fp!(3.1875)));
+
     Ok(())
 }
@@ -0,0 +1,149 @@
+//! Measurement of rounding errors of fixed-point formulas.
+//!
+//! [`rounding_error`] evaluates an operation on many inputs and compares every result with
+//! a reference formula computed in [`Reference`], which has 38 decimal places and covers values
+//! of all primitive layouts exactly. Thus, errors of the reference itself are negligible
+//! comparing to `EPSILON` of the measured type, unless the reference is rounded a lot (e.g. by
+//! division of numbers with a very long period), so it can be used to validate error budgets of
+//! composed formulas.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{analysis, FixedPoint, typenum::U9, ops::{RoundingMul, RoundMode::*}};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! let inputs = (1..1000).map(|i| [Amount::from_bits(i * 7_777_777), Amount::from_bits(i * 3)]);
+//! let distribution = analysis::rounding_error(
+//!     |[a, b]| a.rmul(b, Nearest),
+//!     |[a, b]| a.rmul(b, Nearest),
+//!     inputs,
+//! );
+//!
+//! assert_eq!((distribution.measured, distribution.failed), (999, 0));
+//! // The error of rounding to nearest doesn't exceed a half of `EPSILON`.
+//! assert!(distribution.max_abs() <= "0.0000000005".parse()?);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use core::cmp;
+
+use typenum::{Unsigned, U38};
+
+use crate::{
+    ops::{Bounded, CheckedAdd, CheckedSub, RoundMode, RoundingDiv, Zero},
+    FixedPoint, Precision, I256,
+};
+
+/// The type used to compute reference values.
+pub type Reference = FixedPoint<I256, U38>;
+
+/// The distribution of signed errors `result - reference`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Distribution {
+    /// The number of inputs, for which both the operation and the reference succeeded.
+    pub measured: u64,
+    /// The number of inputs, for which the operation or the reference failed,
+    /// or the result doesn't fit [`Reference`].
+    pub failed: u64,
+    /// The minimal error, `ZERO` if nothing is measured.
+    pub min: Reference,
+    /// The maximal error, `ZERO` if nothing is measured.
+    pub max: Reference,
+    /// The saturating sum of errors.
+    pub sum: Reference,
+}
+
+impl Distribution {
+    /// Returns the maximal absolute error.
+    pub fn max_abs(&self) -> Reference {
+        let min = self.min.abs().unwrap_or(Reference::MAX);
+        cmp::max(min, self.max)
+    }
+
+    /// Returns the mean error rounded to nearest, i.e. the bias of the operation.
+    /// Returns `None` if nothing is measured.
+    pub fn mean(&self) -> Option<Reference> {
+        let measured = Reference::try_from(self.measured).ok()?;
+        self.sum.rdiv(measured, RoundMode::Nearest).ok()
+    }
+
+    fn record(&mut self, error: Reference) {
+        if self.measured == 0 {
+            self.min = error;
+            self.max = error;
+        } else {
+            self.min = cmp::min(self.min, error);
+            self.max = cmp::max(self.max, error);
+        }
+        self.measured += 1;
+        self.sum = self.sum.saturating_add(error);
+    }
+}
+
+impl Default for Distribution {
+    fn default() -> Self {
+        Self {
+            measured: 0,
+            failed: 0,
+            min: Reference::ZERO,
+            max: Reference::ZERO,
+            sum: Reference::ZERO,
+        }
+    }
+}
+
+/// Converts the value to [`Reference`] exactly.
+/// Returns `None` if it has more than 38 decimal places or doesn't fit.
+pub fn to_reference<I, P>(value: FixedPoint<I, P>) -> Option<Reference>
+where
+    I: Into<I256>,
+    P: Precision,
+{
+    let scale = U38::U32.checked_sub(P::U32)?;
+    let coef = I256::from(10u8).checked_pow(scale)?;
+    let bits = value.into_bits().into().checked_mul(coef)?;
+    Some(Reference::from_bits(bits))
+}
+
+/// Evaluates `op` and `reference` on every input and collects the distribution of errors.
+///
+/// Inputs are converted to [`Reference`] exactly before passing to `reference`.
+/// Inputs, on which either `op` or `reference` fails, are counted in [`Distribution::failed`].
+pub fn rounding_error<I, P, E1, E2, const N: usize>(
+    op: impl Fn([FixedPoint<I, P>; N]) -> Result<FixedPoint<I, P>, E1>,
+    reference: impl Fn([Reference; N]) -> Result<Reference, E2>,
+    inputs: impl IntoIterator<Item = [FixedPoint<I, P>; N]>,
+) -> Distribution
+where
+    I: Into<I256>,
+    P: Precision,
+    FixedPoint<I, P>: Copy,
+{
+    let mut distribution = Distribution::default();
+
+    for input in inputs {
+        let error = (|| {
+            let result = to_reference(op(input).ok()?)?;
+
+            let mut converted = [Reference::ZERO; N];
+            for (converted, value) in converted.iter_mut().zip(input) {
+                *converted = to_reference(value)?;
+            }
+            let expected = reference(converted).ok()?;
+
+            result.csub(expected).ok()
+        })();
+
+        match error {
+            Some(error) => distribution.record(error),
+            None => distribution.failed += 1,
+        }
+    }
+
+    distribution
+}
@@ -0,0 +1,202 @@
+//! Auditing of conversions between `f64` and fixed-point numbers.
+//!
+//! It's intended for migrating codebases from floats: every successful `TryFrom<f64>` and
+//! `From<FixedPoint> for f64` conversion is counted and passed to the installed hook, so lossy
+//! boundaries can be found systematically instead of by code review.
+//!
+//! A conversion is lossy if the round trip doesn't restore the original value:
+//! * `f64 → FixedPoint → f64` for [`Direction::FromF64`],
+//! * `FixedPoint → f64 → FixedPoint` for [`Direction::ToF64`].
+//!
+//! The loss is the absolute difference between the original value and the restored one.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{audit, FixedPoint, typenum::U2};
+//!
+//! type Price = FixedPoint<i64, U2>;
+//!
+//! audit::set_hook(|conversion| {
+//!     if conversion.is_lossy() {
+//!         eprintln!("lossy conversion: {:?}", conversion);
+//!     }
+//! });
+//!
+//! let exact = Price::try_from(0.25)?;
+//! let lossy = Price::try_from(0.125)?;
+//! assert_eq!((exact, lossy), ("0.25".parse()?, "0.13".parse()?));
+//!
+//! let summary = audit::summary();
+//! assert!(summary.from_f64.total >= 2);
+//! assert!(summary.from_f64.lossy >= 1);
+//! println!("{}", summary);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        RwLock,
+    },
+};
+
+/// The direction of an audited conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// `TryFrom<f64> for FixedPoint`.
+    FromF64,
+    /// `From<FixedPoint> for f64`.
+    ToF64,
+}
+
+/// An audited conversion, passed to the hook.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct Conversion {
+    /// The direction of the conversion.
+    pub direction: Direction,
+    /// The precision of the fixed-point type.
+    pub precision: i32,
+    /// The float side of the conversion: the source for `FromF64` and the result for `ToF64`.
+    pub float: f64,
+    /// The absolute difference between the original and round-tripped values.
+    pub loss: f64,
+}
+
+impl Conversion {
+    /// Returns `true` if the round trip doesn't restore the original value.
+    #[inline]
+    pub fn is_lossy(&self) -> bool {
+        self.loss != 0.
+    }
+}
+
+/// Statistics of conversions in one direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Stats {
+    /// The number of conversions.
+    pub total: u64,
+    /// The number of lossy conversions.
+    pub lossy: u64,
+    /// The maximum loss.
+    pub max_loss: f64,
+}
+
+/// Statistics of all conversions since the start or the last [`reset`].
+///
+/// `Display` prints a human-readable report.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Summary {
+    /// Statistics of `TryFrom<f64>`.
+    pub from_f64: Stats,
+    /// Statistics of `From<FixedPoint> for f64`.
+    pub to_f64: Stats,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, stats) in [
+            ("f64 → fixed", &self.from_f64),
+            ("fixed → f64", &self.to_f64),
+        ] {
+            writeln!(
+                f,
+                "{}: {} conversions, {} lossy, max loss {}",
+                name, stats.total, stats.lossy, stats.max_loss
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct Counters {
+    total: AtomicU64,
+    lossy: AtomicU64,
+    // Bits of a non-negative `f64`, which are ordered the same way as the numbers.
+    max_loss: AtomicU64,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            total: AtomicU64::new(0),
+            lossy: AtomicU64::new(0),
+            max_loss: AtomicU64::new(0),
+        }
+    }
+
+    fn stats(&self) -> Stats {
+        Stats {
+            total: self.total.load(Ordering::Relaxed),
+            lossy: self.lossy.load(Ordering::Relaxed),
+            max_loss: f64::from_bits(self.max_loss.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn reset(&self) {
+        self.total.store(0, Ordering::Relaxed);
+        self.lossy.store(0, Ordering::Relaxed);
+        self.max_loss.store(0, Ordering::Relaxed);
+    }
+}
+
+type Hook = Box<dyn Fn(&Conversion) + Send + Sync>;
+
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+static FROM_F64: Counters = Counters::new();
+static TO_F64: Counters = Counters::new();
+
+/// Installs the hook called on every audited conversion, replacing the previous one.
+///
+/// The hook is called synchronously, so it should be fast.
+/// It must not convert numbers between `f64` and `FixedPoint` itself.
+pub fn set_hook(hook: impl Fn(&Conversion) + Send + Sync + 'static) {
+    *HOOK.write().unwrap_or_else(|err| err.into_inner()) = Some(Box::new(hook));
+}
+
+/// Removes the installed hook. Conversions are still counted in the [`summary`].
+pub fn remove_hook() {
+    *HOOK.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// Returns statistics of conversions since the start or the last [`reset`].
+pub fn summary() -> Summary {
+    Summary {
+        from_f64: FROM_F64.stats(),
+        to_f64: TO_F64.stats(),
+    }
+}
+
+/// Resets statistics returned by [`summary`].
+pub fn reset() {
+    FROM_F64.reset();
+    TO_F64.reset();
+}
+
+pub(crate) fn record(direction: Direction, precision: i32, float: f64, loss: f64) {
+    let counters = match direction {
+        Direction::FromF64 => &FROM_F64,
+        Direction::ToF64 => &TO_F64,
+    };
+
+    counters.total.fetch_add(1, Ordering::Relaxed);
+    if loss != 0. {
+        counters.lossy.fetch_add(1, Ordering::Relaxed);
+        counters
+            .max_loss
+            .fetch_max(loss.abs().to_bits(), Ordering::Relaxed);
+    }
+
+    if let Some(hook) = &*HOOK.read().unwrap_or_else(|err| err.into_inner()) {
+        hook(&Conversion {
+            direction,
+            precision,
+            float,
+            loss,
+        });
+    }
+}
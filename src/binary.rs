@@ -0,0 +1,323 @@
+//! Binary scaling, `FixedPoint<I, Binary<N>>`, also known as the Q format.
+//!
+//! The scaling factor is `2^N` instead of `10^P`. The checked and rounded arithmetic reuses the
+//! promotions of the decimal [`Precision`](crate::Precision), but parsing and serialization are
+//! not provided: most decimal fractions aren't representable exactly.
+
+use core::{fmt, marker::PhantomData};
+
+use typenum::Unsigned;
+
+#[cfg(feature = "i128")]
+use crate::i256_polyfill::i256;
+#[cfg(feature = "i64")]
+use crate::layout::I64Promotion;
+use crate::layout::Promotion as _;
+use crate::{ops::*, ArithmeticError, ConvertError, ConvertErrorKind, FixedPoint, Result};
+
+/// The binary scaling factor `2^N`, an alternative to the decimal [`Precision`](crate::Precision),
+/// e.g. `FixedPoint<i32, Binary<U16>>` is the Q15.16 format.
+///
+/// `N` is the number of bits in the fractional part. It must leave room for the sign and `ONE`,
+/// i.e. `N < BITS - 1`, otherwise constants fail to compile.
+///
+/// ```
+/// # #[cfg(feature = "i32")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{Binary, FixedPoint, typenum::U16, ops::{One, RoundingDiv, RoundingMul, RoundMode::*}};
+///
+/// type Q16 = FixedPoint<i32, Binary<U16>>;
+///
+/// let half = Q16::ONE.rdiv(2, Floor)?;
+/// assert_eq!(half.into_bits(), 0x8000);
+/// assert_eq!(half.rmul(half, Floor)?.to_string(), "0.25");
+/// assert_eq!(Q16::ONE.rdiv(3, Nearest)?.to_string(), "0.3333282470703125");
+/// assert_eq!(f64::from(Q16::EPSILON), 1. / 65536.);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i32"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Binary<N>(PhantomData<N>);
+
+macro_rules! impl_binary {
+    (
+        $(#[$attr:meta])?
+        inner = $layout:tt;
+        promoted_to = $promotion:tt;
+    ) => {
+        $(#[$attr])?
+        impl<N: Unsigned> FixedPoint<$layout, Binary<N>> {
+            /// The number of bits in the fractional part.
+            pub const FRACTIONAL_BITS: u32 = N::U32;
+            /// The difference between `0.0` and the next larger representable number.
+            pub const EPSILON: Self = Self::from_bits(1);
+
+            const COEF: $layout = {
+                assert!(
+                    N::U32 < $layout::BITS - 1,
+                    concat!("too many fractional bits for `", stringify!($layout), "`"),
+                );
+                1 << N::U32
+            };
+
+            /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn cneg(self) -> Result<Self> {
+                self.inner
+                    .checked_neg()
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Returns the absolute value of a number.
+            #[inline]
+            pub fn abs(self) -> Result<Self> {
+                if self.inner < 0 {
+                    self.cneg()
+                } else {
+                    Ok(self)
+                }
+            }
+
+            /// Rounds towards the nearest integer according to `mode`.
+            #[inline]
+            pub fn integral(self, mode: RoundMode) -> $layout {
+                let sign = self.inner.signum();
+                let (mut int, frac) = (self.inner / Self::COEF, (self.inner % Self::COEF).abs());
+
+                if frac != 0 && mode.is_away_from_zero(sign < 0, frac.cmp(&(Self::COEF - frac)), int % 2 != 0) {
+                    int += sign;
+                }
+
+                int
+            }
+
+            fn round_promoted(value: $promotion, mode: RoundMode) -> Result<Self> {
+                // `|loss| < COEF`, thus it fits in the layout.
+                let (result, loss) = value.div_rem_l(Self::COEF);
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != 0 {
+                    let sign = loss.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(Self::COEF.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> Zero for FixedPoint<$layout, Binary<N>> {
+            const ZERO: Self = Self::from_bits(0);
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> One for FixedPoint<$layout, Binary<N>> {
+            const ONE: Self = Self::from_bits(Self::COEF);
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> Bounded for FixedPoint<$layout, Binary<N>> {
+            const MIN: Self = Self::from_bits($layout::MIN);
+            const MAX: Self = Self::from_bits($layout::MAX);
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> CheckedAdd for FixedPoint<$layout, Binary<N>> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> Result<Self> {
+                self.inner.cadd(rhs.inner).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_add(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> CheckedSub for FixedPoint<$layout, Binary<N>> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn csub(self, rhs: Self) -> Result<Self> {
+                self.inner.csub(rhs.inner).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_sub(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> CheckedMul<$layout> for FixedPoint<$layout, Binary<N>> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cmul(self, rhs: $layout) -> Result<Self> {
+                self.inner.cmul(rhs).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: $layout) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_mul(rhs))
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> RoundingMul for FixedPoint<$layout, Binary<N>> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                let value = $promotion::from(self.inner).mul_l(rhs.inner);
+                Self::round_promoted(value, mode)
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> RoundingDiv for FixedPoint<$layout, Binary<N>> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let numerator = $promotion::from(self.inner).mul_l(Self::COEF);
+                // `|loss| < rhs`, thus it fits in the layout.
+                let (result, loss) = numerator.div_rem_l(rhs.inner);
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != 0 {
+                    let sign = self.inner.signum() * rhs.inner.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(rhs.inner.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> RoundingDiv<$layout> for FixedPoint<$layout, Binary<N>> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: $layout, mode: RoundMode) -> Result<Self> {
+                self.inner.rdiv(rhs, mode).map(Self::from_bits)
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> TryFrom<$layout> for FixedPoint<$layout, Binary<N>> {
+            type Error = ConvertError;
+
+            #[inline]
+            fn try_from(value: $layout) -> Result<Self, ConvertError> {
+                value
+                    .checked_mul(Self::COEF)
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ConvertError::new(ConvertErrorKind::IntegralOverflow, "too big number"))
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> From<FixedPoint<$layout, Binary<N>>> for f64 {
+            /// Dividing by a power of two is exact, so only `inner as f64` can round.
+            #[inline]
+            fn from(value: FixedPoint<$layout, Binary<N>>) -> Self {
+                value.inner as f64 / FixedPoint::<$layout, Binary<N>>::COEF as f64
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> fmt::Debug for FixedPoint<$layout, Binary<N>> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        $(#[$attr])?
+        impl<N: Unsigned> fmt::Display for FixedPoint<$layout, Binary<N>> {
+            /// Writes the exact decimal expansion, every binary fraction has at most `N` digits.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if self.inner < 0 {
+                    f.write_str("-")?;
+                }
+
+                // `unsigned_abs()` doesn't overflow on `MIN`.
+                let integral = (self.inner / Self::COEF).unsigned_abs();
+                // `fractional < COEF`, thus it fits in the layout.
+                let mut fractional = (self.inner % Self::COEF).unsigned_abs() as $layout;
+
+                write!(f, "{}.", integral)?;
+
+                if fractional == 0 {
+                    return f.write_str("0");
+                }
+
+                while fractional != 0 {
+                    let (digit, rest) = $promotion::from(fractional).mul_l(10).div_rem_l(Self::COEF);
+                    // `digit < 10`, thus it fits in the layout.
+                    let digit = $layout::try_from(digit).map_err(|_| fmt::Error)?;
+                    write!(f, "{}", digit)?;
+                    fractional = rest;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_binary!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
+    inner = i16;
+    promoted_to = i32;
+);
+#[cfg(feature = "i32")]
+impl_binary!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]
+    inner = i32;
+    promoted_to = i64;
+);
+#[cfg(feature = "i64")]
+impl_binary!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]
+    inner = i64;
+    promoted_to = I64Promotion;
+);
+#[cfg(feature = "i128")]
+impl_binary!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+    inner = i128;
+    promoted_to = i256;
+);
@@ -0,0 +1,174 @@
+//! Helpers for order books.
+//!
+//! [`Ladder`] is a thin wrapper over `BTreeMap<FixedPoint, V>` keeping price levels aligned
+//! to the tick size.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{book::Ladder, ArithmeticError, FixedPoint, typenum::U9};
+//!
+//! type Price = FixedPoint<i64, U9>;
+//! type Size = FixedPoint<i64, U9>;
+//!
+//! let p = |s: &str| s.parse::<Price>().unwrap();
+//!
+//! let mut asks = Ladder::<_, _, Size>::new(p("0.25"))?;
+//! asks.insert(p("100.25"), p("3"))?;
+//! asks.insert(p("100.5"), p("1"))?;
+//! asks.insert(p("101"), p("2"))?;
+//! assert_eq!(asks.insert(p("100.3"), p("1")), Err(ArithmeticError::DomainViolation));
+//!
+//! assert_eq!(asks.best_ask(), Some((p("100.25"), &p("3"))));
+//! let near: Vec<_> = asks.range_around(p("100.5"), p("0.25")).map(|(price, _)| price).collect();
+//! assert_eq!(near, [p("100.25"), p("100.5")]);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::{
+    ops::{Bounded, CheckedAdd, CheckedRem, CheckedSub, Zero},
+    ArithmeticError, FixedPoint,
+};
+
+/// Price levels of one side of an order book, aligned to the tick size.
+///
+/// The ladder doesn't know its side: use [`best_bid`](Ladder::best_bid) (the highest price) for
+/// bids and [`best_ask`](Ladder::best_ask) (the lowest price) for asks.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Ladder<I, P, V> {
+    tick: FixedPoint<I, P>,
+    levels: BTreeMap<FixedPoint<I, P>, V>,
+}
+
+impl<I, P, V> Ladder<I, P, V>
+where
+    FixedPoint<I, P>: Ord
+        + Copy
+        + Zero
+        + Bounded
+        + CheckedAdd<Output = FixedPoint<I, P>>
+        + CheckedSub<Output = FixedPoint<I, P>>
+        + CheckedRem<Output = FixedPoint<I, P>, Error = ArithmeticError>,
+{
+    /// Creates an empty ladder. Returns `Err(DomainViolation)` if `tick` isn't positive.
+    pub fn new(tick: FixedPoint<I, P>) -> Result<Self, ArithmeticError> {
+        if tick <= FixedPoint::ZERO {
+            return Err(ArithmeticError::DomainViolation);
+        }
+
+        Ok(Self {
+            tick,
+            levels: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the tick size.
+    pub fn tick(&self) -> FixedPoint<I, P> {
+        self.tick
+    }
+
+    /// Returns `true` if the price is a multiple of the tick size.
+    pub fn is_aligned(&self, price: FixedPoint<I, P>) -> bool {
+        price.crem(self.tick) == Ok(FixedPoint::ZERO)
+    }
+
+    /// Inserts the level, returning the previous value at this price.
+    ///
+    /// Returns `Err(DomainViolation)` if the price isn't aligned to the tick size,
+    /// the ladder isn't changed in this case.
+    pub fn insert(
+        &mut self,
+        price: FixedPoint<I, P>,
+        value: V,
+    ) -> Result<Option<V>, ArithmeticError> {
+        if !self.is_aligned(price) {
+            return Err(ArithmeticError::DomainViolation);
+        }
+
+        Ok(self.levels.insert(price, value))
+    }
+
+    /// Removes the level, returning its value.
+    pub fn remove(&mut self, price: FixedPoint<I, P>) -> Option<V> {
+        self.levels.remove(&price)
+    }
+
+    /// Returns the value at the price.
+    pub fn get(&self, price: FixedPoint<I, P>) -> Option<&V> {
+        self.levels.get(&price)
+    }
+
+    /// Returns the mutable value at the price.
+    pub fn get_mut(&mut self, price: FixedPoint<I, P>) -> Option<&mut V> {
+        self.levels.get_mut(&price)
+    }
+
+    /// Returns the level with the highest price.
+    pub fn best_bid(&self) -> Option<(FixedPoint<I, P>, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Returns the level with the lowest price.
+    pub fn best_ask(&self) -> Option<(FixedPoint<I, P>, &V)> {
+        self.iter().next()
+    }
+
+    /// Returns levels in `[mid - width, mid + width]` in ascending order of prices.
+    /// Bounds are saturated, a negative `width` gives nothing.
+    pub fn range_around(
+        &self,
+        mid: FixedPoint<I, P>,
+        width: FixedPoint<I, P>,
+    ) -> impl DoubleEndedIterator<Item = (FixedPoint<I, P>, &V)> + '_ {
+        let (low, high) = (mid.saturating_sub(width), mid.saturating_add(width));
+        // `BTreeMap::range` panics if `low > high`, which is possible only for a negative `width`.
+        let range = (low <= high).then(|| self.levels.range(low..=high));
+
+        range
+            .into_iter()
+            .flatten()
+            .map(|(price, value)| (*price, value))
+    }
+
+    /// Returns the number of levels.
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns `true` if there are no levels.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Returns levels in ascending order of prices.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (FixedPoint<I, P>, &V)> + '_ {
+        self.levels.iter().map(|(price, value)| (*price, value))
+    }
+
+    /// Returns the underlying map.
+    pub fn as_map(&self) -> &BTreeMap<FixedPoint<I, P>, V> {
+        &self.levels
+    }
+
+    /// Returns the underlying map.
+    pub fn into_map(self) -> BTreeMap<FixedPoint<I, P>, V> {
+        self.levels
+    }
+}
+
+impl<I, P, V> fmt::Debug for Ladder<I, P, V>
+where
+    FixedPoint<I, P>: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ladder")
+            .field("tick", &self.tick)
+            .field("levels", &self.levels)
+            .finish()
+    }
+}
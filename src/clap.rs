@@ -0,0 +1,172 @@
+//! A [`clap`] value parser of `FixedPoint` arguments.
+//!
+//! [`FixedPointParser`] parses values exactly and validates them against optional bounds and
+//! the tick size, reporting problems in terms of the command line, e.g.
+//! `expected at most 2 decimal places, got 3`.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use clap::{Arg, Command};
+//! use fixnum::{clap::value_parser, FixedPoint, typenum::U9};
+//!
+//! type Price = FixedPoint<i64, U9>;
+//!
+//! let p = |s: &str| s.parse::<Price>().unwrap();
+//! let command = Command::new("quote").arg(
+//!     Arg::new("price")
+//!         .long("price")
+//!         .value_parser(value_parser().min(p("0")).max(p("1000")).tick(p("0.25"))),
+//! );
+//!
+//! let matches = command.clone().try_get_matches_from(["quote", "--price", "100.25"])?;
+//! assert_eq!(matches.get_one::<Price>("price"), Some(&p("100.25")));
+//!
+//! let error = command.try_get_matches_from(["quote", "--price", "100.3"]).unwrap_err();
+//! assert!(error.to_string().contains("expected a multiple of 0.25, got 100.3"));
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use std::{
+    ffi::OsStr,
+    fmt::{self, Display},
+};
+
+use clap::{error::ErrorKind, Arg, Command, Error};
+
+use crate::{
+    ops::{CheckedRem, Zero},
+    ConvertErrorKind, FixedPoint, Precision,
+};
+
+/// Parses `FixedPoint` arguments exactly, without rounding.
+///
+/// Created by [`value_parser`]. Bounds and the tick size are optional.
+#[derive(Clone)]
+pub struct FixedPointParser<I, P> {
+    min: Option<FixedPoint<I, P>>,
+    max: Option<FixedPoint<I, P>>,
+    tick: Option<FixedPoint<I, P>>,
+}
+
+/// Creates a parser of `FixedPoint` arguments without any restrictions.
+pub fn value_parser<I, P>() -> FixedPointParser<I, P> {
+    FixedPointParser {
+        min: None,
+        max: None,
+        tick: None,
+    }
+}
+
+impl<I, P> FixedPointParser<I, P> {
+    /// Rejects values less than `min`.
+    pub fn min(mut self, min: FixedPoint<I, P>) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Rejects values greater than `max`.
+    pub fn max(mut self, max: FixedPoint<I, P>) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Rejects values, which aren't multiples of `tick`. A zero `tick` rejects everything.
+    pub fn tick(mut self, tick: FixedPoint<I, P>) -> Self {
+        self.tick = Some(tick);
+        self
+    }
+}
+
+impl<I, P> fmt::Debug for FixedPointParser<I, P>
+where
+    FixedPoint<I, P>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedPointParser")
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("tick", &self.tick)
+            .finish()
+    }
+}
+
+fn error(cmd: &Command, arg: Option<&Arg>, value: &str, reason: impl Display) -> Error {
+    let arg = arg.map_or_else(|| "...".into(), |arg| arg.to_string());
+    let message = format!("invalid value '{}' for '{}': {}\n", value, arg, reason);
+    Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+}
+
+macro_rules! impl_clap {
+    ($layout:ty) => {
+        impl_clap!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: Precision + Send + Sync + 'static> clap::builder::TypedValueParser for FixedPointParser<$layout, P> {
+            type Value = FixedPoint<$layout, P>;
+
+            fn parse_ref(
+                &self,
+                cmd: &Command,
+                arg: Option<&Arg>,
+                value: &OsStr,
+            ) -> Result<Self::Value, Error> {
+                let value = value
+                    .to_str()
+                    .ok_or_else(|| Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+                let error = |reason: &dyn Display| error(cmd, arg, value, reason);
+
+                let parsed = FixedPoint::<$layout, P>::from_str_exact(value).map_err(|err| {
+                    match err.kind() {
+                        ConvertErrorKind::FractionalPrecision => {
+                            let digits = value.split_once('.').map_or(0, |(_, f)| f.len());
+                            error(&format_args!(
+                                "expected at most {} decimal places, got {}",
+                                P::U32,
+                                digits
+                            ))
+                        }
+                        _ => error(&err),
+                    }
+                })?;
+
+                if let Some(min) = self.min.filter(|min| parsed < *min) {
+                    return Err(error(&format_args!("expected at least {}, got {}", min, value)));
+                }
+                if let Some(max) = self.max.filter(|max| parsed > *max) {
+                    return Err(error(&format_args!("expected at most {}, got {}", max, value)));
+                }
+                if let Some(tick) = self.tick {
+                    if parsed.crem(tick) != Ok(FixedPoint::ZERO) {
+                        return Err(error(&format_args!(
+                            "expected a multiple of {}, got {}",
+                            tick, value
+                        )));
+                    }
+                }
+
+                Ok(parsed)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_clap!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_clap!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_clap!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_clap!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
+#[cfg(feature = "u16")]
+impl_clap!(u16, #[cfg_attr(docsrs, doc(cfg(feature = "u16")))]);
+#[cfg(feature = "u32")]
+impl_clap!(u32, #[cfg_attr(docsrs, doc(cfg(feature = "u32")))]);
+#[cfg(feature = "u64")]
+impl_clap!(u64, #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]);
+#[cfg(feature = "u128")]
+impl_clap!(u128, #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]);
@@ -1,14 +1,18 @@
 #![allow(missing_docs)] // available only in `_priv` for macros.
 
+use core::cmp::Ordering;
+
+use crate::ops::RoundMode;
+
 #[cfg(not(feature = "i128"))]
 pub type Int = i64;
 #[cfg(feature = "i128")]
 pub type Int = i128;
 
 macro_rules! const_assert {
-    ($expr:expr) => {
+    ($expr:expr, $message:literal) => {
         if !$expr {
-            loop {}
+            panic!($message);
         }
     };
 }
@@ -66,18 +70,18 @@ const fn find(bytes: &[u8], pattern: u8) -> Option<usize> {
 
 const fn parse_digit(byte: u8) -> Int {
     let digit = byte.wrapping_sub(48);
-    const_assert!(digit < 10);
+    const_assert!(digit < 10, "invalid digit in the literal");
     digit as _
 }
 
-const fn parse_int(bytes: &[u8], start: usize, end: usize) -> Int {
+/// Accumulates digits with the provided sign in order to support `MIN` values.
+const fn parse_int(bytes: &[u8], start: usize, end: usize, signum: Int) -> Int {
     let mut result: Int = 0;
     let mut i = start;
 
     while i < end {
-        let digit = parse_digit(bytes[i]);
+        result = result * 10 + signum * parse_digit(bytes[i]);
         i += 1;
-        result += digit * pow10((end - i) as i32);
     }
 
     result
@@ -97,20 +101,149 @@ pub const fn parse_fixed(str: &str, coef: Int) -> Int {
     let point = match find(bytes, b'.') {
         Some(point) => point,
         None => {
-            let integral = parse_int(bytes, start, bytes.len());
-            return signum * integral * coef;
+            let integral = parse_int(bytes, start, bytes.len(), signum);
+            return integral * coef;
         }
     };
 
-    let integral = parse_int(bytes, start, point);
+    let integral = parse_int(bytes, start, point, signum);
     let exp = pow10((bytes.len() - point - 1) as i32);
-    const_assert!(exp <= coef);
+    const_assert!(
+        exp <= coef,
+        "too long fractional part, use `fixnum_round!` to round it"
+    );
 
-    let fractional = parse_int(bytes, point + 1, bytes.len());
+    let fractional = parse_int(bytes, point + 1, bytes.len(), signum);
     let final_integral = integral * coef;
     let final_fractional = coef / exp * fractional;
 
-    signum * (final_integral + final_fractional)
+    final_integral + final_fractional
+}
+
+/// The same as `parse_fixed`, but [rounds][RoundMode] the fractional part if it's too long.
+pub const fn parse_fixed_round(str: &str, coef: Int, mode: RoundMode) -> Int {
+    let bytes = str.as_bytes();
+
+    let mut precision = 0;
+    let mut power = 1;
+    while power < coef {
+        power *= 10;
+        precision += 1;
+    }
+
+    let point = match find(bytes, b'.') {
+        Some(point) if bytes.len() - point - 1 > precision => point,
+        _ => return parse_fixed(str, coef),
+    };
+
+    let negative = bytes[0] == b'-';
+    let signum = if negative { -1 } else { 1 };
+    let start = if negative || bytes[0] == b'+' { 1 } else { 0 };
+
+    let end = point + 1 + precision;
+    let truncated =
+        parse_int(bytes, start, point, signum) * coef + parse_int(bytes, point + 1, end, signum);
+
+    // Compare the rest of digits with the half of the last unit.
+    let first = parse_digit(bytes[end]);
+    let mut tail_is_zero = true;
+    let mut i = end + 1;
+    while i < bytes.len() {
+        tail_is_zero &= parse_digit(bytes[i]) == 0;
+        i += 1;
+    }
+
+    let half = if first > 5 || (first == 5 && !tail_is_zero) {
+        Ordering::Greater
+    } else if first == 5 {
+        Ordering::Equal
+    } else {
+        Ordering::Less
+    };
+
+    let inexact = first != 0 || !tail_is_zero;
+    if inexact && mode.is_away_from_zero(negative, half, truncated % 2 != 0) {
+        truncated + signum
+    } else {
+        truncated
+    }
+}
+
+/// Enough for `I256::MAX` with the zero precision, i.e. 77 digits and `.0`.
+pub const DECIMAL_CAPACITY: usize = 80;
+
+/// A non-negative number written by `write_decimal`, the text is at the end of the buffer.
+pub type Decimal = ([u8; DECIMAL_CAPACITY], usize);
+
+/// Splits a non-negative number into little-endian limbs, see `write_decimal`.
+pub const fn to_limbs(value: u128) -> [u64; 4] {
+    [value as u64, (value >> 64) as u64, 0, 0]
+}
+
+/// Divides little-endian limbs by ten in place, returns the remainder.
+const fn div_rem_10(limbs: &mut [u64; 4]) -> u8 {
+    let mut rem = 0u128;
+    let mut i = limbs.len();
+
+    while i > 0 {
+        i -= 1;
+        let current = (rem << 64) | limbs[i] as u128;
+        limbs[i] = (current / 10) as u64;
+        rem = current % 10;
+    }
+
+    rem as u8
+}
+
+const fn is_zero(limbs: &[u64; 4]) -> bool {
+    limbs[0] == 0 && limbs[1] == 0 && limbs[2] == 0 && limbs[3] == 0
+}
+
+/// Writes `limbs / 10^precision` the same way `Display` does:
+/// trailing zeros are trimmed, but at least one fractional digit is kept.
+pub const fn write_decimal(mut limbs: [u64; 4], precision: u32) -> Decimal {
+    let mut buf = [b'0'; DECIMAL_CAPACITY];
+    let mut start = DECIMAL_CAPACITY;
+    let mut significant = false;
+
+    let mut i = 0;
+    while i < precision {
+        let digit = div_rem_10(&mut limbs);
+        significant |= digit != 0;
+
+        if significant {
+            start -= 1;
+            buf[start] = b'0' + digit;
+        }
+
+        i += 1;
+    }
+
+    if !significant {
+        start -= 1;
+        buf[start] = b'0';
+    }
+
+    start -= 1;
+    buf[start] = b'.';
+
+    loop {
+        start -= 1;
+        buf[start] = b'0' + div_rem_10(&mut limbs);
+
+        if is_zero(&limbs) {
+            break;
+        }
+    }
+
+    (buf, start)
+}
+
+pub const fn decimal_str(decimal: &'static Decimal) -> &'static str {
+    match core::str::from_utf8(decimal.0.split_at(decimal.1).1) {
+        Ok(str) => str,
+        Err(_) => panic!("only ASCII digits and `.` are written"),
+    }
 }
 
 #[test]
@@ -125,4 +258,48 @@ fn from_good_str() {
     assert_eq!(parse_fixed("9223372036.854775807", c), 9223372036854775807);
     assert_eq!(parse_fixed("0.1234", c), 123400000);
     assert_eq!(parse_fixed("-0.1234", c), -123400000);
+    assert_eq!(
+        parse_fixed("-9223372036.854775808", c),
+        -9223372036854775808
+    );
+    assert_eq!(parse_fixed("-9223372036854775808", 1), -9223372036854775808);
+}
+
+#[test]
+fn round_str() {
+    use RoundMode::*;
+
+    let c = 100;
+    assert_eq!(parse_fixed_round("1.25", c, Floor), 125);
+    assert_eq!(parse_fixed_round("1.2", c, Floor), 120);
+    assert_eq!(parse_fixed_round("1.125", c, Floor), 112);
+    assert_eq!(parse_fixed_round("1.125", c, Ceil), 113);
+    assert_eq!(parse_fixed_round("1.125", c, Nearest), 113);
+    assert_eq!(parse_fixed_round("1.125", c, HalfEven), 112);
+    assert_eq!(parse_fixed_round("1.1251", c, HalfEven), 113);
+    assert_eq!(parse_fixed_round("1.135", c, HalfEven), 114);
+    assert_eq!(parse_fixed_round("1.12000", c, Ceil), 112);
+    assert_eq!(parse_fixed_round("-1.125", c, Floor), -113);
+    assert_eq!(parse_fixed_round("-1.125", c, Ceil), -112);
+    assert_eq!(parse_fixed_round("-1.125", c, Nearest), -113);
+    assert_eq!(parse_fixed_round("-0.001", c, Floor), -1);
+    assert_eq!(parse_fixed_round("-0.001", c, TowardZero), 0);
+    assert_eq!(parse_fixed_round("+0.001", c, AwayFromZero), 1);
+    assert_eq!(parse_fixed_round("7.5", 1, Nearest), 8);
+}
+
+#[test]
+fn write_decimal_like_display() {
+    let check = |value: u128, precision, expected: &str| {
+        let (buf, start) = write_decimal(to_limbs(value), precision);
+        assert_eq!(&buf[start..], expected.as_bytes());
+    };
+
+    check(0, 0, "0.0");
+    check(1, 0, "1.0");
+    check(1, 9, "0.000000001");
+    check(1_500_000_000, 9, "1.5");
+    check(i64::MAX as u128, 9, "9223372036.854775807");
+    check(u128::MAX, 38, "3.40282366920938463463374607431768211455");
+    check(u128::MAX, 0, "340282366920938463463374607431768211455.0");
 }
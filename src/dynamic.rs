@@ -0,0 +1,343 @@
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "i128")]
+use crate::i256_polyfill::i256;
+#[cfg(feature = "i64")]
+use crate::layout::I64Promotion;
+use crate::{
+    ops::{CheckedAdd, CheckedSub, RoundMode, RoundingDiv, RoundingMul},
+    ArithmeticError, ConvertError, ConvertErrorKind, FixedPoint, Precision, Result,
+};
+
+/// A decimal number with the scale (the number of fractional digits) known only at runtime,
+/// e.g. when it comes from exchange metadata.
+///
+/// The value is `mantissa / 10 ^ scale`. Arithmetic is checked and aligns scales:
+/// the result has the largest scale of the operands. Use [`FixedPoint`] if the precision
+/// is known at compile time.
+///
+/// Equality and hashing are structural, so `1.0` and `1.00` are different values.
+/// Rescale numbers to the same scale to compare them.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{DynFixedPoint, FixedPoint, typenum::U9, ops::{RoundingMul, RoundMode::*}};
+///
+/// let price = DynFixedPoint::<i64>::new(12345, 2)?; // 123.45
+/// let size: DynFixedPoint<i64> = "0.003".parse()?;
+/// let amount = price.rmul(size, Floor)?;
+/// assert_eq!(amount.to_string(), "0.370");
+/// assert_eq!(amount.rescale(1, Ceil)?.to_string(), "0.4");
+///
+/// let amount: FixedPoint<i64, U9> = amount.to_fixed_point(Floor)?;
+/// assert_eq!(amount, "0.37".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DynFixedPoint<I> {
+    mantissa: I,
+    scale: u32,
+}
+
+impl<I: Copy> DynFixedPoint<I> {
+    /// Returns the mantissa, i.e. the value multiplied by `10 ^ scale`.
+    #[inline]
+    pub fn mantissa(&self) -> I {
+        self.mantissa
+    }
+
+    /// Returns the number of fractional digits.
+    #[inline]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+}
+
+macro_rules! impl_dyn_fixed_point {
+    (
+        $(#[$attr:meta])?
+        inner = $layout:tt;
+        unsigned = $unsigned:tt;
+        promoted_to = $promotion:tt;
+        max_scale = $max_scale:literal;
+    ) => {const _: () = {
+        use $crate::_priv::Promotion as _;
+
+        $(#[$attr])?
+        impl DynFixedPoint<$layout> {
+            /// The maximal scale, `10 ^ MAX_SCALE` fits the layout.
+            pub const MAX_SCALE: u32 = $max_scale;
+
+            /// Creates a number equal to `mantissa / 10 ^ scale`.
+            /// Returns `Err` if `scale` is greater than [`MAX_SCALE`](Self::MAX_SCALE).
+            #[inline]
+            pub fn new(mantissa: $layout, scale: u32) -> Result<Self, ConvertError> {
+                if scale > Self::MAX_SCALE {
+                    return Err(ConvertError::new(ConvertErrorKind::UnsupportedExponent, "unsupported scale"));
+                }
+
+                Ok(Self { mantissa, scale })
+            }
+
+            /// Converts the number to the given scale.
+            /// Returns `Err` on overflow or if `scale` is greater than
+            /// [`MAX_SCALE`](Self::MAX_SCALE).
+            pub fn rescale(self, scale: u32, mode: RoundMode) -> Result<Self> {
+                if scale > Self::MAX_SCALE {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                let mantissa = if scale >= self.scale {
+                    self.mantissa
+                        .checked_mul(pow10(scale - self.scale))
+                        .ok_or(ArithmeticError::Overflow)?
+                } else {
+                    let divisor = pow10(self.scale - scale);
+                    div_round($promotion::from(self.mantissa), divisor, self.mantissa.signum(), mode)?
+                };
+
+                Ok(Self { mantissa, scale })
+            }
+
+            /// Converts to the compile-time precision, [rounding][RoundMode] if needed.
+            /// Returns `Err` on overflow.
+            pub fn to_fixed_point<P: Precision>(self, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                let precision = FixedPoint::<$layout, P>::PRECISION as u32;
+                self.rescale(precision, mode)
+                    .map(|value| FixedPoint::from_bits(value.mantissa))
+            }
+
+            /// Returns both numbers with the largest scale of them.
+            fn align(self, rhs: Self) -> Result<(Self, Self)> {
+                let scale = self.scale.max(rhs.scale);
+                // Upscaling is exact, so the mode doesn't matter.
+                Ok((self.rescale(scale, RoundMode::Floor)?, rhs.rescale(scale, RoundMode::Floor)?))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> From<FixedPoint<$layout, P>> for DynFixedPoint<$layout> {
+            #[inline]
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                Self {
+                    mantissa: value.into_bits(),
+                    scale: FixedPoint::<$layout, P>::PRECISION as u32,
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl CheckedAdd for DynFixedPoint<$layout> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> Result<Self> {
+                let (lhs, rhs) = self.align(rhs)?;
+                lhs.mantissa
+                    .checked_add(rhs.mantissa)
+                    .map(|mantissa| Self { mantissa, scale: lhs.scale })
+                    .ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        $(#[$attr])?
+        impl CheckedSub for DynFixedPoint<$layout> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn csub(self, rhs: Self) -> Result<Self> {
+                let (lhs, rhs) = self.align(rhs)?;
+                lhs.mantissa
+                    .checked_sub(rhs.mantissa)
+                    .map(|mantissa| Self { mantissa, scale: lhs.scale })
+                    .ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        $(#[$attr])?
+        impl RoundingMul for DynFixedPoint<$layout> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            /// Rounding multiplication. The result has the largest scale of the operands.
+            #[inline]
+            fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                // `a / 10^sa * b / 10^sb = (a * b / 10^min(sa, sb)) / 10^max(sa, sb)`
+                let product = $promotion::from(self.mantissa).mul_l(rhs.mantissa);
+                let divisor = pow10(self.scale.min(rhs.scale));
+                let sign = self.mantissa.signum() * rhs.mantissa.signum();
+                let mantissa = div_round(product, divisor, sign, mode)?;
+
+                Ok(Self { mantissa, scale: self.scale.max(rhs.scale) })
+            }
+        }
+
+        $(#[$attr])?
+        impl RoundingDiv for DynFixedPoint<$layout> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            /// Rounding division. The result has the largest scale of the operands.
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                if rhs.mantissa == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `(a / 10^sa) / (b / 10^sb) = (a * 10^(s - sa) * 10^sb / b) / 10^s`
+                let scale = self.scale.max(rhs.scale);
+                let numerator = $promotion::from(self.mantissa)
+                    .checked_mul_l(pow10(scale - self.scale))
+                    .and_then(|value| value.checked_mul_l(pow10(rhs.scale)))
+                    .ok_or(ArithmeticError::Overflow)?;
+                let sign = self.mantissa.signum() * rhs.mantissa.signum();
+                let mantissa = div_round(numerator, rhs.mantissa, sign, mode)?;
+
+                Ok(Self { mantissa, scale })
+            }
+        }
+
+        $(#[$attr])?
+        impl FromStr for DynFixedPoint<$layout> {
+            type Err = ConvertError;
+
+            /// Parses a string slice keeping all fractional digits, so `"1.50"` has scale `2`.
+            fn from_str(str: &str) -> Result<Self, Self::Err> {
+                let str = str.trim();
+                let (negative, str) = match str.as_bytes().first() {
+                    Some(b'-') => (true, &str[1..]),
+                    Some(b'+') => (false, &str[1..]),
+                    _ => (false, str),
+                };
+
+                let (integral, fractional) = match str.split_once('.') {
+                    Some((_, "")) => return Err(syntax_error("can't parse fractional part")),
+                    Some(parts) => parts,
+                    None => (str, ""),
+                };
+
+                let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+                if integral.is_empty() || !is_digits(integral) {
+                    return Err(syntax_error("can't parse integral part"));
+                }
+                if !is_digits(fractional) {
+                    return Err(syntax_error("can't parse fractional part: must contain digits only"));
+                }
+
+                let scale = fractional.len() as u32;
+                if scale > Self::MAX_SCALE {
+                    return Err(ConvertError::fractional_precision(Self::MAX_SCALE));
+                }
+
+                let overflow = || ConvertError::integral_overflow(
+                    $layout::MIN as i128,
+                    $layout::MAX as u128,
+                    scale,
+                );
+
+                let magnitude = integral
+                    .bytes()
+                    .chain(fractional.bytes())
+                    .try_fold(0 as $unsigned, |acc, digit| {
+                        acc.checked_mul(10)?.checked_add($unsigned::from(digit - b'0'))
+                    })
+                    .ok_or_else(overflow)?;
+
+                let mantissa = if negative {
+                    if magnitude > $layout::MIN.unsigned_abs() {
+                        return Err(overflow());
+                    }
+                    (magnitude as $layout).wrapping_neg()
+                } else {
+                    $layout::try_from(magnitude).map_err(|_| overflow())?
+                };
+
+                Ok(Self { mantissa, scale })
+            }
+        }
+
+        $(#[$attr])?
+        impl fmt::Display for DynFixedPoint<$layout> {
+            /// Formats the number with exactly `scale` fractional digits.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let magnitude = self.mantissa.unsigned_abs();
+                let coef = pow10(self.scale) as $unsigned;
+                let sign = if self.mantissa < 0 { "-" } else { "" };
+
+                write!(f, "{}{}", sign, magnitude / coef)?;
+                if self.scale > 0 {
+                    let width = self.scale as usize;
+                    write!(f, ".{:0width$}", magnitude % coef, width = width)?;
+                }
+                Ok(())
+            }
+        }
+
+        #[inline]
+        fn pow10(scale: u32) -> $layout {
+            let ten: $layout = 10;
+            ten.pow(scale)
+        }
+
+        /// Divides `numerator` by `divisor` rounding according to `mode`,
+        /// `sign` is the sign of the exact result.
+        fn div_round(numerator: $promotion, divisor: $layout, sign: $layout, mode: RoundMode) -> Result<$layout> {
+            // `|loss| < |divisor|`, thus it fits in the layout.
+            let (result, loss) = numerator.div_rem_l(divisor);
+            let mut result = $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+            if loss != 0 {
+                let loss_abs = loss.unsigned_abs();
+                let half = loss_abs.cmp(&(divisor.unsigned_abs() - loss_abs));
+
+                if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                    result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                }
+            }
+
+            Ok(result)
+        }
+    };};
+}
+
+fn syntax_error(reason: &'static str) -> ConvertError {
+    ConvertError::new(ConvertErrorKind::InvalidSyntax, reason)
+}
+
+#[cfg(feature = "i16")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
+    inner = i16;
+    unsigned = u16;
+    promoted_to = i32;
+    max_scale = 4;
+);
+#[cfg(feature = "i32")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]
+    inner = i32;
+    unsigned = u32;
+    promoted_to = i64;
+    max_scale = 9;
+);
+#[cfg(feature = "i64")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]
+    inner = i64;
+    unsigned = u64;
+    promoted_to = I64Promotion;
+    max_scale = 18;
+);
+#[cfg(feature = "i128")]
+impl_dyn_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+    inner = i128;
+    unsigned = u128;
+    promoted_to = i256;
+    max_scale = 38;
+);
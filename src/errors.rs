@@ -16,6 +16,8 @@ pub enum ArithmeticError {
     /// When someone tries to use operand out of the set of departure of the function.
     /// E.g.: when you try to compute the square root of a negative number.
     DomainViolation,
+    /// A result is zero, which cannot be represented by non-zero layouts.
+    ZeroResult,
 }
 
 impl ArithmeticError {
@@ -25,6 +27,7 @@ impl ArithmeticError {
             Self::Overflow => "overflow",
             Self::DivisionByZero => "division by zero",
             Self::DomainViolation => "domain violation",
+            Self::ZeroResult => "zero result",
         }
     }
 }
@@ -41,23 +44,151 @@ impl Error for ArithmeticError {}
 /// Represents errors during conversions.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConvertError {
+    kind: ConvertErrorKind,
     reason: &'static str,
+    details: Details,
+}
+
+/// A kind of [`ConvertError`].
+///
+/// Unlike messages, kinds are stable: the same failure keeps the same kind across compatible
+/// versions, so match on kinds instead of texts. New kinds can be added in minor versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConvertErrorKind {
+    /// The number doesn't fit the range of the target type.
+    IntegralOverflow,
+    /// The number has more fractional digits than the precision allows.
+    FractionalPrecision,
+    /// The input isn't a valid number.
+    InvalidSyntax,
+    /// The float is `NaN` or infinite.
+    NotFinite,
+    /// The exponent is out of the supported range.
+    UnsupportedExponent,
+    /// The number is out of the domain of the target type, e.g. not in `[0, 1]`.
+    OutOfDomain,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Details {
+    None,
+    /// Bounds of the layout as raw bits.
+    IntegralOverflow {
+        min: i128,
+        max: u128,
+        precision: u32,
+    },
+    FractionalPrecision {
+        precision: u32,
+    },
 }
 
 impl ConvertError {
-    pub(crate) fn new(reason: &'static str) -> Self {
-        Self { reason }
+    pub(crate) fn new(kind: ConvertErrorKind, reason: &'static str) -> Self {
+        Self {
+            kind,
+            reason,
+            details: Details::None,
+        }
+    }
+
+    pub(crate) fn integral_overflow(min: i128, max: u128, precision: u32) -> Self {
+        Self {
+            kind: ConvertErrorKind::IntegralOverflow,
+            reason: "too big number",
+            details: Details::IntegralOverflow {
+                min,
+                max,
+                precision,
+            },
+        }
+    }
+
+    pub(crate) fn fractional_precision(precision: u32) -> Self {
+        Self {
+            kind: ConvertErrorKind::FractionalPrecision,
+            reason: "requested precision is too high",
+            details: Details::FractionalPrecision { precision },
+        }
+    }
+
+    /// Returns the kind of the error, which is stable unlike the message.
+    pub const fn kind(&self) -> ConvertErrorKind {
+        self.kind
     }
 
     /// Stringify an error.
+    ///
+    /// Unlike `Display`, it doesn't include the bounds of the layout.
+    /// Messages can be changed in any version, use [`kind`](Self::kind) to handle errors.
     pub const fn as_str(&self) -> &'static str {
         self.reason
     }
+
+    /// Returns `true` if the number doesn't fit the range of the layout.
+    pub const fn is_integral_overflow(&self) -> bool {
+        matches!(self.kind, ConvertErrorKind::IntegralOverflow)
+    }
+
+    /// Returns `true` if the number has more fractional digits than the precision allows.
+    pub const fn is_fractional_precision(&self) -> bool {
+        matches!(self.kind, ConvertErrorKind::FractionalPrecision)
+    }
+}
+
+pub(crate) fn write_bits(
+    f: &mut Formatter<'_>,
+    negative: bool,
+    abs: u128,
+    precision: u32,
+) -> Result {
+    let coef = 10u128.pow(precision);
+    let sign = if negative { "-" } else { "" };
+    let integral = abs / coef;
+    let mut fractional = abs % coef;
+
+    if fractional == 0 {
+        return write!(f, "{}{}", sign, integral);
+    }
+
+    let mut width = precision as usize;
+    while fractional / 10 * 10 == fractional {
+        fractional /= 10;
+        width -= 1;
+    }
+
+    write!(
+        f,
+        "{}{}.{:0width$}",
+        sign,
+        integral,
+        fractional,
+        width = width
+    )
 }
 
 impl Display for ConvertError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.write_str(self.as_str())
+        f.write_str(self.as_str())?;
+
+        match self.details {
+            Details::None => Ok(()),
+            Details::IntegralOverflow {
+                min,
+                max,
+                precision,
+            } => {
+                f.write_str(": must be in [")?;
+                write_bits(f, min < 0, min.unsigned_abs(), precision)?;
+                f.write_str(", ")?;
+                write_bits(f, false, max, precision)?;
+                f.write_str("]")
+            }
+            Details::FractionalPrecision { precision } => {
+                write!(f, ": at most {} fractional digits", precision)
+            }
+        }
     }
 }
 
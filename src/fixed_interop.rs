@@ -0,0 +1,163 @@
+#[cfg(feature = "i128")]
+use crate::i256_polyfill::i256;
+use crate::{
+    layout::Promotion,
+    ops::{One, RoundMode, Zero},
+    ConvertError, ConvertErrorKind, FixedPoint, Precision,
+};
+
+macro_rules! impl_fixed_interop {
+    (
+        inner = $layout:tt;
+        promoted_to = $promotion:tt;
+        fixed = $fixed:ident: $le_eq:ident;
+        example = ($example:literal, $example_frac:ident, $example_precision:ident);
+    ) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "fixed-interop")))]
+        impl<P: Precision> FixedPoint<$layout, P> {
+            #[doc = concat!("Converts a binary fixed-point number [`fixed::", stringify!($fixed), "`] into")]
+            /// a decimal one, rounding according to `mode` if the value isn't representable exactly.
+            ///
+            /// The conversion is performed via the promoted integer, so the only possible error is
+            /// an overflow of the result.
+            ///
+            /// ```
+            #[doc = concat!("# #[cfg(feature = \"", $example, "\")]")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("use fixed::{", stringify!($fixed), ", types::extra::", stringify!($example_frac), "};")]
+            #[doc = concat!("use fixnum::{FixedPoint, typenum::", stringify!($example_precision), ", ops::RoundMode::*};")]
+            ///
+            #[doc = concat!("type Amount = FixedPoint<", stringify!($layout), ", ", stringify!($example_precision), ">;")]
+            #[doc = concat!("type Binary = ", stringify!($fixed), "<", stringify!($example_frac), ">;")]
+            ///
+            /// // 0.1 isn't representable in binary, so the nearest value is converted.
+            /// let binary = Binary::from_num(0.1);
+            /// assert_eq!(Amount::from_fixed(binary, Nearest)?, "0.1".parse()?);
+            /// assert_eq!(Amount::from_fixed(binary, Ceil)?, "0.11".parse()?);
+            /// assert_eq!(Amount::from_fixed(Binary::from_num(-2.5), Floor)?, "-2.5".parse()?);
+            /// # Ok(()) }
+            #[doc = concat!("# #[cfg(not(feature = \"", $example, "\"))]")]
+            /// # fn main() {}
+            /// ```
+            pub fn from_fixed<Frac: fixed::types::extra::$le_eq>(
+                value: fixed::$fixed<Frac>,
+                mode: RoundMode,
+            ) -> Result<Self, ConvertError> {
+                let frac_nbits = Frac::U32;
+                let scaled = $promotion::from(value.to_bits()).mul_l(Self::COEF);
+
+                // Arithmetic shift rounds towards negative infinity, so `0 <= rem < 2^frac_nbits`.
+                let floor = scaled >> frac_nbits;
+                let rem = scaled - (floor << frac_nbits);
+
+                let add_one = if rem == $promotion::ZERO {
+                    false
+                } else {
+                    let negative = value.to_bits() < 0;
+                    let unit = $promotion::ONE << frac_nbits;
+                    // The magnitude lost by truncation towards zero.
+                    let lost = if negative { unit - rem } else { rem };
+                    let is_floor_odd = floor - ((floor >> 1) << 1) != $promotion::ZERO;
+                    let away = mode.is_away_from_zero(negative, lost.cmp(&(unit - lost)), is_floor_odd != negative);
+                    // `floor` is already rounded away from zero for negative numbers.
+                    away != negative
+                };
+
+                let result = if add_one {
+                    floor + $promotion::ONE
+                } else {
+                    floor
+                };
+
+                result
+                    .try_into()
+                    .map(Self::from_bits)
+                    .map_err(|_| Self::integral_overflow())
+            }
+
+            #[doc = concat!("Converts the number into a binary fixed-point number [`fixed::", stringify!($fixed), "`],")]
+            /// rounding according to `mode` if the value isn't representable exactly.
+            ///
+            /// The conversion is performed via the promoted integer, so the only possible error is
+            /// an overflow of the result.
+            ///
+            /// ```
+            #[doc = concat!("# #[cfg(feature = \"", $example, "\")]")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            #[doc = concat!("use fixed::{", stringify!($fixed), ", types::extra::", stringify!($example_frac), "};")]
+            #[doc = concat!("use fixnum::{FixedPoint, typenum::", stringify!($example_precision), ", ops::RoundMode::*};")]
+            ///
+            #[doc = concat!("type Amount = FixedPoint<", stringify!($layout), ", ", stringify!($example_precision), ">;")]
+            #[doc = concat!("type Binary = ", stringify!($fixed), "<", stringify!($example_frac), ">;")]
+            ///
+            /// let amount: Amount = "2.5".parse()?;
+            /// let binary: Binary = amount.to_fixed(Floor)?;
+            /// assert_eq!(binary, Binary::from_num(2.5));
+            ///
+            /// // 0.1 isn't representable in binary, so the result depends on the rounding mode.
+            /// let amount: Amount = "0.1".parse()?;
+            /// let floor: Binary = amount.to_fixed(Floor)?;
+            /// let ceil: Binary = amount.to_fixed(Ceil)?;
+            /// assert_eq!(floor + Binary::DELTA, ceil);
+            /// # Ok(()) }
+            #[doc = concat!("# #[cfg(not(feature = \"", $example, "\"))]")]
+            /// # fn main() {}
+            /// ```
+            pub fn to_fixed<Frac: fixed::types::extra::$le_eq>(
+                self,
+                mode: RoundMode,
+            ) -> Result<fixed::$fixed<Frac>, ConvertError> {
+                let numerator = $promotion::from(self.inner) << Frac::U32;
+                // `|loss| < COEF`, thus it fits in the layout.
+                let (result, loss) = numerator.div_rem_l(Self::COEF);
+
+                let add_signed_one = loss != 0 && {
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(Self::COEF.unsigned_abs() - loss_abs));
+                    let is_odd = result - ((result >> 1) << 1) != $promotion::ZERO;
+                    mode.is_away_from_zero(self.inner < 0, half, is_odd)
+                };
+
+                let result = if add_signed_one {
+                    result + $promotion::from(self.inner.signum())
+                } else {
+                    result
+                };
+
+                result
+                    .try_into()
+                    .map(fixed::$fixed::from_bits)
+                    .map_err(|_| ConvertError::new(ConvertErrorKind::IntegralOverflow, "too big number"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_fixed_interop!(
+    inner = i16;
+    promoted_to = i32;
+    fixed = FixedI16: LeEqU16;
+    example = ("i16", U8, U2);
+);
+#[cfg(feature = "i32")]
+impl_fixed_interop!(
+    inner = i32;
+    promoted_to = i64;
+    fixed = FixedI32: LeEqU32;
+    example = ("i32", U16, U2);
+);
+#[cfg(feature = "i64")]
+impl_fixed_interop!(
+    inner = i64;
+    promoted_to = i128;
+    fixed = FixedI64: LeEqU64;
+    example = ("i64", U32, U2);
+);
+#[cfg(feature = "i128")]
+impl_fixed_interop!(
+    inner = i128;
+    promoted_to = i256;
+    fixed = FixedI128: LeEqU128;
+    example = ("i128", U64, U2);
+);
@@ -1,10 +1,12 @@
+#[cfg(feature = "audit")]
+use crate::audit;
 use crate::{
     ops::Zero,
     power_table::{
         power_of_10, rdiv_by_exponent_10, MAX_EXPONENT_5, NEXT_EXPONENT_10, POWERS_OF_10,
         POWERS_OF_5,
     },
-    ConvertError, FixedPoint, Precision,
+    ConvertError, ConvertErrorKind, FixedPoint, Precision,
 };
 
 macro_rules! impl_try_from_f64 {
@@ -12,12 +14,26 @@ macro_rules! impl_try_from_f64 {
         impl<P: Precision> TryFrom<f64> for FixedPoint<$layout, P> {
             type Error = ConvertError;
 
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                let result = Self::from_f64(value);
+
+                #[cfg(feature = "audit")]
+                if let Ok(fixed) = result {
+                    let loss = (value - fixed.to_f64()).abs();
+                    audit::record(audit::Direction::FromF64, Self::PRECISION, value, loss);
+                }
+
+                result
+            }
+        }
+
+        impl<P: Precision> FixedPoint<$layout, P> {
             /// Implementation courtesy of [`rust_decimal` crate][rust_decimal]
             ///
             /// [rust_decimal]: https://github.com/paupino/rust-decimal/blob/2de2a6dd2f385e98c4019ebe38b5c6de5fef6cba/src/decimal.rs#L2059
-            fn try_from(value: f64) -> Result<Self, Self::Error> {
+            pub(crate) fn from_f64(value: f64) -> Result<Self, ConvertError> {
                 if !value.is_finite() {
-                    return Err(ConvertError::new("not finite"));
+                    return Err(ConvertError::new(ConvertErrorKind::NotFinite, "not finite"));
                 }
 
                 // f64 is being broken up by bits i.e. 1/11/52 (sign, biased_exponent, mantissa)
@@ -160,7 +176,7 @@ macro_rules! impl_try_from_f64 {
                     // will cause the significand to overflow.
                     bits = power_of_10((exponent10 + Self::PRECISION) as u32)
                         .and_then(|multiplier| bits.checked_mul(multiplier))
-                        .ok_or_else(|| ConvertError::new("too big number"))?;
+                        .ok_or_else(Self::integral_overflow)?;
                 } else if exponent10 < -Self::PRECISION {
                     // In order to bring exponent up to -PRECISION, the significand should
                     // be divided by 10 to compensate. If the exponent10 is too small, this
@@ -168,16 +184,14 @@ macro_rules! impl_try_from_f64 {
                     bits = rdiv_by_exponent_10(bits, (-Self::PRECISION - exponent10) as u32);
                 }
 
-                let bits: $layout = bits
-                    .try_into()
-                    .map_err(|_| ConvertError::new("too big number"))?;
+                let bits: $layout = bits.try_into().map_err(|_| Self::integral_overflow())?;
 
                 if positive {
                     Ok(Self::from_bits(bits))
                 } else {
                     bits.checked_neg()
                         .map(Self::from_bits)
-                        .ok_or_else(|| ConvertError::new("too big number"))
+                        .ok_or_else(Self::integral_overflow)
                 }
             }
         }
@@ -193,3 +207,11 @@ impl_try_from_f64!(i32);
 impl_try_from_f64!(i64);
 #[cfg(feature = "i128")]
 impl_try_from_f64!(i128);
+#[cfg(feature = "u16")]
+impl_try_from_f64!(u16);
+#[cfg(feature = "u32")]
+impl_try_from_f64!(u32);
+#[cfg(feature = "u64")]
+impl_try_from_f64!(u64);
+#[cfg(feature = "u128")]
+impl_try_from_f64!(u128);
@@ -0,0 +1,42 @@
+use core::{fmt, str::FromStr};
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::FixedPoint;
+
+/// A fixed-point decimal number. Serialized as a string to keep all digits,
+/// parsed from a string or a number, extra fractional digits are rounded to nearest.
+///
+/// All fixed-point types share the GraphQL name `FixedPoint`, so a schema using several of them
+/// should be built with `ignore_name_conflicts(["FixedPoint"])`.
+#[Scalar(name = "FixedPoint")]
+impl<I, P> ScalarType for FixedPoint<I, P>
+where
+    Self: FromStr + TryFrom<i64> + TryFrom<u64> + TryFrom<f64> + fmt::Display + Send + Sync,
+    <Self as FromStr>::Err: fmt::Display,
+    <Self as TryFrom<i64>>::Error: fmt::Display,
+    <Self as TryFrom<u64>>::Error: fmt::Display,
+    <Self as TryFrom<f64>>::Error: fmt::Display,
+{
+    fn parse(value: Value) -> InputValueResult<Self> {
+        match &value {
+            Value::String(s) => s.parse().map_err(InputValueError::custom),
+            // Integers are converted exactly, other numbers are parsed as `f64` with rounding.
+            Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+                (Some(i), _, _) => Self::try_from(i).map_err(InputValueError::custom),
+                (_, Some(u), _) => Self::try_from(u).map_err(InputValueError::custom),
+                (_, _, Some(f)) => Self::try_from(f).map_err(InputValueError::custom),
+                _ => Err(InputValueError::expected_type(value)),
+            },
+            _ => Err(InputValueError::expected_type(value)),
+        }
+    }
+
+    fn is_valid(value: &Value) -> bool {
+        matches!(value, Value::String(_) | Value::Number(_))
+    }
+
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
@@ -6,7 +6,7 @@ use ::i256::i256 as i256_;
 use crate::{
     layout::Promotion,
     ops::{One, Zero},
-    ConvertError,
+    ConvertError, ConvertErrorKind,
 };
 
 /// A polyfill for i256.
@@ -40,6 +40,13 @@ impl i256 {
     const fn new(lo: u128, hi: i128) -> Self {
         Self(i256_::new(lo, hi))
     }
+
+    /// Returns the value if it fits `i128`, which is much faster to operate on.
+    #[inline]
+    fn to_i128(self) -> Option<i128> {
+        let value = self.0.as_i128();
+        (Self::from_i128(value) == self).then_some(value)
+    }
 }
 
 impl Promotion for i256 {
@@ -65,18 +72,45 @@ impl Promotion for i256 {
         self.0.leading_zeros()
     }
 
+    #[inline]
+    fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
     #[inline]
     fn mul_l(&self, rhs: Self::Layout) -> Self {
+        // Fast path: the product fits `i128`.
+        if let Some(product) = self.to_i128().and_then(|lhs| lhs.checked_mul(rhs)) {
+            return Self::from_i128(product);
+        }
+
         Self(self.0.mul_iwide(rhs))
     }
 
+    #[inline]
+    fn checked_mul_l(&self, rhs: Self::Layout) -> Option<Self> {
+        self.0.checked_mul_iwide(rhs).map(Self)
+    }
+
     #[inline]
     fn div_l(&self, rhs: Self::Layout) -> Self {
+        // Fast path: the dividend fits `i128`. `MIN / -1` is left to the slow path.
+        if let Some(div) = self.to_i128().and_then(|lhs| lhs.checked_div(rhs)) {
+            return Self::from_i128(div);
+        }
+
         Self(self.0.div_iwide(rhs))
     }
 
     #[inline]
     fn div_rem_l(&self, rhs: Self::Layout) -> (Self, Self::Layout) {
+        // Fast path: the dividend fits `i128`. `MIN / -1` is left to the slow path.
+        if let Some(lhs) = self.to_i128() {
+            if let (Some(div), Some(rem)) = (lhs.checked_div(rhs), lhs.checked_rem(rhs)) {
+                return (Self::from_i128(div), rem);
+            }
+        }
+
         let (div, rem) = self.0.div_rem_iwide(rhs);
         (Self(div), rem)
     }
@@ -177,7 +211,10 @@ impl TryFrom<i256> for i128 {
     #[inline]
     fn try_from(x: i256) -> Result<Self, Self::Error> {
         if !(i256::I128_MIN..=i256::I128_MAX).contains(&x) {
-            return Err(ConvertError::new("not in range"));
+            return Err(ConvertError::new(
+                ConvertErrorKind::IntegralOverflow,
+                "not in range",
+            ));
         }
 
         Ok(x.0.as_i128())
@@ -190,7 +227,10 @@ impl TryFrom<i256> for i64 {
     #[inline]
     fn try_from(x: i256) -> Result<Self, Self::Error> {
         if !(i256::I64_MIN..=i256::I64_MAX).contains(&x) {
-            return Err(ConvertError::new("not in range"));
+            return Err(ConvertError::new(
+                ConvertErrorKind::IntegralOverflow,
+                "not in range",
+            ));
         }
 
         Ok(x.0.as_i64())
@@ -346,6 +386,33 @@ mod tests {
         t(-35, 5, -7);
     }
 
+    #[test]
+    fn fast_path_matches_wide() {
+        fn t(a: i256, b: i128) {
+            let (div, rem) = a.0.div_rem_iwide(b);
+            assert_eq!(a.div_rem_l(b), (i256(div), rem));
+            assert_eq!(a.div_l(b), i256(a.0.div_iwide(b)));
+        }
+
+        let values = [
+            1,
+            -1,
+            7,
+            -7,
+            1_000_000_007,
+            i64::MAX as i128,
+            i128::MAX,
+            i128::MIN,
+        ];
+        for a in values {
+            for b in values {
+                assert_eq!(i256::from(a).mul_l(b), i256(i256_::from(a).mul_iwide(b)));
+                t(i256::from(a), b);
+                t(i256::from(a).mul_l(b), b);
+            }
+        }
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn as_positive_f64() {
@@ -0,0 +1,23 @@
+//! Wide integers used for promoted math of the `i128` layout.
+//!
+//! [`I256`] and [`U256`] implement the same API as primitive integers: operators
+//! (`Add`, `Sub`, `Mul`, `Div`, `Rem`, `Neg`, `Shl`, `Shr` and bitwise ones), checked, wrapping,
+//! overflowing and saturating variants, `FromStr`, `Display` and conversions from primitives.
+//!
+//! ```
+//! use fixnum::int::{I256, U256};
+//!
+//! let a = I256::from(i128::MAX);
+//! let b = a * I256::from(4);
+//! assert_eq!(b / a, I256::from(4));
+//! assert_eq!(b % a, I256::from(0));
+//! assert_eq!(-b >> 2u32, -a);
+//! assert_eq!(a.checked_mul(a).and_then(|x| x.checked_mul(a)), None);
+//!
+//! let c = U256::from(u128::MAX) + U256::from(1u8);
+//! assert_eq!(c, U256::from(1u8) << 128u32);
+//! assert_eq!(c.to_string().parse::<U256>()?, c);
+//! # Ok::<(), fixnum::int::ParseIntError>(())
+//! ```
+
+pub use ::i256::{ParseIntError, TryFromIntError, I256, U256};
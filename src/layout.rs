@@ -1,9 +1,9 @@
 use core::convert::{From, TryInto};
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 
 #[doc(hidden)]
 pub trait Promotion:
-    Sized + Ord + Neg + Add + Sub + Mul + Div + From<Self::Layout> + TryInto<Self::Layout>
+    Sized + Ord + Add + Sub + Mul + Div + From<Self::Layout> + TryInto<Self::Layout>
 {
     type Layout;
 
@@ -11,12 +11,21 @@ pub trait Promotion:
     #[cfg(feature = "std")]
     fn as_positive_f64(&self) -> f64;
     fn leading_zeros(&self) -> u32;
+    fn checked_add(&self, rhs: Self) -> Option<Self>;
     fn mul_l(&self, rhs: Self::Layout) -> Self;
+    fn checked_mul_l(&self, rhs: Self::Layout) -> Option<Self>;
     fn div_l(&self, rhs: Self::Layout) -> Self;
     fn div_rem_l(&self, rhs: Self::Layout) -> (Self, Self::Layout);
 }
 
-#[cfg(any(feature = "i16", feature = "i32", feature = "i64"))]
+#[cfg(any(
+    feature = "i16",
+    feature = "i32",
+    feature = "i64",
+    feature = "u16",
+    feature = "u32",
+    feature = "u64"
+))]
 macro_rules! promotion {
     ($layout:ty => $prom:ty) => {
         impl Promotion for $prom {
@@ -38,11 +47,21 @@ macro_rules! promotion {
                 (*self).leading_zeros()
             }
 
+            #[inline]
+            fn checked_add(&self, rhs: Self) -> Option<Self> {
+                <$prom>::checked_add(*self, rhs)
+            }
+
             #[inline]
             fn mul_l(&self, rhs: Self::Layout) -> Self {
                 self * rhs as $prom
             }
 
+            #[inline]
+            fn checked_mul_l(&self, rhs: Self::Layout) -> Option<Self> {
+                self.checked_mul(rhs as $prom)
+            }
+
             #[inline]
             fn div_l(&self, rhs: Self::Layout) -> Self {
                 self / rhs as $prom
@@ -62,4 +81,18 @@ promotion!(i16 => i32);
 promotion!(i32 => i64);
 #[cfg(feature = "i64")]
 promotion!(i64 => i128);
+// NOTE: `SplitI128` is implemented in the `split_i128` module.
 // NOTE: i128 => i256 is implemented in the `i256_polyfill` module.
+#[cfg(feature = "u16")]
+promotion!(u16 => u32);
+#[cfg(feature = "u32")]
+promotion!(u32 => u64);
+#[cfg(feature = "u64")]
+promotion!(u64 => u128);
+// NOTE: u128 => u256 is implemented in the `u256_polyfill` module.
+
+/// The promotion of the `i64` layout.
+#[cfg(all(feature = "i64", not(feature = "split-i128")))]
+pub(crate) type I64Promotion = i128;
+#[cfg(feature = "split-i128")]
+pub(crate) type I64Promotion = crate::split_i128::SplitI128;
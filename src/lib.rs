@@ -2,23 +2,66 @@
 //!
 //! [Fixed-point][FixedPoint] numbers with explicit rounding.
 //!
-//! Uses various signed integer types to store the number.
+//! Uses various signed and unsigned integer types to store the number.
 //!
 //! ## Features
 //! Turn them on in `Cargo.toml`:
 //!
+//! - `i256` — [`I256`] layout support which will be promoted to a polyfill for `u512` for
+//!   multiplication and division. Enables `i128`.
 //! - `i128` — `i128` layout support which will be promoted to a polyfill for `i256` for
 //!   multiplication and division.
 //! - `i64` — `i64` layout support which will be promoted to `i128` for multiplication and division.
+//! - `split-i128` — promotes the `i64` layout to a 128-bit integer of two `u64` words instead of
+//!   the native `i128`, whose arithmetic is emulated by slow and bloaty `compiler-rt` routines on
+//!   some targets (e.g. microcontrollers). Enables `i64`.
 //! - `i32` — `i32` layout support which will be promoted to `i64` for multiplication and division.
 //! - `i16` — `i16` layout support which will be promoted to `i32` for multiplication and division.
+//! - `u128` — `u128` layout support which will be promoted to a polyfill for `u256` for
+//!   multiplication and division.
+//! - `u64` — `u64` layout support which will be promoted to `u128` for multiplication and division.
+//! - `u32` — `u32` layout support which will be promoted to `u64` for multiplication and division.
+//! - `u16` — `u16` layout support which will be promoted to `u32` for multiplication and division.
+//! - `fixed-interop` — checked conversions from and to binary fixed-point numbers of
+//!   the [`fixed`][fixed] crate.
 //! - `parity` — [`parity-scale-codec`][parity_scale_codec] support (`Encode` and `Decode`
 //!   implementations).
-//! - `serde` — support for `serde`.
+//! - `serde` — support for `serde`. Deserialization never panics on untrusted input.
 //! - `schemars` — support for `schemars`.
+//! - `utoipa` — support for `utoipa`: `FixedPoint` is a string with a pattern limiting
+//!   fractional digits by `PRECISION`. Requires `std`.
+//! - `async-graphql` — the `FixedPoint` scalar of `async-graphql`, serialized as a string
+//!   and parsed from a string or a number. Requires `std`.
+//! - `clap` — [`clap`] value parser validating bounds and the tick size of arguments with
+//!   clear diagnostics. Requires `std`.
+//! - `redis` — `ToRedisArgs` and `FromRedisValue` of `redis`: values are stored as canonical
+//!   strings and parsed exactly, without conversions through `f64`. Requires `std`.
+//! - `prost` — [`prost`] messages of `google.type.Money` and `google.type.Decimal` with exact
+//!   conversions from and to `FixedPoint`.
+//! - `const-generics` — [`ConstFixedPoint`] specifying the precision by a const generic,
+//!   e.g. `ConstFixedPoint<i64, 9>` instead of `FixedPoint<i64, U9>`.
+//! - `ct` — hides masks of `FixedPoint::select` and `FixedPoint::abs_branchless` from
+//!   the optimizer by [`core::hint::black_box`], so they are less likely to be compiled into
+//!   branches. It's best-effort hardening, not a constant-time guarantee.
+//! - `testgen` — [`testgen`] generating edge-case-heavy numbers for property tests.
+//! - `deterministic` — uses only integer arithmetic, e.g. for initial guesses of `rsqrt`.
+//!   Computed values never depend on features (including `std`), it's checked by shared
+//!   test vectors, but the feature rules out floating-point instructions completely.
+//! - `trig` — `rsin`, `rcos`, `rtan`, `ratan` and `ratan2` of signed layouts implemented with
+//!   CORDIC, using only integer shifts and additions. Doesn't require `std`.
+//! - `audit` — [`audit`] recording conversions from and to `f64` with their loss, for
+//!   migrating float codebases. Requires `std`.
+//! - `analysis` — [`analysis`] measuring rounding errors of formulas against a high-precision
+//!   reference. Enables `i256`.
+//! - `inventory` — [`registry`] of fixed-point types compiled into the binary, listed by
+//!   [`introspect()`].
+//! - `ops` — `core::ops` operators (`a + b`, `a - b`, `a * int`, `a / int` and their assigning
+//!   versions, `-a` for signed layouts) and `Sum`, `Product` of iterators panicking on overflow,
+//!   for prototyping. The checked API is still recommended.
 //! - `std` — Enabled by default.
 //!
-//! At least one of `i128`, `i64`, `i32`, `i16` must be enabled.
+//! At least one of `i128`, `i64`, `i32`, `i16` must be enabled. Unsigned features enable
+//! the signed ones of the same size.
 //!
 //! ## Example
 //! ```
@@ -59,6 +102,7 @@
 //! | [`cmul`][cmul] | `let result: Result<FixedPoint, ArithmeticError> = a.cmul(b)` | Checked multiplication. Returns `Err` on overflow. This is multiplication without rounding, hence it's available only when at least one operand is integer. |
 //! | [`rmul`][rmul] | `let result: Result<FixedPoint, ArithmeticError> = a.rmul(b, RoundMode::Ceil)` | Checked rounding multiplication. Returns `Err` on overflow. Because of provided [`RoundMode`][RoundMode] it's possible across the [`FixedPoint`][FixedPoint] values. |
 //! | [`rdiv`][rdiv] | `let result: Result<FixedPoint, ArithmeticError> = a.rdiv(b, RoundMode::Floor)` | Checked [rounding][RoundMode] division. Returns `Err` on overflow. |
+//! | [`rmul_div`][rmul_div] | `let result: Result<FixedPoint, ArithmeticError> = a.rmul_div(b, c, RoundMode::Floor)` | Checked `a * b / c` with the only [rounding][RoundMode]. Returns `Err` on overflow. |
 //! | [`rsqrt`][rsqrt] | `let result: Result<FixedPoint, ArithmeticError> = a.rsqrt(RoundMode::Floor)` | Checked [rounding][RoundMode] square root. Returns `Err` for negative argument. |
 //! | [`cneg`][cneg] | `let result: Result<FixedPoint, ArithmeticError> = a.cneg()` | Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]). |
 //! | [`integral`][integral] | `let y: {integer} = x.integral(RoundMode::Floor)` | Takes [rounded][RoundMode] integral part of the number. |
@@ -66,6 +110,7 @@
 //! | [`saturating_sub`][saturating_sub] | `let z: FixedPoint = x.saturating_sub(y)` | Saturating subtraction |
 //! | [`saturating_mul`][saturating_mul] | `let z: FixedPoint = x.saturating_mul(y)` | Saturating multiplication. This is multiplication without rounding, hence it's available only when at least one operand is integer. |
 //! | [`saturating_rmul`][saturating_rmul] | `let z: FixedPoint = x.saturating_rmul(y, RoundMode::Floor)` | Saturating [rounding][RoundMode] multiplication |
+//! | [`saturating_rdiv`][saturating_rdiv] | `let z: FixedPoint = x.saturating_rdiv(y, RoundMode::Floor)` | Saturating [rounding][RoundMode] division. Panics if `y` is zero. |
 //!
 //! ## Implementing wrapper types.
 //! It's possible to restrict the domain in order to reduce chance of mistakes.
@@ -97,8 +142,13 @@
 //! impl_op!(Price [rdiv] Price = Ratio);
 //! impl_op!(Price [rmul] Ratio = Price);
 //! impl_op!(PriceDelta [cadd] PriceDelta = PriceDelta);
+//! impl_op!([neg] PriceDelta = PriceDelta);
 //! impl_op!(Amount [cadd] Amount = Amount);
 //! impl_op!(Amount [csub] Amount = Amount);
+//! // `Zero` and `Bounded`, required by `saturating_*` methods.
+//! impl_op!([bounded] Size);
+//! impl_op!([bounded] Price);
+//! impl_op!([bounded] Amount);
 //!
 //! // Use it.
 //! use fixnum::ops::*;
@@ -106,6 +156,9 @@
 //! let price = fixnum!(4.25, 9); // compile-time
 //! let amount = size.cmul(price)?;
 //! assert_eq!(amount, fixnum!(17, 9));
+//! let delta = Price(fixnum!(1, 9)).csub(price)?;
+//! assert_eq!(-delta, fixnum!(3.25, 9));
+//! assert_eq!(size.saturating_mul(Price(Fp64::MAX)), Amount(Fp64::MAX));
 //! # Ok(()) }
 //! # #[cfg(not(feature = "i64"))]
 //! # fn main() {}
@@ -115,6 +168,7 @@
 //! [cneg]: ./struct.FixedPoint.html#method.cneg
 //! [csub]: ./ops/trait.CheckedSub.html#tymethod.csub
 //! [cmul]: ./ops/trait.CheckedMul.html#tymethod.cmul
+//! [fixed]: https://docs.rs/fixed
 //! [fixnum]: ./macro.fixnum.html
 //! [FixedPoint]: ./struct.FixedPoint.html
 //! [integral]: ./struct.FixedPoint.html#method.integral
@@ -122,10 +176,12 @@
 //! [parity_scale_codec]: https://docs.rs/parity-scale-codec
 //! [rdiv]: ./ops/trait.RoundingDiv.html#tymethod.rdiv
 //! [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
+//! [rmul_div]: ./ops/trait.RoundingMulDiv.html#tymethod.rmul_div
 //! [rsqrt]: ./struct.FixedPoint.html#method.rsqrt
 //! [RoundMode]: ./ops/enum.RoundMode.html
 //! [saturating_add]: ./ops/trait.CheckedAdd.html#tymethod.saturating_add
 //! [saturating_mul]: ./ops/trait.CheckedMul.html#tymethod.saturating_mul
+//! [saturating_rdiv]: ./ops/trait.RoundingDiv.html#tymethod.saturating_rdiv
 //! [saturating_rmul]: ./ops/trait.RoundingMul.html#tymethod.saturating_rmul
 //! [saturating_sub]: ./ops/trait.CheckedSub.html#tymethod.saturating_sub
 
@@ -134,47 +190,118 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
-use core::{cmp::Ord, fmt, marker::PhantomData};
+use core::{
+    cmp::{Ord, Ordering},
+    fmt,
+    marker::PhantomData,
+    num::{Saturating, Wrapping},
+};
 
-use typenum::Unsigned;
+use typenum::{IsLessOrEqual, True, Unsigned};
 
 #[cfg(feature = "i128")]
 use crate::i256_polyfill::i256;
+#[cfg(feature = "i64")]
+use crate::layout::I64Promotion;
 use crate::ops::{sqrt::Sqrt, *};
 use crate::string::Stringify;
+#[cfg(feature = "u128")]
+use crate::u256_polyfill::u256;
 
+mod binary;
 mod const_fn;
+mod dynamic;
 mod errors;
+#[cfg(feature = "fixed-interop")]
+mod fixed_interop;
 mod float;
 #[cfg(feature = "i128")]
 mod i256_polyfill;
 mod layout;
 mod macros;
+mod non_zero;
+#[cfg(feature = "ops")]
+mod operators;
 #[cfg(feature = "parity")]
 mod parity;
 mod power_table;
+#[cfg(feature = "split-i128")]
+mod split_i128;
 mod string;
+mod transcendental;
+#[cfg(feature = "u128")]
+mod u256_polyfill;
+#[cfg(feature = "i256")]
+mod u512_polyfill;
+mod unit_interval;
+#[cfg(any(feature = "u16", feature = "u32", feature = "u64", feature = "u128"))]
+mod unsigned;
+#[cfg(feature = "i256")]
+mod wide;
 
 #[cfg(not(any(feature = "i16", feature = "i32", feature = "i64", feature = "i128")))]
 compile_error!("Some of the next features must be enabled: \"i128\", \"i64\", \"i32\", \"i16\"");
 
+pub use binary::Binary;
+pub use dynamic::DynFixedPoint;
 pub use errors::*;
+#[cfg(feature = "i256")]
+#[cfg_attr(docsrs, doc(cfg(feature = "i256")))]
+pub use int::I256;
+#[cfg(feature = "inventory")]
+#[cfg_attr(docsrs, doc(cfg(feature = "inventory")))]
+pub use registry::introspect;
 pub use typenum;
-
+pub use unit_interval::UnitInterval;
+
+#[cfg(feature = "analysis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "analysis")))]
+pub mod analysis;
+#[cfg(feature = "audit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "audit")))]
+pub mod audit;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod book;
+#[cfg(feature = "clap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+pub mod clap;
+#[cfg(feature = "i128")]
+#[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+pub mod int;
 pub mod ops;
+#[cfg(feature = "prost")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prost")))]
+pub mod prost;
+#[cfg(feature = "inventory")]
+#[cfg_attr(docsrs, doc(cfg(feature = "inventory")))]
+pub mod registry;
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serde;
-
+pub mod slice;
+pub mod stats;
+#[cfg(feature = "testgen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testgen")))]
+pub mod testgen;
+
+#[cfg(feature = "async-graphql")]
+mod graphql;
+#[cfg(feature = "redis")]
+mod redis;
 #[cfg(feature = "schemars")]
 mod schemars;
+#[cfg(feature = "utoipa")]
+mod utoipa;
 
 #[doc(hidden)]
 pub mod _priv {
     pub use crate::const_fn::*;
     pub use crate::layout::*;
-    pub use crate::macros::Operand;
+    pub use crate::macros::{is_within_ulps, Operand};
     pub use crate::ops::*;
+    #[cfg(feature = "inventory")]
+    pub use inventory;
 }
 
 type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
@@ -195,6 +322,11 @@ type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
 /// MAX = (2 ^ (64 - 1) - 1) / 1e9 = 9223372036.854775807 ~ 9.2e9
 /// ERROR_MAX = 0.5 / 1e9 = 5e-10
 /// ```
+///
+/// Every value has the only representation, there is no negative zero: `"-0.0"`, negative
+/// numbers rounded to zero and `-0.0f64` are all parsed into [`ZERO`](Zero::ZERO), which is
+/// formatted as `0.0` and converted into the positive `0.0f64`. Thus, `Eq` and `Hash` are
+/// consistent with the numeric equality.
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
     docsrs,
@@ -207,11 +339,59 @@ pub struct FixedPoint<I, P> {
 }
 
 /// The number of digits in the fractional part.
-pub trait Precision: Unsigned {}
-impl<U: Unsigned> Precision for U {}
+///
+/// `Ord` is required to compare numbers in precision-generic code.
+///
+/// Precisions above [`MaxPrecision`] don't fit any layout and are rejected at compile time,
+/// e.g. `FixedPoint<i128, U100>` is an error instead of overflowing the coefficient:
+/// ```compile_fail
+/// use fixnum::{Precision, typenum::U100};
+///
+/// fn precision<P: Precision>() -> u32 {
+///     P::U32
+/// }
+///
+/// precision::<U100>();
+/// ```
+pub trait Precision: Unsigned + Ord + IsLessOrEqual<MaxPrecision, Output = True> {}
+impl<U> Precision for U where U: Unsigned + Ord + IsLessOrEqual<MaxPrecision, Output = True> {}
+
+/// The largest [`Precision`], `10^76` is the largest power of ten fitting in `I256`.
+pub type MaxPrecision = typenum::U76;
+
+/// [`FixedPoint`] with the precision specified by a const generic instead of `typenum`,
+/// e.g. `ConstFixedPoint<i64, 9>` is the same type as `FixedPoint<i64, U9>`.
+///
+/// It's a type alias, so both forms are interchangeable without any conversion.
+/// Generic code requires `Const<P>: ToUInt` to use it. Note that `P` cannot be inferred
+/// from an argument, specify it explicitly:
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ConstFixedPoint, FixedPoint, Precision, typenum::{Const, ToUInt, U, U9}};
+///
+/// fn precision<const P: usize>(_: ConstFixedPoint<i64, P>) -> i32
+/// where
+///     Const<P>: ToUInt,
+///     U<P>: Precision,
+/// {
+///     ConstFixedPoint::<i64, P>::PRECISION
+/// }
+///
+/// let a: ConstFixedPoint<i64, 9> = "1.5".parse()?;
+/// let b: FixedPoint<i64, U9> = a;
+/// assert_eq!(precision::<9>(b), 9);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "const-generics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "const-generics")))]
+pub type ConstFixedPoint<I, const P: usize> = FixedPoint<I, typenum::U<P>>;
 
 impl<I, P> FixedPoint<I, P> {
     /// Creates from the raw representation. `1` here is equal to `1**-P`
+    #[inline]
     pub const fn from_bits(raw: I) -> Self {
         FixedPoint {
             inner: raw,
@@ -220,6 +400,7 @@ impl<I, P> FixedPoint<I, P> {
     }
 
     /// Returns the raw representation.
+    #[inline]
     pub const fn as_bits(&self) -> &I {
         &self.inner
     }
@@ -229,13 +410,66 @@ impl<I, P> FixedPoint<I, P> {
     pub fn into_bits(self) -> I {
         self.inner
     }
+
+    /// Reinterprets a slice of fixed-point numbers as a slice of their raw representations.
+    ///
+    /// It's a zero-cost conversion useful for FFI and columnar storages.
+    #[inline]
+    pub fn slice_as_bits(values: &[Self]) -> &[I] {
+        // `FixedPoint` is `repr(transparent)` over `I`, so slices have the same memory layout.
+        unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<I>(), values.len()) }
+    }
+
+    /// Reinterprets a slice of raw representations as a slice of fixed-point numbers.
+    ///
+    /// It's a zero-cost conversion useful for FFI and columnar storages.
+    #[inline]
+    pub fn slice_from_bits(raws: &[I]) -> &[Self] {
+        // `FixedPoint` is `repr(transparent)` over `I`, so slices have the same memory layout.
+        unsafe { core::slice::from_raw_parts(raws.as_ptr().cast::<Self>(), raws.len()) }
+    }
+}
+
+/// Views the raw representation as [`Wrapping`], e.g. for hashing or checksum algorithms.
+/// `1` here is equal to `1**-P`.
+impl<I, P> From<FixedPoint<I, P>> for Wrapping<I> {
+    #[inline]
+    fn from(value: FixedPoint<I, P>) -> Self {
+        Wrapping(value.inner)
+    }
+}
+
+/// Creates from the raw representation wrapped in [`Wrapping`].
+impl<I, P> From<Wrapping<I>> for FixedPoint<I, P> {
+    #[inline]
+    fn from(value: Wrapping<I>) -> Self {
+        Self::from_bits(value.0)
+    }
+}
+
+/// Views the raw representation as [`Saturating`]. `1` here is equal to `1**-P`.
+impl<I, P> From<FixedPoint<I, P>> for Saturating<I> {
+    #[inline]
+    fn from(value: FixedPoint<I, P>) -> Self {
+        Saturating(value.inner)
+    }
+}
+
+/// Creates from the raw representation wrapped in [`Saturating`].
+impl<I, P> From<Saturating<I>> for FixedPoint<I, P> {
+    #[inline]
+    fn from(value: Saturating<I>) -> Self {
+        Self::from_bits(value.0)
+    }
 }
 
 macro_rules! impl_fixed_point {
     (
         $(#[$attr:meta])?
         inner = $layout:tt;
+        unsigned = $unsigned:tt;
         promoted_to = $promotion:tt;
+        max_precision = $max_precision:literal;
         try_from = [$($try_from:ty),*];
     ) => {const _: () = {
         use $crate::_priv::Promotion as _;
@@ -246,9 +480,52 @@ macro_rules! impl_fixed_point {
             pub const PRECISION: i32 = P::I32;
             /// The difference between `0.0` and the next larger representable number.
             pub const EPSILON: Self = Self::from_bits(1);
-
-            const COEF: $layout = const_fn::pow10(Self::PRECISION) as _;
-            const NEG_COEF: $layout = -Self::COEF;
+            /// `0.5`. Fails to compile for the zero precision, where it isn't representable.
+            pub const ONE_HALF: Self = {
+                assert!(P::U32 > 0, "`ONE_HALF` isn't representable with the zero precision");
+                Self::from_bits(Self::COEF / 2)
+            };
+            /// `10`. Fails to compile if it doesn't fit the layout.
+            pub const TEN: Self = Self::from_bits(Self::COEF * 10);
+            /// `100`. Fails to compile if it doesn't fit the layout.
+            pub const HUNDRED: Self = Self::from_bits(Self::COEF * 100);
+            /// [`MAX`] in the decimal form, the same as `MAX.to_string()`, but available in const
+            /// contexts, e.g. it's used by [`fixnum_type!`](crate::fixnum_type).
+            ///
+            /// [`MAX`]: ./ops/trait.Bounded.html#associatedconstant.MAX
+            pub const MAX_STR: &'static str = const_fn::decimal_str(Self::MAX_DECIMAL);
+            /// [`EPSILON`](Self::EPSILON) in the decimal form, the same as `EPSILON.to_string()`.
+            pub const EPSILON_STR: &'static str = const_fn::decimal_str(Self::EPSILON_DECIMAL);
+
+            const MAX_DECIMAL: &'static const_fn::Decimal =
+                &const_fn::write_decimal(const_fn::to_limbs(<Self as Bounded>::MAX.inner as u128), P::U32);
+            const EPSILON_DECIMAL: &'static const_fn::Decimal =
+                &const_fn::write_decimal(const_fn::to_limbs(1), P::U32);
+
+            /// The largest precision of the layout, `10^MAX_PRECISION` is the largest power of
+            /// ten fitting in it.
+            pub const MAX_PRECISION: i32 = $max_precision;
+
+            const COEF: $layout = {
+                assert!(
+                    Self::PRECISION <= Self::MAX_PRECISION,
+                    concat!(
+                        "precision is too large for `",
+                        stringify!($layout),
+                        "`, the maximum is ",
+                        stringify!($max_precision),
+                    )
+                );
+                const_fn::pow10(Self::PRECISION) as _
+            };
+
+            fn integral_overflow() -> ConvertError {
+                ConvertError::integral_overflow(
+                    $layout::MIN as i128,
+                    $layout::MAX as u128,
+                    Self::PRECISION as u32,
+                )
+            }
         }
 
         $(#[$attr])?
@@ -275,26 +552,7 @@ macro_rules! impl_fixed_point {
             #[inline]
             fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
                 let value = $promotion::from(self.inner).mul_l(rhs.inner);
-                // `|loss| < COEF`, thus it fits in the layout.
-                let (result, loss) = value.div_rem_l(Self::COEF);
-
-                let mut result =
-                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
-
-                let sign = self.inner.signum() * rhs.inner.signum();
-
-                let add_signed_one = if mode == RoundMode::Nearest {
-                    sign as i32 >= 0 && loss + loss >= Self::COEF
-                                     || loss + loss <= Self::NEG_COEF
-                } else {
-                    loss != 0 && mode as i32 == sign as i32
-                };
-
-                if add_signed_one {
-                    result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
-                }
-
-                Ok(Self::from_bits(result))
+                Self::round_promoted(value, mode)
             }
         }
 
@@ -318,15 +576,43 @@ macro_rules! impl_fixed_point {
 
                 if loss != 0 {
                     let sign = self.inner.signum() * rhs.inner.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(rhs.inner.unsigned_abs() - loss_abs));
 
-                    let add_signed_one = if mode == RoundMode::Nearest {
-                        let loss_abs = loss.abs();
-                        loss_abs + loss_abs >= rhs.inner.abs()
-                    } else {
-                        mode as i32 == sign as i32
-                    };
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingMulDiv for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul_div(self, rhs: Self, divisor: Self, mode: RoundMode) -> Result<Self> {
+                if divisor.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `COEF`s cancel each other out, so raw values are enough.
+                let value = $promotion::from(self.inner).mul_l(rhs.inner);
+                // `|loss| < divisor`, thus it fits in the layout.
+                let (result, loss) = value.div_rem_l(divisor.inner);
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
 
-                    if add_signed_one {
+                if loss != 0 {
+                    let sign = self.inner.signum() * rhs.inner.signum() * divisor.inner.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(divisor.inner.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
                         result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                     }
                 }
@@ -335,6 +621,64 @@ macro_rules! impl_fixed_point {
             }
         }
 
+        $(#[$attr])?
+        impl<P: Precision> CheckedRem for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Quotient = $layout;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn crem(self, rhs: Self) -> Result<Self> {
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `MIN % -1` is `0`, but `checked_rem` considers it as overflow.
+                Ok(Self::from_bits(self.inner.wrapping_rem(rhs.inner)))
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Result<Self> {
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                Ok(Self::from_bits(self.inner.wrapping_rem_euclid(rhs.inner)))
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: Self) -> Result<$layout> {
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // Only `MIN / -1` overflows.
+                self.inner.checked_div_euclid(rhs.inner).ok_or(ArithmeticError::Overflow)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedRem<$layout> for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Quotient = $layout;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn crem(self, rhs: $layout) -> Result<Self> {
+                self.crem(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: $layout) -> Result<Self> {
+                self.rem_euclid(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: $layout) -> Result<$layout> {
+                self.div_euclid(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+            }
+        }
+
         $(#[$attr])?
         impl<P: Precision> RoundingDiv<$layout> for FixedPoint<$layout, P> {
             type Output = Self;
@@ -422,6 +766,85 @@ macro_rules! impl_fixed_point {
             }
         }
 
+        $(#[$attr])?
+        impl<P: Precision> crate::ops::sealed::Sealed for FixedPoint<$layout, P> {}
+
+        #[cfg(feature = "inventory")]
+        impl<P: Precision> crate::registry::Layout for FixedPoint<$layout, P> {
+            const LAYOUT: &'static str = stringify!($layout);
+            const PRECISION: u32 = P::U32;
+            const MIN_BITS: i128 = $layout::MIN as i128;
+            const MAX_BITS: u128 = $layout::MAX as u128;
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPointOps for FixedPoint<$layout, P> {
+            type Layout = $layout;
+
+            const PRECISION: i32 = Self::PRECISION;
+            const EPSILON: Self = Self::EPSILON;
+
+            #[inline]
+            fn from_bits(raw: $layout) -> Self {
+                Self::from_bits(raw)
+            }
+
+            #[inline]
+            fn into_bits(self) -> $layout {
+                Self::into_bits(self)
+            }
+
+            #[inline]
+            fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::rsqrt(self, mode)
+            }
+
+            #[inline]
+            fn recip(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::recip(self, mode)
+            }
+
+            #[inline]
+            fn from_ratio(numerator: $layout, denominator: $layout, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::from_ratio(numerator, denominator, mode)
+            }
+
+            #[inline]
+            fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+                Self::half_sum(a, b, mode)
+            }
+
+            #[inline]
+            fn integral(self, mode: RoundMode) -> $layout {
+                Self::integral(self, mode)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                Self::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                Self::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                Self::round(self)
+            }
+
+            #[inline]
+            fn from_decimal(mantissa: $layout, exponent: i32) -> Result<Self, ConvertError> {
+                Self::from_decimal(mantissa, exponent)
+            }
+
+            #[inline]
+            fn to_decimal(&self, max_exponent: i32) -> ($layout, i32) {
+                Self::to_decimal(self, max_exponent)
+            }
+        }
+
         $(#[$attr])?
         impl<P: Precision> FixedPoint<$layout, P> {
             /// Returns a number representing sign of self.
@@ -433,208 +856,1433 @@ macro_rules! impl_fixed_point {
                 self.inner.signum()
             }
 
-            /// Returns `1/n`.
+            /// Returns [`signum`](Self::signum) as a number, i.e. `-1`, `0` or `1`, so it can be
+            /// multiplied back with [`rmul`](ops::RoundingMul::rmul).
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{RoundingMul, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let position: Amount = "-2.5".parse()?;
+            /// let price: Amount = "4".parse()?;
+            /// assert_eq!(position.signum_fp(), "-1".parse()?);
+            /// assert_eq!(position.signum_fp().rmul(price, Floor)?, "-4".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
             #[inline]
-            pub fn recip(self, mode: RoundMode) -> Result<Self> {
-                Self::ONE.rdiv(self, mode)
+            pub fn signum_fp(self) -> Self {
+                Self::from_bits(self.inner.signum() * Self::COEF)
             }
 
-            /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
+            /// Returns a number with the magnitude of `self` and the sign of `sign`, like
+            /// [`f64::copysign`]. Zero is considered positive.
+            ///
+            /// Returns `Err` on overflow (you can't make [`MIN` value][MIN] positive).
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, Zero}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "1.5".parse()?;
+            /// let b: Amount = "-2".parse()?;
+            /// assert_eq!(a.copysign(b)?, "-1.5".parse()?);
+            /// assert_eq!(b.copysign(a)?, "2".parse()?);
+            /// assert_eq!(b.copysign(Amount::ZERO)?, "2".parse()?);
+            /// assert!(Amount::MIN.copysign(a).is_err());
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
             ///
             /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
             #[inline]
-            pub fn cneg(self) -> Result<Self> {
-                self.inner
-                    .checked_neg()
-                    .map(Self::from_bits)
-                    .ok_or_else(|| ArithmeticError::Overflow)
-            }
-
-            /// Calculates `(a + b) / 2`.
-            #[inline]
-            pub fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
-                if a.inner.signum() != b.inner.signum() {
-                    Self::from_bits(a.inner + b.inner).rdiv(2, mode).unwrap()
+            pub fn copysign(self, sign: Self) -> Result<Self> {
+                if (self.inner < 0) == (sign.inner < 0) {
+                    Ok(self)
                 } else {
-                    let min = a.inner.min(b.inner);
-                    let max = a.inner.max(b.inner);
-                    let half_diff = (max - min).rdiv(2, mode).unwrap();
-                    Self::from_bits(min + half_diff)
+                    self.cneg()
                 }
             }
 
-            /// Takes [rounded][RoundMode] integral part of the number.
+            /// Returns `true` if the number is zero.
             ///
+            /// Predicates take `&self` to be usable in `#[serde(skip_serializing_if = "...")]`:
             /// ```
-            /// # #[cfg(feature = "i64")]
+            /// # #[cfg(all(feature = "serde", feature = "i64"))]
             /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            /// use fixnum::{FixedPoint, typenum::U9};
+            /// use serde::Serialize;
             ///
             /// type Amount = FixedPoint<i64, U9>;
             ///
-            /// let a: Amount = "8273.519".parse()?;
-            /// assert_eq!(a.integral(Floor), 8273);
-            /// assert_eq!(a.integral(Nearest), 8274);
-            /// assert_eq!(a.integral(Ceil), 8274);
+            /// #[derive(Serialize)]
+            /// struct Fill {
+            ///     amount: Amount,
+            ///     #[serde(skip_serializing_if = "Amount::is_zero")]
+            ///     fee: Amount,
+            /// }
             ///
-            /// let a: Amount = "-8273.519".parse()?;
-            /// assert_eq!(a.integral(Floor), -8274);
-            /// assert_eq!(a.integral(Nearest), -8274);
-            /// assert_eq!(a.integral(Ceil), -8273);
+            /// let fill = Fill { amount: "1.5".parse()?, fee: "0".parse()? };
+            /// assert_eq!(serde_json::to_string(&fill)?, r#"{"amount":"1.5"}"#);
+            /// assert!(fill.amount.is_positive());
+            /// assert!(!fill.amount.is_negative());
             /// # Ok(()) }
-            /// # #[cfg(not(feature = "i64"))]
+            /// # #[cfg(not(all(feature = "serde", feature = "i64")))]
             /// # fn main() {}
             /// ```
             #[inline]
-            pub fn integral(self, mode: RoundMode) -> $layout {
-                let sign = self.inner.signum();
-                let (mut int, frac) = (self.inner / Self::COEF, self.inner.abs() % Self::COEF);
-
-                let add_signed_one = if mode == RoundMode::Nearest {
-                    frac + frac >= Self::COEF
-                } else {
-                    mode as i32 == sign as i32 && frac > 0
-                };
-
-                if add_signed_one {
-                    int += sign;
-                }
+            pub const fn is_zero(&self) -> bool {
+                self.inner == 0
+            }
 
-                int
+            /// Returns `true` if the number is greater than zero.
+            #[inline]
+            pub const fn is_positive(&self) -> bool {
+                self.inner > 0
             }
 
-            /// Returns the largest integer less than or equal to a number.
+            /// Returns `true` if the number is less than zero.
             #[inline]
-            pub fn floor(self) -> Self {
-                Self::from_decimal(self.integral(RoundMode::Floor), 0).unwrap()
+            pub const fn is_negative(&self) -> bool {
+                self.inner < 0
             }
 
-            /// Returns the smallest integer greater than or equal to a number.
+            /// Returns `true` if the number has no fractional part, e.g. to validate lot sizes
+            /// without [`round_towards_zero_by`](Self::round_towards_zero_by).
             #[inline]
-            pub fn ceil(self) -> Self {
-                Self::from_decimal(self.integral(RoundMode::Ceil), 0).unwrap()
+            pub const fn is_integer(&self) -> bool {
+                self.inner % Self::COEF == 0
             }
 
-            /// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
+            /// Returns `true` if the number is in `[0, 1]`, e.g. it's a valid probability or weight.
             #[inline]
-            pub fn round(self) -> Self {
-                Self::from_decimal(self.integral(RoundMode::Nearest), 0).unwrap()
+            pub fn is_ratio(self) -> bool {
+                0 <= self.inner && self.inner <= Self::COEF
             }
 
-            /// Rounds towards zero by the provided precision.
+            /// Clamps the number to `[0, 1]`.
             #[inline]
-            pub fn round_towards_zero_by(self, precision: Self) -> Self {
-                self.inner
-                    .checked_div(precision.inner)
-                    .and_then(|v| v.checked_mul(precision.inner))
-                    .map_or(self, Self::from_bits)
+            pub fn clamp01(self) -> Self {
+                Self::from_bits(self.inner.clamp(0, Self::COEF))
             }
 
-            /// Returns the next power of ten:
-            /// * For positive: the smallest greater than or equal to a number.
-            /// * For negative: the largest less than or equal to a number.
+            /// Returns the minimum of two numbers, like [`Ord::min`], but usable in const contexts.
             #[inline]
-            pub fn next_power_of_ten(self) -> Result<Self> {
-                if self.inner < 0 {
-                    return self.cneg()?.next_power_of_ten()?.cneg();
+            pub const fn min(self, other: Self) -> Self {
+                if other.inner < self.inner {
+                    other
+                } else {
+                    self
                 }
+            }
 
-                let lz = self.inner.leading_zeros() as usize;
-                assert!(lz > 0, "unexpected negative value");
-
-                let value = power_table::$layout[lz];
-
-                let value = if self.inner > value {
-                    power_table::$layout[lz - 1]
+            /// Returns the maximum of two numbers, like [`Ord::max`], but usable in const contexts.
+            #[inline]
+            pub const fn max(self, other: Self) -> Self {
+                if other.inner < self.inner {
+                    self
                 } else {
-                    value
-                };
-
-                if value == 0 {
-                    return Err(ArithmeticError::Overflow);
+                    other
+                }
+            }
+
+            /// Restricts the number to `[min, max]`, like [`Ord::clamp`], but usable in const
+            /// contexts.
+            ///
+            /// # Panics
+            /// If `min > max`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, fixnum_const};
+            ///
+            /// type Leverage = FixedPoint<i64, U9>;
+            ///
+            /// const MIN_LEVERAGE: Leverage = fixnum_const!(1, 9);
+            /// const MAX_LEVERAGE: Leverage = fixnum_const!(125, 9);
+            /// const REQUESTED_LEVERAGE: Leverage = fixnum_const!(200, 9);
+            /// const LEVERAGE: Leverage = REQUESTED_LEVERAGE.clamp(MIN_LEVERAGE, MAX_LEVERAGE);
+            ///
+            /// assert_eq!(LEVERAGE, MAX_LEVERAGE);
+            /// assert_eq!(MIN_LEVERAGE.min(MAX_LEVERAGE), MIN_LEVERAGE);
+            /// assert_eq!(MIN_LEVERAGE.max(MAX_LEVERAGE), MAX_LEVERAGE);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min.inner <= max.inner, "assertion failed: min <= max");
+                if self.inner < min.inner {
+                    min
+                } else if self.inner > max.inner {
+                    max
+                } else {
+                    self
+                }
+            }
+
+            /// Returns `1/n`.
+            #[inline]
+            pub fn recip(self, mode: RoundMode) -> Result<Self> {
+                Self::ONE.rdiv(self, mode)
+            }
+
+            /// Divides by `rhs` like [`rdiv`][RoundingDiv::rdiv], but returns `default`
+            /// instead of `Err(DivisionByZero)` if `rhs` is zero.
+            ///
+            /// Other errors (e.g. overflow) are still returned.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{RoundMode::*, Zero}};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let volume: Price = "10".parse()?;
+            /// let amount: Price = "25".parse()?;
+            /// assert_eq!(amount.rdiv_or(volume, Price::ZERO, Floor)?, "2.5".parse()?);
+            /// assert_eq!(amount.rdiv_or(Price::ZERO, Price::ZERO, Floor)?, Price::ZERO);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn rdiv_or(self, rhs: Self, default: Self, mode: RoundMode) -> Result<Self> {
+                match self.rdiv(rhs, mode) {
+                    Err(ArithmeticError::DivisionByZero) => Ok(default),
+                    result => result,
+                }
+            }
+
+            /// Returns `1/n` like [`recip`](Self::recip), but returns `default` instead of
+            /// `Err(DivisionByZero)` if the number is zero.
+            #[inline]
+            pub fn recip_or(self, default: Self, mode: RoundMode) -> Result<Self> {
+                Self::ONE.rdiv_or(self, default, mode)
+            }
+
+            /// Divides by an integer like [`rdiv`][RoundingDiv::rdiv], but also returns the exact
+            /// remainder `self - quotient * rhs`, so the rounding residue can be carried forward
+            /// instead of being dropped. The remainder is less than `rhs` by magnitude, but its sign
+            /// depends on the rounding.
+            ///
+            /// Only integer divisors are supported, because the exact remainder of the division
+            /// by a fractional number can require twice as many fractional digits.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let total: Amount = "100".parse()?;
+            /// let (installment, residue) = total.rdiv_rem(3, Nearest)?;
+            /// assert_eq!(installment, "33.333333333".parse()?);
+            /// assert_eq!(residue, "0.000000001".parse()?);
+            ///
+            /// let (installment, residue) = total.rdiv_rem(3, Ceil)?;
+            /// assert_eq!(installment, "33.333333334".parse()?);
+            /// assert_eq!(residue, "-0.000000002".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn rdiv_rem(self, rhs: $layout, mode: RoundMode) -> Result<(Self, Self)> {
+                let quotient = self.inner.rdiv(rhs, mode)?;
+                // The exact remainder fits the layout, so wrapping intermediate results are fine.
+                let remainder = self.inner.wrapping_sub(quotient.wrapping_mul(rhs));
+                Ok((Self::from_bits(quotient), Self::from_bits(remainder)))
+            }
+
+            /// Splits `self` into parts proportional to `weights`, which sum exactly to `self`.
+            ///
+            /// Every part is the exact share truncated to `EPSILON`, then the rest (less than
+            /// `EPSILON` times the number of nonzero weights) is distributed by the largest
+            /// remainder method: parts with the largest truncated remainders receive one
+            /// `EPSILON` each, ties are broken by position. Parts with zero weights are always
+            /// zero, and all parts have the sign of `self`.
+            ///
+            /// Returns `Err(DomainViolation)` if some weight is negative, `Err(DivisionByZero)`
+            /// if all weights are zero and `Err(Overflow)` if the sum of weights doesn't fit
+            /// the layout.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let amount: Amount = "100".parse()?;
+            /// let parts = amount.allocate(&[3, 2, 1])?;
+            /// assert_eq!(parts, ["50".parse()?, "33.333333333".parse()?, "16.666666667".parse()?]);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn allocate<const N: usize>(self, weights: &[$layout; N]) -> Result<[Self; N]> {
+                let mut total: $layout = 0;
+                for &weight in weights {
+                    if weight < 0 {
+                        return Err(ArithmeticError::DomainViolation);
+                    }
+                    total = total.checked_add(weight).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                if total == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let mut parts = [Self::ZERO; N];
+                let mut remainders = [0; N];
+                let mut rest = self.inner;
+                for ((part, remainder), &weight) in parts.iter_mut().zip(&mut remainders).zip(weights) {
+                    let share = $promotion::from(self.inner).mul_l(weight);
+                    // `|part| <= |self|` and `|remainder| < total`, thus both fit the layout.
+                    let (quotient, loss) = share.div_rem_l(total);
+                    part.inner = quotient.as_layout();
+                    *remainder = loss.unsigned_abs();
+                    rest -= part.inner;
+                }
+
+                // The rest has the sign of `self` and it's less than the number of nonzero
+                // weights by magnitude, which have nonzero remainders in this case.
+                let mut order = [0; N];
+                for (i, index) in order.iter_mut().enumerate() {
+                    *index = i;
+                }
+                order.sort_unstable_by_key(|&i| (core::cmp::Reverse(remainders[i]), i));
+
+                let (sign, rest) = (self.inner.signum(), rest.unsigned_abs());
+                for &i in order.iter().take(rest as usize) {
+                    parts[i].inner += sign;
+                }
+
+                Ok(parts)
+            }
+
+            /// Constructs the quotient `numerator / denominator` of two integers, rounding it
+            /// according to `mode`. E.g. converts counts into rates.
+            ///
+            /// The numerator is scaled in the promoted type, so no precision is lost
+            /// before the rounding.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Rate = FixedPoint<i64, U9>;
+            ///
+            /// assert_eq!(Rate::from_ratio(1, 3, Floor)?, "0.333333333".parse()?);
+            /// assert_eq!(Rate::from_ratio(-2, 3, Nearest)?, "-0.666666667".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn from_ratio(numerator: $layout, denominator: $layout, mode: RoundMode) -> Result<Self> {
+                Self::from_bits(numerator).rdiv(Self::from_bits(denominator), mode)
+            }
+
+            /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn cneg(self) -> Result<Self> {
+                self.inner
+                    .checked_neg()
+                    .map(Self::from_bits)
+                    .ok_or_else(|| ArithmeticError::Overflow)
+            }
+
+            /// Calculates `self + rhs`, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            ///
+            /// It's cheaper than [`cadd`](CheckedAdd::cadd) in tight loops where an overflow
+            /// is handled once for many operations.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "0.1".parse()?;
+            /// let b: Amount = "0.2".parse()?;
+            /// assert_eq!(a.overflowing_add(b), ("0.3".parse()?, false));
+            /// assert_eq!(Amount::MAX.overflowing_add(Amount::EPSILON), (Amount::MIN, true));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_add(rhs.inner);
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Calculates `self - rhs`, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_sub(rhs.inner);
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Calculates `self * rhs`, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: $layout) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_mul(rhs);
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Negates the number, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            ///
+            /// Only [`MIN`][MIN] overflows, it's returned as is.
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub const fn overflowing_neg(self) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_neg();
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Calculates `(a + b) / 2`, rounding the same as `(a + b).rdiv(2, mode)` would
+            /// if the sum didn't overflow.
+            ///
+            /// It never fails: the sum of halves is calculated instead of the halved sum,
+            /// and the result lies between `a` and `b`.
+            #[inline]
+            pub fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+                let (a, b) = (a.inner, b.inner);
+                // Arithmetic shifts round towards negative infinity, the lost halves are summed.
+                let floor = (a >> 1) + (b >> 1) + (a & b & 1);
+                if (a ^ b) & 1 == 0 {
+                    return Self::from_bits(floor);
+                }
+
+                // The exact result is `floor + 0.5`, it's negative only if `floor` is.
+                let negative = floor < 0;
+                let truncated = if negative { floor + 1 } else { floor };
+
+                // Both `floor` and `floor + 1` lie between `a` and `b`.
+                if mode.is_away_from_zero(negative, Ordering::Equal, truncated % 2 != 0) {
+                    Self::from_bits(if negative { floor } else { floor + 1 })
+                } else {
+                    Self::from_bits(truncated)
+                }
+            }
+
+            /// Calculates `a + (b - a) * t`, the linear interpolation between `a` and `b`.
+            ///
+            /// The whole expression is evaluated in the promoted type and rounded only once, so
+            /// `b - a` can't overflow and the result is `a` and `b` exactly for `t = 0` and `t = 1`.
+            /// Values of `t` outside `[0, 1]` extrapolate and return `Err(Overflow)` if the result
+            /// doesn't fit.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*}};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let a: Price = "-1".parse()?;
+            /// let b: Price = "2".parse()?;
+            /// assert_eq!(Price::lerp(a, b, "0.25".parse()?, Floor)?, "-0.25".parse()?);
+            /// assert_eq!(Price::lerp(a, b, "0.333333333".parse()?, Nearest)?, "-0.000000001".parse()?);
+            /// assert_eq!(Price::lerp(Price::MIN, Price::MAX, "0.5".parse()?, Ceil)?, "0".parse()?);
+            /// assert_eq!(Price::lerp(Price::MIN, Price::MAX, "1".parse()?, Ceil)?, Price::MAX);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn lerp(a: Self, b: Self, t: Self, mode: RoundMode) -> Result<Self> {
+                let start = $promotion::from(a.inner);
+                let numerator = ($promotion::from(b.inner) - start)
+                    .checked_mul_l(t.inner)
+                    .and_then(|delta| delta.checked_add(start.mul_l(Self::COEF)))
+                    .ok_or(ArithmeticError::Overflow)?;
+                // `|loss| < COEF`, thus it fits in the layout.
+                let (result, loss) = numerator.div_rem_l(Self::COEF);
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != 0 {
+                    // The loss has the sign of the numerator.
+                    let sign = loss.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(Self::COEF.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+
+            /// Applies a slippage of `bps` basis points, i.e. calculates
+            /// `self * (1 + bps / 10000)` with the only rounding.
+            ///
+            /// The rounding follows the trade direction regardless of the sign of the number:
+            /// use `Ceil` for buys, so the limit is never less than the exact one, and `Floor`
+            /// for sells. Returns `Err(Overflow)` if the result doesn't fit.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let price: Price = "0.123456789".parse()?;
+            /// assert_eq!(price.apply_bps(50, Ceil)?, "0.124074073".parse()?);
+            /// assert_eq!(price.apply_bps(-50, Floor)?, "0.122839505".parse()?);
+            ///
+            /// // Negative prices (e.g. of spreads) are rounded in the same direction.
+            /// let spread: Price = "-0.123456789".parse()?;
+            /// assert_eq!(spread.apply_bps(-50, Ceil)?, "-0.122839505".parse()?);
+            /// assert_eq!(spread.apply_bps(50, Floor)?, "-0.124074073".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn apply_bps(self, bps: i32, mode: RoundMode) -> Result<Self> {
+                const BPS_PER_ONE: $layout = 10_000;
+
+                let factor = $layout::try_from(i64::from(bps) + 10_000)
+                    .map_err(|_| ArithmeticError::Overflow)?;
+                // `COEF`s cancel each other out, so raw values are enough.
+                self.rmul_div(Self::from_bits(factor), Self::from_bits(BPS_PER_ONE), mode)
+            }
+
+            /// Takes [rounded][RoundMode] integral part of the number.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "8273.519".parse()?;
+            /// assert_eq!(a.integral(Floor), 8273);
+            /// assert_eq!(a.integral(Nearest), 8274);
+            /// assert_eq!(a.integral(Ceil), 8274);
+            ///
+            /// let a: Amount = "-8273.519".parse()?;
+            /// assert_eq!(a.integral(Floor), -8274);
+            /// assert_eq!(a.integral(Nearest), -8274);
+            /// assert_eq!(a.integral(Ceil), -8273);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn integral(self, mode: RoundMode) -> $layout {
+                let sign = self.inner.signum();
+                let (mut int, frac) = (self.inner / Self::COEF, (self.inner % Self::COEF).abs());
+
+                if frac != 0 && mode.is_away_from_zero(sign < 0, frac.cmp(&(Self::COEF - frac)), int % 2 != 0) {
+                    int += sign;
+                }
+
+                int
+            }
+
+            #[inline]
+            fn integral_fp(self, mode: RoundMode) -> Self {
+                let int = self.integral(mode);
+                // Rounding moves the value by less than `1`, thus the previous integer fits.
+                let inner = int
+                    .checked_mul(Self::COEF)
+                    .unwrap_or_else(|| (int - int.signum()) * Self::COEF);
+                Self::from_bits(inner)
+            }
+
+            /// Returns the largest integer less than or equal to a number.
+            /// If the result doesn't fit (only possible near `MIN`), saturates to the nearest
+            /// representable integer, which is greater than the number in this case.
+            #[inline]
+            pub fn floor(self) -> Self {
+                self.integral_fp(RoundMode::Floor)
+            }
+
+            /// Returns the smallest integer greater than or equal to a number.
+            /// If the result doesn't fit (only possible near `MAX`), saturates to the nearest
+            /// representable integer, which is less than the number in this case.
+            #[inline]
+            pub fn ceil(self) -> Self {
+                self.integral_fp(RoundMode::Ceil)
+            }
+
+            /// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
+            /// If the result doesn't fit (only possible near `MIN` or `MAX`), saturates to the nearest
+            /// representable integer.
+            #[inline]
+            pub fn round(self) -> Self {
+                self.integral_fp(RoundMode::Nearest)
+            }
+
+            /// Returns the integer part of a number, i.e. rounds towards zero. It never fails.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedAdd};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "-8273.519".parse()?;
+            /// assert_eq!(a.trunc(), "-8273".parse()?);
+            /// assert_eq!(a.fract(), "-0.519".parse()?);
+            /// assert_eq!(a.trunc().cadd(a.fract())?, a);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn trunc(self) -> Self {
+                Self::from_bits(self.inner / Self::COEF * Self::COEF)
+            }
+
+            /// Returns the fractional part of a number, it has the sign of the number.
+            /// `trunc() + fract()` is equal to the number.
+            #[inline]
+            pub fn fract(self) -> Self {
+                Self::from_bits(self.inner % Self::COEF)
+            }
+
+            /// Rounds towards zero by the provided precision.
+            ///
+            /// It never fails: the result lies between zero and `self`.
+            /// The number is returned unchanged if `precision` is zero.
+            #[inline]
+            pub fn round_towards_zero_by(self, precision: Self) -> Self {
+                // `None` only if `precision` is zero or for `MIN % -1`, which is zero.
+                let loss = self.inner.checked_rem(precision.inner).unwrap_or(0);
+                Self::from_bits(self.inner - loss)
+            }
+
+            /// Rounds the number to `decimal_places` digits after the point according to `mode`.
+            /// The number is returned unchanged if `decimal_places >= PRECISION`.
+            ///
+            /// Returns `Err(Overflow)` if the rounded number doesn't fit (only possible near `MIN` or `MAX`).
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "-2.345".parse()?;
+            /// assert_eq!(a.round_to(2, Floor)?, "-2.35".parse()?);
+            /// assert_eq!(a.round_to(2, Nearest)?, "-2.35".parse()?);
+            /// assert_eq!(a.round_to(2, HalfEven)?, "-2.34".parse()?);
+            /// assert_eq!(a.round_to(0, Ceil)?, "-2".parse()?);
+            /// assert_eq!(a.round_to(12, Floor)?, a);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn round_to(self, decimal_places: u32, mode: RoundMode) -> Result<Self> {
+                if decimal_places >= P::U32 {
+                    return Ok(self);
+                }
+
+                // `step <= COEF`, thus it fits in the layout.
+                let step = $layout::pow(10, P::U32 - decimal_places);
+                let steps = self.inner.rdiv(step, mode)?;
+                steps.checked_mul(step).map(Self::from_bits).ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Returns the next power of ten:
+            /// * For positive: the smallest greater than or equal to a number.
+            /// * For negative: the largest less than or equal to a number.
+            #[inline]
+            pub fn next_power_of_ten(self) -> Result<Self> {
+                if self.inner < 0 {
+                    return self.cneg()?.next_power_of_ten()?.cneg();
+                }
+
+                let lz = self.inner.leading_zeros() as usize;
+                assert!(lz > 0, "unexpected negative value");
+
+                let value = power_table::$layout[lz];
+
+                let value = if self.inner > value {
+                    power_table::$layout[lz - 1]
+                } else {
+                    value
+                };
+
+                if value == 0 {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                Ok(Self::from_bits(value))
+            }
+
+            /// Returns the absolute value of a number.
+            #[inline]
+            pub fn abs(self) -> Result<Self> {
+                if self.inner < 0 {
+                    self.cneg()
+                } else {
+                    Ok(self)
+                }
+            }
+
+            /// Returns the absolute value of a number like [`abs`](Self::abs), but without
+            /// branching on the sign. The only branch is on the overflow of [`MIN` value][MIN].
+            ///
+            /// The code itself is branchless, but the compiler is free to emit branches, thus
+            /// it's not guaranteed to be constant-time. The `ct` feature makes such branches
+            /// less likely.
+            ///
+            /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+            #[inline]
+            pub fn abs_branchless(self) -> Result<Self> {
+                // `0` for non-negative numbers and `-1` (all ones) for negative ones.
+                let sign = Self::ct_hide(self.inner >> ($layout::BITS - 1));
+                let abs = (self.inner ^ sign).wrapping_sub(sign);
+
+                if abs < 0 {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                Ok(Self::from_bits(abs))
+            }
+
+            /// Returns the absolute difference between two numbers as the unsigned counterpart of
+            /// the layout, like [`i64::abs_diff`]. Unlike `a.csub(b)?.abs()?`, it never overflows,
+            /// even for distances spanning more than half the range.
+            ///
+            /// ```
+            /// # #[cfg(feature = "u64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, CheckedSub}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            /// type Distance = FixedPoint<u64, U9>;
+            ///
+            /// let a: Amount = "1.5".parse()?;
+            /// let b: Amount = "-2".parse()?;
+            /// let distance: Distance = "3.5".parse()?;
+            /// assert_eq!(a.abs_diff(b), distance);
+            /// assert_eq!(b.abs_diff(a), distance);
+            ///
+            /// assert!(Amount::MAX.csub(Amount::MIN).is_err());
+            /// assert_eq!(Amount::MAX.abs_diff(Amount::MIN), Distance::MAX);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "u64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> FixedPoint<$unsigned, P> {
+                FixedPoint::from_bits(self.inner.abs_diff(other.inner))
+            }
+
+            /// Returns `a` if `cond` is `true` and `b` otherwise, without branching on `cond`.
+            /// The choice is made by masking raw bits.
+            ///
+            /// The code itself is branchless, but the compiler is free to emit branches, thus
+            /// it's not guaranteed to be constant-time. The `ct` feature makes such branches
+            /// less likely.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "1.5".parse()?;
+            /// let b: Amount = "-2".parse()?;
+            /// assert_eq!(Amount::select(true, a, b), a);
+            /// assert_eq!(Amount::select(false, a, b), b);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn select(cond: bool, a: Self, b: Self) -> Self {
+                // All ones if `cond` is `true` and zero otherwise.
+                let mask = Self::ct_hide(-(cond as $layout));
+                Self::from_bits(b.inner ^ (mask & (a.inner ^ b.inner)))
+            }
+
+            /// Discourages the optimizer from turning masks back into branches.
+            ///
+            /// `black_box` is only a hint, it can't be relied on for constant-time guarantees.
+            #[inline(always)]
+            fn ct_hide(mask: $layout) -> $layout {
+                #[cfg(feature = "ct")]
+                let mask = core::hint::black_box(mask);
+                mask
+            }
+
+            /// Checked [rounding][RoundMode] square root.
+            /// Returns `Err` for negative argument.
+            ///
+            /// Square root of a non-negative F is a non-negative S such that:
+            /// * `Floor`, `TowardZero`: `S ≤ sqrt(F)`
+            /// * `Ceil`, `AwayFromZero`: `S ≥ sqrt(F)`
+            /// * `Nearest`, `HalfEven`: `Floor` or `Ceil`, which one is closer to `sqrt(F)`
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
+            /// use fixnum::ops::{Zero, RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "81".parse()?;
+            /// let b: Amount = "2".parse()?;
+            /// let c: Amount = "-100".parse()?;
+            /// assert_eq!(a.rsqrt(Floor)?, "9".parse()?);
+            /// assert_eq!(b.rsqrt(Floor)?, "1.414213562".parse()?);
+            /// assert_eq!(b.rsqrt(Ceil)?, "1.414213563".parse()?);
+            /// assert_eq!(c.rsqrt(Floor), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                if self.inner.is_negative() {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                // At first we have `S_inner = S * COEF`.
+                // We'd like to gain `sqrt(S) * COEF`:
+                // `sqrt(S) * COEF = sqrt(S * COEF^2) = sqrt(S_inner * COEF)`
+                let squared = $promotion::from(self.inner).mul_l(Self::COEF);
+                let lo = squared.sqrt();
+
+                // We choose to round to the nearest value by comparing
+                //
+                //  squared - lo^2  and  (lo+1)^2 - squared
+                //
+                // There are no ties, because their sum `2lo + 1` is odd.
+                //
+                // However, we don't want to do calculations in the promoted type,
+                // because it can be slow (`i128` and `i256`). So, we use modular
+                // arithmetic (with `2^bits(layout)` modulus) to avoid it.
+                let lo2 = lo.wrapping_mul(lo);
+                // hi^2 = (lo+1)^2 = lo^2 + 2lo + 1
+                let hi2 = lo2.wrapping_add(lo).wrapping_add(lo).wrapping_add($layout::ONE);
+                let squared = squared.as_layout();
+                let half = squared.wrapping_sub(lo2).cmp(&hi2.wrapping_sub(squared));
+
+                let add_one = lo2 != squared && mode.is_away_from_zero(false, half, lo % 2 != 0);
+
+                let inner = if add_one {
+                    lo + $layout::ONE
+                } else {
+                    lo
+                };
+
+                Ok(Self::from_bits(inner))
+            }
+
+            /// Checked [rounding][RoundMode] cube root.
+            /// Unlike [`rsqrt`](Self::rsqrt), it's defined for negative arguments too.
+            ///
+            /// Cube root of F is C such that:
+            /// * `Floor`: `C ≤ cbrt(F)`
+            /// * `Ceil`: `C ≥ cbrt(F)`
+            /// * `TowardZero`: `|C| ≤ |cbrt(F)|`
+            /// * `AwayFromZero`: `|C| ≥ |cbrt(F)|`
+            /// * `Nearest`, `HalfEven`: `Floor` or `Ceil`, which one is closer to `cbrt(F)`
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9};
+            /// use fixnum::ops::RoundMode::*;
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "27".parse()?;
+            /// let b: Amount = "2".parse()?;
+            /// let c: Amount = "-2".parse()?;
+            /// assert_eq!(a.rcbrt(Floor)?, "3".parse()?);
+            /// assert_eq!(b.rcbrt(Floor)?, "1.259921049".parse()?);
+            /// assert_eq!(b.rcbrt(Ceil)?, "1.25992105".parse()?);
+            /// assert_eq!(c.rcbrt(Floor)?, "-1.25992105".parse()?);
+            /// assert_eq!(c.rcbrt(TowardZero)?, "-1.259921049".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rcbrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                if self.inner == 0 {
+                    return Ok(Self::ZERO);
+                }
+
+                let negative = self.inner < 0;
+
+                // Similar to `rsqrt`, `cbrt(C) * COEF = cbrt(C_inner * COEF^2)`.
+                // However, the radicand `N = |C_inner| * COEF^2` can overflow even the promoted
+                // type, so it's kept as `m * COEF`, where `m = |C_inner| * COEF` always fits.
+                let coef = if negative { -Self::COEF } else { Self::COEF };
+                let m = $promotion::from(self.inner).mul_l(coef);
+
+                // Returns `(floor(N / x), N mod x)`. The quotient fits the promoted type
+                // if `x ≥ cbrt(N)`, because it doesn't exceed `x^2` in this case.
+                let div = |x: $layout| {
+                    let (q, r) = m.div_rem_l(x);
+                    let (rq, rr) = $promotion::from(r).mul_l(Self::COEF).div_rem_l(x);
+                    (q.mul_l(Self::COEF) + rq, rr)
+                };
+
+                // Start from a power of two exceeding `cbrt(N)`. It fits the layout, because
+                // `cbrt(N) ≤ cbrt(MAX * COEF^2) ≤ MAX`.
+                let bits = 2 * $layout::BITS - m.leading_zeros()
+                    + $layout::BITS - Self::COEF.leading_zeros();
+                let shift = bits.div_ceil(3);
+                let mut lo: $layout = if shift < $layout::BITS - 1 {
+                    1 << shift
+                } else {
+                    $layout::MAX
+                };
+
+                // Newton's method descends monotonically to `floor(cbrt(N))` from any greater guess.
+                loop {
+                    let (q, _) = div(lo);
+                    let next = ($promotion::from(lo).mul_l(2) + q.div_l(lo)).div_l(3).as_layout();
+                    if next >= lo {
+                        break;
+                    }
+                    lo = next;
+                }
+
+                let (q, r) = div(lo);
+                let lo2 = $promotion::from(lo).mul_l(lo);
+
+                let add_one = (q != lo2 || r != 0) && {
+                    // `N - lo^3 = lo * (floor(N / lo) - lo^2) + N mod lo`, which is less than
+                    // `(lo+1)^3 - lo^3 = 3lo^2 + 3lo + 1`, so it fits the promoted type.
+                    let diff = (q - lo2).mul_l(lo) + $promotion::from(r);
+                    // `(lo+1/2)^3 - lo^3 = (12lo^2 + 6lo + 1) / 8`. The numerator is odd,
+                    // so there are no ties and it's enough to compare with the floor of it.
+                    let numerator = lo2.mul_l(4) + $promotion::from(lo).mul_l(6);
+                    let mid = lo2 + (numerator + $promotion::from($layout::ONE)).div_l(8);
+                    let half = if diff > mid { Ordering::Greater } else { Ordering::Less };
+                    mode.is_away_from_zero(negative, half, lo % 2 != 0)
+                };
+
+                let inner = if add_one { lo + $layout::ONE } else { lo };
+                Ok(Self::from_bits(if negative { -inner } else { inner }))
+            }
+
+            /// Checked sum of all values. Returns `ZERO` for an empty iterator and `Err`
+            /// on the first overflow, so the rest of values isn't consumed.
+            ///
+            /// It's a readable replacement for long chains of [`cadd`][cadd].
+            /// See also [`try_sum_of!`](crate::try_sum_of) for values of different types.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::Bounded};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "0.1".parse()?;
+            /// let b: Amount = "0.2".parse()?;
+            /// let c: Amount = "-0.3".parse()?;
+            /// assert_eq!(Amount::csum([a, b, c])?, "0".parse()?);
+            /// assert_eq!(Amount::csum([Amount::MAX, b, c]), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            ///
+            /// [cadd]: ./ops/trait.CheckedAdd.html#tymethod.cadd
+            #[inline]
+            pub fn csum(iter: impl IntoIterator<Item = Self>) -> Result<Self> {
+                iter.into_iter().try_fold(Self::ZERO, Self::cadd)
+            }
+
+            /// Checked [rounding][RoundMode] product of all values. Returns `ONE` for an empty
+            /// iterator and `Err` on overflow.
+            ///
+            /// Unlike folding with [`rmul`][rmul], which rounds after every multiplication and
+            /// accumulates up to `n - 1` rounding errors, partial products are kept in the promoted
+            /// type and the result is rounded only once at the end. Hence, the result is the exact
+            /// product rounded according to `mode`, as long as partial products fit the promoted
+            /// type (it's always true for two values and usually for small iterators).
+            /// Otherwise, a partial product is truncated to fewer decimal places in order
+            /// to continue, which can add an error of the order of `EPSILON`.
+            ///
+            /// Values are multiplied pairwise (a tree reduction): neighbours first, then products
+            /// of neighbouring pairs and so on. Partial products of a left fold grow with every
+            /// value, e.g. `x * x * x * ε * ε * ε` overflows the promoted type on `x * x * x`,
+            /// while the tree computes `((x * x) * (x * ε)) * (ε * ε)` and overflows only if
+            /// products of such blocks do. No memory is allocated, the stack of partial products
+            /// is bounded by `usize::BITS`.
+            ///
+            /// Also, partial products are allowed to be out of the layout's range.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{RoundingMul, RoundMode::*}};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let a: Amount = "0.5".parse()?;
+            /// let b: Amount = "0.000000001".parse()?;
+            /// let c: Amount = "3".parse()?;
+            /// // 0.5 * 1e-9 * 3 = 1.5e-9
+            /// assert_eq!(Amount::checked_product([a, b, c], Nearest)?, "0.000000002".parse()?);
+            /// assert_eq!(Amount::checked_product([a, b, c], Floor)?, "0.000000001".parse()?);
+            /// // Rounding after every step leads to a different result.
+            /// assert_eq!(a.rmul(b, Nearest)?.rmul(c, Nearest)?, "0.000000003".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            ///
+            /// [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
+            pub fn checked_product(
+                iter: impl IntoIterator<Item = Self>,
+                mode: RoundMode,
+            ) -> Result<Self> {
+                // Blocks of `2^level` values, the accumulator is equal to `product * COEF^scale`.
+                let mut stack = [($promotion::from(Self::ZERO.inner), 0, 0); usize::BITS as usize + 1];
+                let mut len = 0;
+                let mut sign = 1;
+                let mut inexact = false;
+
+                for value in iter {
+                    sign *= value.inner.signum();
+
+                    let (mut acc, mut scale, mut level) = ($promotion::from(value.inner), 1, 0);
+
+                    // Merge blocks of the same size, like a binary counter does.
+                    while len > 0 && stack[len - 1].2 == level {
+                        len -= 1;
+                        let (lhs, lhs_scale, _) = stack[len];
+                        (acc, scale) =
+                            Self::mul_partial((lhs, lhs_scale), (acc, scale), &mut inexact)?;
+                        level += 1;
+                    }
+
+                    stack[len] = (acc, scale, level);
+                    len += 1;
+                }
+
+                if len == 0 {
+                    return Ok(Self::ONE);
+                }
+
+                // Merge the rest, from smaller blocks to larger ones.
+                len -= 1;
+                let (mut acc, mut scale, _) = stack[len];
+                while len > 0 {
+                    len -= 1;
+                    let (lhs, lhs_scale, _) = stack[len];
+                    (acc, scale) = Self::mul_partial((lhs, lhs_scale), (acc, scale), &mut inexact)?;
+                }
+
+                while scale > 2 {
+                    let (truncated, loss) = acc.div_rem_l(Self::COEF);
+                    acc = truncated;
+                    inexact |= loss != 0;
+                    scale -= 1;
+                }
+
+                let (result, loss) = if scale == 2 {
+                    acc.div_rem_l(Self::COEF)
+                } else {
+                    (acc, 0)
+                };
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                // The product is exactly zero if any value is zero.
+                if sign != 0 && (loss != 0 || inexact) {
+                    let loss_abs = loss.abs();
+                    // Truncated digits are behind `loss`, so they matter only for exact ties.
+                    let half = match loss_abs.cmp(&(Self::COEF - loss_abs)) {
+                        Ordering::Equal if inexact => Ordering::Greater,
+                        half => half,
+                    };
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+
+            /// Multiplies partial products `(acc, scale)` of `checked_product`. If the product
+            /// doesn't fit the promoted type, the larger operand is truncated.
+            fn mul_partial(
+                (mut lhs, mut lhs_scale): ($promotion, u32),
+                (mut rhs, mut rhs_scale): ($promotion, u32),
+                inexact: &mut bool,
+            ) -> Result<($promotion, u32)> {
+                loop {
+                    let product = match ($layout::try_from(lhs), $layout::try_from(rhs)) {
+                        (_, Ok(rhs)) => lhs.checked_mul_l(rhs),
+                        (Ok(lhs), _) => rhs.checked_mul_l(lhs),
+                        _ => None,
+                    };
+
+                    if let Some(product) = product {
+                        return Ok((product, lhs_scale + rhs_scale));
+                    }
+
+                    let zero = $promotion::from(Self::ZERO.inner);
+                    let abs = |value: $promotion| if value < zero { zero - value } else { value };
+
+                    // Truncating the larger operand loses less significant digits.
+                    let (acc, scale) = match (lhs_scale > 1, rhs_scale > 1) {
+                        (true, true) if abs(lhs) >= abs(rhs) => (&mut lhs, &mut lhs_scale),
+                        (true, false) => (&mut lhs, &mut lhs_scale),
+                        (_, true) => (&mut rhs, &mut rhs_scale),
+                        (false, false) => return Err(ArithmeticError::Overflow),
+                    };
+
+                    let (truncated, loss) = acc.div_rem_l(Self::COEF);
+                    *acc = truncated;
+                    *inexact |= loss != 0;
+                    *scale -= 1;
+                }
+            }
+
+            /// Checked [rounding][RoundMode] integer power by squaring, e.g. a compound factor
+            /// `(1 + rate)^n`. Returns `ONE` for `n = 0` and `Err(Overflow)` if the result or
+            /// an intermediate power doesn't fit the layout.
+            ///
+            /// Only `O(log n)` multiplications are performed. Intermediate powers keep as many
+            /// significant digits as the layout can hold, so they have guard digits beyond
+            /// `PRECISION`, and the result is rounded according to `mode` once at the end.
+            /// Hence, the error is much lower than for `n - 1` successive [`rmul`][rmul]s and
+            /// usually the result is the exact power rounded according to `mode`. However, it isn't
+            /// guaranteed for big `n`, because errors of guard digits are multiplied too.
+            /// For directed modes intermediate powers are rounded in the same direction
+            /// by magnitude, so the result is never on the wrong side of the exact power,
+            /// e.g. `Floor` never exceeds it.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let factor: Amount = "1.05".parse()?;
+            /// // 1.05^10 = 1.62889462677744140625
+            /// assert_eq!(factor.rpowi(10, Nearest)?, "1.628894627".parse()?);
+            /// assert_eq!(factor.rpowi(0, Nearest)?, "1".parse()?);
+            /// let base: Amount = "-2".parse()?;
+            /// assert_eq!(base.rpowi(3, Nearest)?, "-8".parse()?);
+            /// assert_eq!(base.rpowi(64, Nearest), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            ///
+            /// [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
+            pub fn rpowi(self, n: u32, mode: RoundMode) -> Result<Self> {
+                if n == 0 {
+                    return Ok(Self::ONE);
                 }
 
-                Ok(Self::from_bits(value))
-            }
+                if n == 1 || self.inner == 0 {
+                    return Ok(self);
+                }
 
-            /// Returns the absolute value of a number.
-            #[inline]
-            pub fn abs(self) -> Result<Self> {
-                if self.inner < 0 {
-                    self.cneg()
-                } else {
-                    Ok(self)
+                let negative = self.inner < 0 && n % 2 == 1;
+                let intermediate = match mode {
+                    RoundMode::Nearest | RoundMode::HalfEven => RoundMode::Nearest,
+                    _ if mode.is_away_from_zero(negative, Ordering::Less, false) => {
+                        RoundMode::AwayFromZero
+                    }
+                    _ => RoundMode::TowardZero,
+                };
+
+                let mut acc = None;
+                let mut base = (self.inner, i64::from(Self::PRECISION), false);
+                let mut n = n;
+
+                loop {
+                    if n & 1 == 1 {
+                        acc = Some(match acc {
+                            // The highest bit is the last multiplication.
+                            Some(acc) if n == 1 => return Self::round_power(acc, base, mode),
+                            Some(acc) => Self::mul_power(acc, base, intermediate)?,
+                            None => base,
+                        });
+                    }
+
+                    n >>= 1;
+
+                    // For powers of two the last squaring is the last multiplication.
+                    if n == 1 && acc.is_none() {
+                        return Self::round_power(base, base, mode);
+                    }
+
+                    base = Self::mul_power(base, base, intermediate)?;
                 }
             }
 
-            /// Checked [rounding][RoundMode] square root.
-            /// Returns `Err` for negative argument.
+            /// Checked [rounding][RoundMode] dot product `Σ values[i] * weights[i]`, e.g. a
+            /// weighted sum of prices. Returns `ZERO` for empty slices and `Err` on overflow
+            /// or `Err(DomainViolation)` if the slices have different lengths.
             ///
-            /// Square root of a non-negative F is a non-negative S such that:
-            /// * `Floor`: `S ≤ sqrt(F)`
-            /// * `Ceil`: `S ≥ sqrt(F)`
-            /// * `Nearest`: `Floor` or `Ceil`, which one is closer to `sqrt(F)`
+            /// Products are accumulated in the promoted type and rounded only once at the end,
+            /// so the result is the exact dot product rounded according to `mode`.
+            /// It's both more precise and faster than [`rmul`][rmul] and [`cadd`][cadd] for
+            /// every pair. Intermediate sums are allowed to be out of the layout's range.
             ///
             /// ```
             /// # #[cfg(feature = "i64")]
             /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9};
-            /// use fixnum::ops::{Zero, RoundMode::*};
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{CheckedAdd, RoundingMul, RoundMode::*}};
             ///
             /// type Amount = FixedPoint<i64, U9>;
             ///
-            /// let a: Amount = "81".parse()?;
-            /// let b: Amount = "2".parse()?;
-            /// let c: Amount = "-100".parse()?;
-            /// assert_eq!(a.rsqrt(Floor)?, "9".parse()?);
-            /// assert_eq!(b.rsqrt(Floor)?, "1.414213562".parse()?);
-            /// assert_eq!(b.rsqrt(Ceil)?, "1.414213563".parse()?);
-            /// assert_eq!(c.rsqrt(Floor), Err(ArithmeticError::DomainViolation));
+            /// let a: Amount = "0.000000001".parse()?;
+            /// let b: Amount = "0.5".parse()?;
+            /// // 1e-9 * 0.5 + 1e-9 * 0.5 = 1e-9
+            /// assert_eq!(Amount::dot(&[a, a], &[b, b], Nearest)?, a);
+            /// // Rounding of every product leads to a different result.
+            /// assert_eq!(a.rmul(b, Nearest)?.cadd(a.rmul(b, Nearest)?)?, "0.000000002".parse()?);
             /// # Ok(()) }
             /// # #[cfg(not(feature = "i64"))]
             /// # fn main() {}
             /// ```
-            #[inline]
-            pub fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
-                if self.inner.is_negative() {
+            ///
+            /// [cadd]: ./ops/trait.CheckedAdd.html#tymethod.cadd
+            /// [rmul]: ./ops/trait.RoundingMul.html#tymethod.rmul
+            pub fn dot(values: &[Self], weights: &[Self], mode: RoundMode) -> Result<Self> {
+                if values.len() != weights.len() {
                     return Err(ArithmeticError::DomainViolation);
                 }
 
-                // At first we have `S_inner = S * COEF`.
-                // We'd like to gain `sqrt(S) * COEF`:
-                // `sqrt(S) * COEF = sqrt(S * COEF^2) = sqrt(S_inner * COEF)`
-                let squared = $promotion::from(self.inner).mul_l(Self::COEF);
-                let lo = squared.sqrt();
+                let mut acc = <$promotion as Zero>::ZERO;
+                for (value, weight) in values.iter().zip(weights) {
+                    let product = $promotion::from(value.inner).mul_l(weight.inner);
+                    acc = acc.checked_add(product).ok_or(ArithmeticError::Overflow)?;
+                }
+
+                Self::round_promoted(acc, mode)
+            }
+
+            /// Checked [rounding][RoundMode] arithmetic mean of values.
+            ///
+            /// Values are summed in the promoted type and divided only once, so the result is
+            /// the exact mean rounded according to `mode`, and intermediate sums are allowed
+            /// to be out of the layout's range.
+            ///
+            /// Returns `Err(DivisionByZero)` for an empty slice and `Err(Overflow)` if the sum
+            /// doesn't fit the promoted type or the length doesn't fit the layout, which is
+            /// possible only for huge slices of short layouts.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundMode::*}};
+            ///
+            /// type Price = FixedPoint<i64, U9>;
+            ///
+            /// let a: Price = "1".parse()?;
+            /// let b: Price = "2".parse()?;
+            /// assert_eq!(Price::mean(&[a, a, b], Floor)?, "1.333333333".parse()?);
+            /// assert_eq!(Price::mean(&[a, b, b], Nearest)?, "1.666666667".parse()?);
+            /// assert_eq!(Price::mean(&[Price::MAX, Price::MAX], Nearest)?, Price::MAX);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn mean(values: &[Self], mode: RoundMode) -> Result<Self> {
+                if values.is_empty() {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let len = $layout::try_from(values.len()).map_err(|_| ArithmeticError::Overflow)?;
+
+                let mut sum = <$promotion as Zero>::ZERO;
+                for value in values {
+                    sum = sum
+                        .checked_add($promotion::from(value.inner))
+                        .ok_or(ArithmeticError::Overflow)?;
+                }
 
-                let add_one = match mode {
-                    RoundMode::Floor => false,
-                    RoundMode::Nearest => {
-                        // We choose to round up iff
-                        //
-                        //  (lo+1)^2 - squared <= squared - lo^2
-                        //
-                        // However, we don't want to do calculations in the promoted type,
-                        // because it can be slow (`i128` and `i256`). So, we use modular
-                        // arithmetic (with `2^bits(layout)` modulus) to avoid it.
-
-                        let lo2 = lo.wrapping_mul(lo);
-                        // hi^2 = (lo+1)^2 = lo^2 + 2lo + 1
-                        let hi2 = lo2.wrapping_add(lo).wrapping_add(lo).wrapping_add($layout::ONE);
-                        let squared = squared.as_layout();
-                        hi2.wrapping_sub(squared) <= squared.wrapping_sub(lo2)
-                    },
-                    RoundMode::Ceil => {
-                        lo.wrapping_mul(lo) != squared.as_layout()
-                    },
+                // `|loss| < len`, thus it fits in the layout.
+                let (result, loss) = sum.div_rem_l(len);
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != 0 {
+                    let sign = loss.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(len.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+
+            /// Multiplies intermediate powers of [`rpowi`][Self::rpowi], which are represented
+            /// as `(mantissa, exponent, inexact)` meaning `mantissa * 10^-exponent`.
+            /// The product is rounded according to `mode` to fit `|mantissa| <= MAX / 10`.
+            fn mul_power(
+                a: ($layout, i64, bool),
+                b: ($layout, i64, bool),
+                mode: RoundMode,
+            ) -> Result<($layout, i64, bool)> {
+                let limit = $layout::MAX / 10;
+                let product = $promotion::from(a.0).mul_l(b.0);
+
+                let mut scale = 0;
+                let mut truncated = product;
+                while truncated > $promotion::from(limit) || truncated < $promotion::from(-limit) {
+                    truncated = truncated.div_l(10);
+                    scale += 1;
+                }
+
+                let (mantissa, inexact) = Self::round_decimal(product, scale, a.2 || b.2, mode);
+                let exponent = a.1 + b.1 - scale;
+
+                // `|mantissa| >= 1`, thus the power is bigger than any value of the layout.
+                if exponent < -i64::from($layout::BITS) {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                Ok((mantissa.as_layout(), exponent, inexact))
+            }
+
+            /// Multiplies intermediate powers of [`rpowi`][Self::rpowi] and rounds the product
+            /// to `Self` according to `mode`.
+            fn round_power(
+                a: ($layout, i64, bool),
+                b: ($layout, i64, bool),
+                mode: RoundMode,
+            ) -> Result<Self> {
+                let product = $promotion::from(a.0).mul_l(b.0);
+                let scale = a.1 + b.1 - i64::from(Self::PRECISION);
+
+                let inner = if scale >= 0 {
+                    Self::round_decimal(product, scale, a.2 || b.2, mode).0
+                } else {
+                    let mut product = product;
+                    for _ in scale..0 {
+                        product = product.checked_mul_l(10).ok_or(ArithmeticError::Overflow)?;
+                    }
+                    product
                 };
 
-                let inner = if add_one {
-                    lo + $layout::ONE
+                $layout::try_from(inner)
+                    .map(Self::from_bits)
+                    .map_err(|_| ArithmeticError::Overflow)
+            }
+
+            /// Divides `value` by `10^scale` and rounds the quotient according to `mode`.
+            /// `inexact` means that `value` is already truncated, it matters only for ties.
+            /// Returns the quotient and whether it's inexact.
+            fn round_decimal(
+                value: $promotion,
+                mut scale: i64,
+                inexact: bool,
+                mode: RoundMode,
+            ) -> ($promotion, bool) {
+                if scale == 0 {
+                    return (value, inexact);
+                }
+
+                let zero = $promotion::from(0 as $layout);
+                let negative = value < zero;
+                let mut value = value;
+                let mut sticky = inexact;
+
+                // Only the last dropped digit is required to compare with a half.
+                while scale > 1 && value != zero {
+                    let (quotient, loss) = value.div_rem_l(10);
+                    sticky |= loss != 0;
+                    value = quotient;
+                    scale -= 1;
+                }
+
+                let (result, loss) = if scale == 1 {
+                    value.div_rem_l(10)
                 } else {
-                    lo
+                    (zero, 0)
                 };
 
-                Ok(Self::from_bits(inner))
+                if loss == 0 && !sticky {
+                    return (result, false);
+                }
+
+                let half = match loss.abs().cmp(&5) {
+                    Ordering::Equal if sticky => Ordering::Greater,
+                    half => half,
+                };
+
+                if mode.is_away_from_zero(negative, half, result.as_layout() % 2 != 0) {
+                    let one = $promotion::from(if negative { -1 as $layout } else { 1 });
+                    (result + one, true)
+                } else {
+                    (result, true)
+                }
+            }
+
+            /// Rounds `value * EPSILON ^ 2` to `Self` according to `mode`.
+            #[inline]
+            fn round_promoted(value: $promotion, mode: RoundMode) -> Result<Self> {
+                // `|loss| < COEF`, thus it fits in the layout.
+                let (result, loss) = value.div_rem_l(Self::COEF);
+
+                let mut result =
+                    $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != 0 {
+                    let sign = loss.signum();
+                    // `loss + loss` can overflow the layout if `COEF > MAX / 2`.
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(Self::COEF.unsigned_abs() - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
             }
         }
 
@@ -661,7 +2309,7 @@ macro_rules! impl_fixed_point {
             /// Creates a new number from separate mantissa and exponent.
             pub fn from_decimal(mantissa: $layout, exponent: i32) -> Result<Self, ConvertError> {
                 if exponent < -Self::PRECISION || exponent > 10 {
-                    return Err(ConvertError::new("unsupported exponent"));
+                    return Err(ConvertError::new(ConvertErrorKind::UnsupportedExponent, "unsupported exponent"));
                 }
 
                 let ten: $layout = 10;
@@ -670,7 +2318,7 @@ macro_rules! impl_fixed_point {
                 mantissa
                     .checked_mul(multiplier)
                     .map(Self::from_bits)
-                    .map_or_else(|| Err(ConvertError::new("too big mantissa")), Ok)
+                    .map_or_else(|| Err(ConvertError::new(ConvertErrorKind::IntegralOverflow, "too big mantissa")), Ok)
             }
 
             /// Returns a pair `(mantissa, exponent)` where `exponent`
@@ -686,31 +2334,275 @@ macro_rules! impl_fixed_point {
             /// # Panics
             /// If `max_exponent` is less than `-PRECISION`.
             pub fn to_decimal(&self, max_exponent: i32) -> ($layout, i32) {
+                let (mantissa, exponent, _) = self.to_decimal_stripped(max_exponent);
+                (mantissa, exponent)
+            }
+
+            /// The same as [`to_decimal`](Self::to_decimal), but additionally returns the number
+            /// of stripped trailing zeros, i.e. `exponent + PRECISION`.
+            ///
+            /// Uses binary search over powers of ten, so it's cheap even for huge `max_exponent`.
+            ///
+            /// Examples:
+            /// * `fp!(5.5).to_decimal_stripped(0)       // => (55, -1, 8)` for `PRECISION = 9`
+            /// * `fp!(50).to_decimal_stripped(i32::MAX) // => (5, 1, 10)` for `PRECISION = 9`
+            ///
+            /// # Panics
+            /// If `max_exponent` is less than `-PRECISION`.
+            pub fn to_decimal_stripped(&self, max_exponent: i32) -> ($layout, i32, u32) {
                 assert!(max_exponent >= -Self::PRECISION);
 
+                let max_stripped = (i64::from(max_exponent) + i64::from(Self::PRECISION)) as u64;
+
                 if self.inner == 0 {
-                    return (0, 0.min(max_exponent));
+                    let exponent = 0.min(max_exponent);
+                    return (0, exponent, (exponent + Self::PRECISION) as u32);
+                }
+
+                // `10 ^ k` divides `inner` only if `10 ^ k` fits the layout, because `inner != 0`.
+                let power_of_10 = |k: usize| {
+                    let power = power_table::POWERS_OF_10[k].0;
+                    (power <= $layout::MAX as u128).then(|| power as $layout)
+                };
+                let is_stripped = |k: usize| power_of_10(k).map_or(false, |p| self.inner % p == 0);
+
+                // Divisibility by `10 ^ k` is monotonic in `k`, so find the largest `k`.
+                let mut low = 0;
+                let mut high = max_stripped.min(power_table::POWERS_OF_10.len() as u64 - 1) as usize;
+                while low < high {
+                    let middle = high - (high - low) / 2;
+                    if is_stripped(middle) {
+                        low = middle;
+                    } else {
+                        high = middle - 1;
+                    }
                 }
 
-                let mut mantissa = self.inner;
-                let mut exponent = -Self::PRECISION;
+                let mantissa = power_of_10(low).map_or(self.inner, |p| self.inner / p);
+                (mantissa, low as i32 - Self::PRECISION, low as u32)
+            }
+
+            /// Splits into `(negative, integral, fractional)`, where `fractional` is the numerator
+            /// of the fractional part over `10 ^ PRECISION`, i.e. the number is
+            /// `±(integral + fractional / 10 ^ PRECISION)`. The conversion is exact, so it's
+            /// useful for messages modeling decimals as separate integral and fractional fields.
+            ///
+            /// `negative` is `false` for zero. Use [`from_parts`](Self::from_parts) to get
+            /// the number back.
+            ///
+            /// Examples:
+            /// * `fp!(5.25).to_parts()  // => (false, 5, 250_000_000)` for `PRECISION = 9`
+            /// * `fp!(-0.5).to_parts()  // => (true, 0, 500_000_000)` for `PRECISION = 9`
+            #[inline]
+            pub fn to_parts(self) -> (bool, u128, u128) {
+                let abs = self.inner.unsigned_abs() as u128;
+                let coef = Self::COEF as u128;
+                (self.inner < 0, abs / coef, abs % coef)
+            }
 
-                // TODO: use binary search to optimize it.
-                while exponent < max_exponent && mantissa % 10 == 0 {
-                    exponent += 1;
-                    mantissa /= 10;
+            /// Creates a number from `(negative, integral, fractional)` parts,
+            /// see [`to_parts`](Self::to_parts). A negative zero is allowed.
+            ///
+            /// Returns `Err` if `fractional` isn't less than `10 ^ PRECISION`
+            /// or the number doesn't fit the layout.
+            pub fn from_parts(negative: bool, integral: u128, fractional: u128) -> Result<Self, ConvertError> {
+                let coef = Self::COEF as u128;
+                if fractional >= coef {
+                    return Err(ConvertError::new(
+                        ConvertErrorKind::OutOfDomain,
+                        "fractional part must be less than 10 ^ PRECISION",
+                    ));
                 }
 
-                (mantissa, exponent)
+                let abs = integral
+                    .checked_mul(coef)
+                    .and_then(|abs| abs.checked_add(fractional))
+                    .ok_or_else(Self::integral_overflow)?;
+
+                let inner = if negative {
+                    // `MIN` has no positive counterpart, so negate the unsigned value.
+                    if abs > $layout::MIN.unsigned_abs() as u128 {
+                        return Err(Self::integral_overflow());
+                    }
+                    (abs as $unsigned).wrapping_neg() as $layout
+                } else {
+                    $layout::try_from(abs).map_err(|_| Self::integral_overflow())?
+                };
+
+                Ok(Self::from_bits(inner))
+            }
+
+            /// Creates a number from an ASCII digit, e.g. `b'7'` becomes `7.0`.
+            /// Returns `Err` for other bytes and digits that don't fit the layout.
+            #[inline]
+            pub fn try_from_digit(digit: u8) -> Result<Self, ConvertError> {
+                if !digit.is_ascii_digit() {
+                    return Err(ConvertError::new(ConvertErrorKind::InvalidSyntax, "not an ASCII digit"));
+                }
+
+                Self::try_from(digit - b'0')
+            }
+
+            /// Converts to another precision, rounding according to `mode` if it's lower.
+            /// Increasing the precision is exact, so it fails only if the result doesn't fit.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::{U2, U9}, ops::RoundMode::*};
+            ///
+            /// let a: FixedPoint<i64, U9> = "1.235".parse()?;
+            /// let b: FixedPoint<i64, U2> = a.rescale(Nearest)?;
+            /// assert_eq!(b, "1.24".parse()?);
+            /// assert_eq!(b.rescale::<U9>(Floor)?, "1.24".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            pub fn rescale<Q: Precision>(self, mode: RoundMode) -> Result<FixedPoint<$layout, Q>> {
+                let (from, to) = (Self::COEF, FixedPoint::<$layout, Q>::COEF);
+                let inner = if to >= from {
+                    self.inner.checked_mul(to / from).ok_or(ArithmeticError::Overflow)?
+                } else {
+                    self.inner.rdiv(from / to, mode)?
+                };
+                Ok(FixedPoint::from_bits(inner))
+            }
+
+            /// Converts to another precision exactly, without rounding.
+            /// Returns `Err` if the number has more decimal places than `Q` allows
+            /// ([`is_fractional_precision`]) or the result doesn't fit ([`is_integral_overflow`]).
+            ///
+            /// Unlike [`rescale`](Self::rescale), any loss is an error, so it's both stricter and
+            /// cheaper for values known to have few decimal places, e.g. prices stored with
+            /// a higher precision.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::{U9, U18}};
+            ///
+            /// let a: FixedPoint<i64, U18> = "1.25".parse()?;
+            /// let b: FixedPoint<i64, U9> = a.try_rescale()?;
+            /// assert_eq!(b, "1.25".parse()?);
+            ///
+            /// let c: FixedPoint<i64, U18> = "1.0000000001".parse()?;
+            /// assert!(c.try_rescale::<U9>().unwrap_err().is_fractional_precision());
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            ///
+            /// [`is_fractional_precision`]: ConvertError::is_fractional_precision
+            /// [`is_integral_overflow`]: ConvertError::is_integral_overflow
+            #[inline]
+            pub fn try_rescale<Q: Precision>(self) -> Result<FixedPoint<$layout, Q>, ConvertError> {
+                let (from, to) = (Self::COEF, FixedPoint::<$layout, Q>::COEF);
+                let inner = if to >= from {
+                    self.inner
+                        .checked_mul(to / from)
+                        .ok_or_else(FixedPoint::<$layout, Q>::integral_overflow)?
+                } else if self.inner % (from / to) == 0 {
+                    self.inner / (from / to)
+                } else {
+                    let precision = FixedPoint::<$layout, Q>::PRECISION as u32;
+                    return Err(ConvertError::fractional_precision(precision));
+                };
+                Ok(FixedPoint::from_bits(inner))
+            }
+
+            /// Returns a key that preserves the numeric order as the unsigned integer order,
+            /// which is useful for radix sorting and binary encodings of keys (e.g. in LSM trees).
+            ///
+            /// The key is the raw representation with the sign bit flipped, so `MIN` maps to `0`,
+            /// `ZERO` maps to `2 ^ (BITS - 1)` and `MAX` maps to the maximum unsigned value.
+            /// Use big-endian bytes of the key to get the same order for byte strings.
+            ///
+            /// Use [`from_sortable_key`](Self::from_sortable_key) to get the number back.
+            #[inline]
+            pub const fn sortable_key(self) -> $unsigned {
+                (self.inner as $unsigned) ^ (1 << ($unsigned::BITS - 1))
+            }
+
+            /// Restores a number from a key returned by [`sortable_key`](Self::sortable_key).
+            #[inline]
+            pub const fn from_sortable_key(key: $unsigned) -> Self {
+                Self::from_bits((key ^ (1 << ($unsigned::BITS - 1))) as $layout)
+            }
+
+            /// Returns the decimal digit of the absolute value at `position` relative to
+            /// the decimal point: `0` is the units digit, `1` is tens, `-1` is tenths and so on.
+            ///
+            /// Positions outside the representable range have zero digits.
+            /// The digit is computed via the power table, so no string is formatted.
+            ///
+            /// Examples:
+            /// * `fp!(123.45).digit_at(2)  // => 1`
+            /// * `fp!(123.45).digit_at(0)  // => 3`
+            /// * `fp!(-123.45).digit_at(-2) // => 5`
+            /// * `fp!(123.45).digit_at(-3) // => 0`
+            #[inline]
+            pub fn digit_at(&self, position: i32) -> u8 {
+                let exponent = match position.checked_add(Self::PRECISION) {
+                    Some(exponent) if exponent >= 0 => exponent as u32,
+                    _ => return 0,
+                };
+
+                power_table::power_of_10(exponent)
+                    .map_or(0, |power| (self.inner.unsigned_abs() as u128 / power % 10) as u8)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> TryFrom<($layout, i32)> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Creates a new number from a pair `(mantissa, exponent)`.
+            /// The same as [`from_decimal`](FixedPoint::from_decimal).
+            #[inline]
+            fn try_from((mantissa, exponent): ($layout, i32)) -> Result<Self, Self::Error> {
+                Self::from_decimal(mantissa, exponent)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> From<bool> for FixedPoint<$layout, P> {
+            /// Converts `true` to `ONE` and `false` to `ZERO`.
+            #[inline]
+            fn from(value: bool) -> Self {
+                if value {
+                    Self::ONE
+                } else {
+                    Self::ZERO
+                }
+            }
+        }
+
+        impl<P: Precision> FixedPoint<$layout, P> {
+            pub(crate) fn to_f64(self) -> f64 {
+                let integral = (self.inner / Self::COEF) as f64;
+                let fractional = ((self.inner % Self::COEF) as f64) / (Self::COEF as f64);
+                integral + fractional
+            }
+
+            #[cfg(feature = "audit")]
+            fn audit_to_f64(self, float: f64) {
+                // The float doesn't fit the layout only near `MIN` and `MAX`, it's at least `EPSILON` off.
+                let distance = Self::from_f64(float).map_or(1, |back| self.inner.abs_diff(back.inner));
+                let loss = distance as f64 / Self::COEF as f64;
+                crate::audit::record(crate::audit::Direction::ToF64, Self::PRECISION, float, loss);
             }
         }
 
         impl<P: Precision> From<FixedPoint<$layout, P>> for f64 {
             fn from(value: FixedPoint<$layout, P>) -> Self {
-                let coef = FixedPoint::<$layout, P>::COEF;
-                let integral = (value.inner / coef) as f64;
-                let fractional = ((value.inner % coef) as f64) / (coef as f64);
-                integral + fractional
+                let float = value.to_f64();
+
+                #[cfg(feature = "audit")]
+                value.audit_to_f64(float);
+
+                float
             }
         }
 
@@ -721,10 +2613,10 @@ macro_rules! impl_fixed_point {
 
                 fn try_from(value: $try_from) -> Result<Self, Self::Error> {
                     $layout::try_from(value)
-                        .map_err(|_| ConvertError::new("too big number"))?
+                        .map_err(|_| Self::integral_overflow())?
                         .checked_mul(Self::COEF)
                         .map(Self::from_bits)
-                        .ok_or(ConvertError::new("too big number"))
+                        .ok_or_else(Self::integral_overflow)
                 }
             }
         )*
@@ -735,27 +2627,135 @@ macro_rules! impl_fixed_point {
 impl_fixed_point!(
     #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
     inner = i16;
+    unsigned = u16;
     promoted_to = i32;
+    max_precision = 4;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
 );
 #[cfg(feature = "i32")]
 impl_fixed_point!(
     #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]
     inner = i32;
+    unsigned = u32;
     promoted_to = i64;
+    max_precision = 9;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
 );
 #[cfg(feature = "i64")]
 impl_fixed_point!(
     #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]
     inner = i64;
-    promoted_to = i128;
+    unsigned = u64;
+    promoted_to = I64Promotion;
+    max_precision = 18;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
 );
 #[cfg(feature = "i128")]
 impl_fixed_point!(
     #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
     inner = i128;
+    unsigned = u128;
     promoted_to = i256;
+    max_precision = 38;
     try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
 );
+
+macro_rules! impl_widening {
+    ($from:ident => $to:ident, $feature_from:literal, $feature_to:literal) => {
+        #[cfg(all(feature = $feature_from, feature = $feature_to))]
+        #[cfg_attr(docsrs, doc(cfg(all(feature = $feature_from, feature = $feature_to))))]
+        impl<P: Precision> FixedPoint<$from, P> {
+            #[doc = concat!("Converts to the `", stringify!($to), "` layout with the same precision, it's lossless.")]
+            ///
+            /// It isn't a `From` impl, because it would break type inference of conversions
+            /// between `FixedPoint`s with unspecified layouts, e.g. in `fixnum!` and `serde::repr`.
+            #[inline]
+            pub fn widen(self) -> FixedPoint<$to, P> {
+                FixedPoint::from_bits($to::from(self.inner))
+            }
+        }
+    };
+}
+
+impl_widening!(i16 => i32, "i16", "i32");
+impl_widening!(i32 => i64, "i32", "i64");
+impl_widening!(i64 => i128, "i64", "i128");
+
+macro_rules! impl_narrowing {
+    ($from:ident => $to:ident, $feature_from:literal, $feature_to:literal) => {
+        #[cfg(all(feature = $feature_from, feature = $feature_to))]
+        #[cfg_attr(docsrs, doc(cfg(all(feature = $feature_from, feature = $feature_to))))]
+        impl<P: Precision> TryFrom<FixedPoint<$from, P>> for FixedPoint<$to, P> {
+            type Error = ConvertError;
+
+            /// Narrows the layout, failing if the number doesn't fit.
+            #[inline]
+            fn try_from(value: FixedPoint<$from, P>) -> Result<Self, Self::Error> {
+                $to::try_from(value.inner)
+                    .map(Self::from_bits)
+                    .map_err(|_| Self::integral_overflow())
+            }
+        }
+
+        #[cfg(all(feature = $feature_from, feature = $feature_to))]
+        #[cfg_attr(docsrs, doc(cfg(all(feature = $feature_from, feature = $feature_to))))]
+        impl<P: Precision> FixedPoint<$from, P> {
+            #[doc = concat!("Rescales `values` to the `", stringify!($to), "` layout with the precision `Q`")]
+            /// into `out` and returns the maximum absolute loss over the batch, e.g. to downsample
+            /// analytics into a cheaper storage and check that nothing important is lost.
+            ///
+            /// Every number is rounded only once according to `mode`, like [`rescale`](Self::rescale).
+            /// Returns `Err(Overflow)` if some number doesn't fit the target layout,
+            /// `out` is left partially written in this case.
+            ///
+            /// # Panics
+            /// If `values` and `out` have different lengths.
+            ///
+            /// ```
+            /// # #[cfg(all(feature = "i128", feature = "i64"))]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::{U9, U18}, ops::RoundMode::*};
+            ///
+            /// type Fp128 = FixedPoint<i128, U18>;
+            /// type Fp64 = FixedPoint<i64, U9>;
+            ///
+            /// let values: [Fp128; 3] = ["1.5".parse()?, "0.123456789123".parse()?, "-2.0000000004".parse()?];
+            /// let mut out = [Fp64::default(); 3];
+            /// let max_loss = Fp128::rescale_slice_with_max_loss(&values, &mut out, Nearest)?;
+            ///
+            /// assert_eq!(out, ["1.5".parse()?, "0.123456789".parse()?, "-2".parse()?]);
+            /// assert_eq!(max_loss, "0.0000000004".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(all(feature = "i128", feature = "i64")))]
+            /// # fn main() {}
+            /// ```
+            #[track_caller]
+            pub fn rescale_slice_with_max_loss<Q: Precision>(
+                values: &[Self],
+                out: &mut [FixedPoint<$to, Q>],
+                mode: RoundMode,
+            ) -> Result<Self> {
+                assert_eq!(values.len(), out.len(), "slices must have the same length");
+
+                let mut max_loss = Self::ZERO;
+
+                for (value, out) in values.iter().zip(out.iter_mut()) {
+                    let rescaled = value.rescale::<Q>(mode)?;
+                    let narrowed = $to::try_from(rescaled.inner).map_err(|_| ArithmeticError::Overflow)?;
+                    // Rounding moves the number by less than `EPSILON` of `Q`, so the loss fits
+                    // unless the rounded number itself doesn't fit the precision `P`.
+                    let loss = rescaled.rescale::<P>(mode)?.csub(*value)?.abs()?;
+
+                    max_loss = max_loss.max(loss);
+                    *out = FixedPoint::from_bits(narrowed);
+                }
+
+                Ok(max_loss)
+            }
+        }
+    };
+}
+
+impl_narrowing!(i32 => i16, "i32", "i16");
+impl_narrowing!(i64 => i32, "i64", "i32");
+impl_narrowing!(i128 => i64, "i128", "i64");
@@ -41,16 +41,46 @@ macro_rules! impl_int_operand {
     }
 }
 
-// TODO: unsigned?
 impl_int_operand!(i8 => i8, i16, i32, i64, i128);
 impl_int_operand!(i16 => i16, i32, i64, i128);
 impl_int_operand!(i32 => i32, i64, i128);
 impl_int_operand!(i64 => i64, i128);
 impl_int_operand!(i128 => i128);
+impl_int_operand!(u8 => u8, u16, u32, u64, u128);
+impl_int_operand!(u16 => u16, u32, u64, u128);
+impl_int_operand!(u32 => u32, u64, u128);
+impl_int_operand!(u64 => u64, u128);
+impl_int_operand!(u128 => u128);
 
 /// Defines an operation for some wrapper. See top-level documentation.
 #[macro_export]
 macro_rules! impl_op {
+    ([neg] $arg:ty = $res:tt) => {
+        /// # Panics
+        /// If the value is `MIN`.
+        impl core::ops::Neg for $arg {
+            type Output = $res;
+
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> $res {
+                match self.0.cneg() {
+                    Ok(value) => $res(value),
+                    Err(_) => panic!("attempt to negate with overflow"),
+                }
+            }
+        }
+    };
+    ([bounded] $arg:tt) => {
+        impl $crate::ops::Zero for $arg {
+            const ZERO: Self = $arg($crate::ops::Zero::ZERO);
+        }
+
+        impl $crate::ops::Bounded for $arg {
+            const MIN: Self = $arg($crate::ops::Bounded::MIN);
+            const MAX: Self = $arg($crate::ops::Bounded::MAX);
+        }
+    };
     ($lhs:ty [cadd] $rhs:ty = $res:tt) => {
         impl $crate::ops::CheckedAdd<$rhs> for $lhs {
             type Output = $res;
@@ -92,6 +122,14 @@ macro_rules! impl_op {
             fn cmul(self, rhs: $rhs) -> Result<$res, $crate::ArithmeticError> {
                 $crate::impl_op!(@checked_method (l = self, r = rhs) => l.cmul(r), $res)
             }
+
+            #[inline]
+            fn saturating_mul(self, rhs: $rhs) -> Self::Output {
+                $crate::impl_op!(@method (l = self, r = rhs) => {
+                    // Not `l.saturating_mul(r)`, which is the inherent method for integers.
+                    CheckedMul::saturating_mul(l, r)
+                }, $res)
+            }
         }
     };
     ($lhs:ty [rmul] $rhs:ty = $res:tt) => {
@@ -107,6 +145,11 @@ macro_rules! impl_op {
             ) -> Result<$res, $crate::ArithmeticError> {
                 $crate::impl_op!(@checked_method (l = self, r = rhs) => l.rmul(r, mode), $res)
             }
+
+            #[inline]
+            fn saturating_rmul(self, rhs: $rhs, mode: $crate::ops::RoundMode) -> Self::Output {
+                $crate::impl_op!(@method (l = self, r = rhs) => l.saturating_rmul(r, mode), $res)
+            }
         }
     };
     ($lhs:ty [rdiv] $rhs:ty = $res:tt) => {
@@ -127,6 +170,46 @@ macro_rules! impl_op {
                     ).0.rdiv(r, mode)
                 }, $res)
             }
+
+            #[inline]
+            #[track_caller]
+            fn saturating_rdiv(self, rhs: $rhs, mode: $crate::ops::RoundMode) -> Self::Output {
+                use core::convert::TryInto;
+                $crate::impl_op!(@method (l = self, r = rhs) => {
+                    fn zero<T: Zero>(_: &T) -> T {
+                        T::ZERO
+                    }
+                    assert!(r != zero(&r), "attempt to divide by zero");
+                    let is_negative = (l < zero(&l)) != (r < zero(&r));
+                    l.try_into()
+                        .map_err(|_| $crate::ArithmeticError::Overflow)
+                        .and_then(|l| $res(l).0.rdiv(r, mode))
+                        .unwrap_or(if is_negative { Bounded::MIN } else { Bounded::MAX })
+                }, $res)
+            }
+        }
+    };
+    ($lhs:ty [rmul_div] $rhs:ty, $div:ty = $res:tt) => {
+        impl $crate::ops::RoundingMulDiv<$rhs, $div> for $lhs {
+            type Output = $res;
+            type Error = $crate::ArithmeticError;
+
+            #[inline]
+            fn rmul_div(
+                self,
+                rhs: $rhs,
+                divisor: $div,
+                mode: $crate::ops::RoundMode,
+            ) -> Result<$res, $crate::ArithmeticError> {
+                use $crate::_priv::*;
+                fn up<I, O: Operand<I>>(operand: O, _: impl FnOnce(I) -> $res) -> O::Promotion {
+                    operand.promote()
+                }
+                let l = up(self.0, $res);
+                let r = up(rhs.0, $res);
+                let d = up(divisor.0, $res);
+                l.rmul_div(r, d, mode).map($res)
+            }
         }
     };
     (@method ($l:ident = $lhs:expr, $r:ident = $rhs:expr) => $op:expr, $res:tt) => {{
@@ -169,7 +252,7 @@ macro_rules! impl_op {
 ///
 /// macro_rules! fp_const {
 ///     ($value:literal) => {
-///         fixnum::fixnum_const!($value, 9);
+///         fixnum::fixnum_const!($value, 9)
 ///     };
 /// }
 ///
@@ -178,8 +261,8 @@ macro_rules! impl_op {
 #[macro_export]
 macro_rules! fixnum_const {
     ($value:literal, $precision:literal) => {{
-        use $crate::FixedPoint;
         use $crate::_priv::*;
+        use $crate::FixedPoint;
         const VALUE_INNER: Int = parse_fixed(stringify!($value), pow10($precision));
         FixedPoint::from_bits(VALUE_INNER as _)
     }};
@@ -215,7 +298,7 @@ macro_rules! fixnum_const {
 ///
 /// macro_rules! fp {
 ///     ($val:literal) => {
-///         fixnum::fixnum!($val, 9);
+///         fixnum::fixnum!($val, 9)
 ///     };
 /// }
 ///
@@ -229,3 +312,213 @@ macro_rules! fixnum {
         $crate::fixnum_const!($value, $precision).into()
     };
 }
+
+/// The same as [`fixnum!`](crate::fixnum), but explicitly [rounds][RoundMode] the literal
+/// at compile time if it has more fractional digits than the precision.
+///
+/// `fixnum!` rejects such literals, so the intent to lose digits is visible in code review.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U2, fixnum_round};
+///
+/// type Price = FixedPoint<i64, U2>;
+///
+/// let a: Price = fixnum_round!(0.125, 2, Floor);
+/// let b: Price = fixnum_round!(0.125, 2, HalfEven);
+/// let c: Price = fixnum_round!(-0.125, 2, Nearest);
+/// assert_eq!((a, b, c), ("0.12".parse()?, "0.12".parse()?, "-0.13".parse()?));
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// [RoundMode]: ./ops/enum.RoundMode.html
+#[macro_export]
+macro_rules! fixnum_round {
+    ($value:literal, $precision:literal, $mode:ident) => {{
+        use $crate::_priv::*;
+        use $crate::FixedPoint;
+        const VALUE_INNER: Int =
+            parse_fixed_round(stringify!($value), pow10($precision), RoundMode::$mode);
+        FixedPoint::from_bits(VALUE_INNER as _).into()
+    }};
+}
+
+/// Macro to declare fixed-point type aliases along with the documented constants.
+///
+/// The generated documentation contains an example with `MAX_STR` and `EPSILON_STR`,
+/// the decimal forms of `MAX` and `EPSILON` computed at compile time, so they cannot drift
+/// from the actual type. Any layout is supported, `I256` must be in scope.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # mod amount {
+/// use fixnum::fixnum_type;
+///
+/// fixnum_type! {
+///     /// Signed fixed point amount over 64 bits, 9 decimal places.
+///     pub type Amount = FixedPoint<i64, U9>;
+/// }
+///
+/// const MAX: &str = Amount::MAX_STR; // "9223372036.854775807"
+/// const EPSILON: &str = Amount::EPSILON_STR; // "0.000000001"
+/// # }
+/// ```
+///
+/// Precisions that don't fit the layout are rejected at compile time.
+#[macro_export]
+macro_rules! fixnum_type {
+    // `introspect()` lists only primitive layouts.
+    (@register I256 $name:ident) => {};
+    (@register $layout:ident $name:ident) => {
+        $crate::__register_type!($name);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis type $name:ident = FixedPoint<$layout:ident, $precision:ident> $(;)?
+    ) => {
+        $(#[$attr])*
+        ///
+        /// ```
+        /// # #[allow(unused_imports)]
+        /// use fixnum::{ops::Bounded, *};
+        ///
+        #[doc = concat!(
+            "type ", stringify!($name), " = FixedPoint<",
+            stringify!($layout), ", typenum::", stringify!($precision), ">;"
+        )]
+        ///
+        #[doc = concat!("println!(\"MAX = {}\", ", stringify!($name), "::MAX_STR);")]
+        #[doc = concat!("println!(\"EPSILON = {}\", ", stringify!($name), "::EPSILON_STR);")]
+        #[doc = concat!(
+            "assert_eq!(", stringify!($name), "::MAX_STR, ", stringify!($name), "::MAX.to_string());"
+        )]
+        /// ```
+        $vis type $name = $crate::FixedPoint<$layout, $crate::typenum::$precision>;
+        $crate::fixnum_type!(@register $layout $name);
+
+        // Rejects precisions that don't fit the layout.
+        const _: $name = <$name as $crate::ops::One>::ONE;
+    };
+}
+
+/// Registers types declared by `fixnum_type!` if the `inventory` feature is enabled.
+#[cfg(not(feature = "inventory"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_type {
+    ($ty:ident) => {};
+}
+
+/// Used by `assert_fp_eq_ulps!` to convert `ulps` to the type of the distance.
+#[doc(hidden)]
+pub fn is_within_ulps<D: PartialOrd, U: TryInto<D>>(distance: D, ulps: U) -> bool {
+    match ulps.try_into() {
+        Ok(ulps) => distance <= ulps,
+        Err(_) => false,
+    }
+}
+
+/// Asserts that two fixed-point numbers are equal within `ulps` units in the last place,
+/// i.e. the distance between their raw representations is at most `ulps`.
+///
+/// Unlike absolute epsilons, the tolerance in ULPs doesn't depend on the precision.
+/// On failure, both numbers are printed in the decimal form along with their raw bits.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{assert_fp_eq_ulps, FixedPoint, typenum::U9, ops::{One, RoundingDiv, RoundMode::*}};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let third = Amount::ONE.rdiv(Amount::try_from(3)?, Floor)?;
+/// assert_fp_eq_ulps!(third, "0.333333334".parse::<Amount>()?, 1);
+/// assert_fp_eq_ulps!(third, "0.333333333".parse::<Amount>()?, 0, "exact for {}", "Floor");
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// ```should_panic
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{assert_fp_eq_ulps, FixedPoint, typenum::U9};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// // Panics: distance is 2 ULPs, more than 1
+/// assert_fp_eq_ulps!("0.1".parse::<Amount>()?, "0.100000002".parse::<Amount>()?, 1);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() { panic!() }
+/// ```
+#[macro_export]
+macro_rules! assert_fp_eq_ulps {
+    ($left:expr, $right:expr, $ulps:expr $(,)?) => {
+        $crate::assert_fp_eq_ulps!(@impl $left, $right, $ulps, "")
+    };
+    ($left:expr, $right:expr, $ulps:expr, $($arg:tt)+) => {
+        $crate::assert_fp_eq_ulps!(
+            @impl $left, $right, $ulps,
+            ::core::format_args!(": {}", ::core::format_args!($($arg)+))
+        )
+    };
+    (@impl $left:expr, $right:expr, $ulps:expr, $message:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let (left_bits, right_bits) = (*left.as_bits(), *right.as_bits());
+                let distance = left_bits.abs_diff(right_bits);
+                let ulps = $ulps;
+                if !$crate::_priv::is_within_ulps(distance, ulps) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed: distance is {} ULPs, more than {}{}\n  left: {} (bits: {:?})\n right: {} (bits: {:?})",
+                        distance, ulps, $message, left, left_bits, right, right_bits,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Checked sum of two or more arguments, short-circuiting on the first overflow.
+///
+/// Expands to a chain of [`cadd`][cadd] calls, so it works with any types implementing
+/// [`CheckedAdd`][cadd], including wrappers defined by [`impl_op!`](crate::impl_op),
+/// and evaluates arguments only until the first error.
+/// Use [`FixedPoint::csum`](crate::FixedPoint) to sum iterators.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{try_sum_of, ArithmeticError, FixedPoint, typenum::U9, ops::Bounded};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let fee: Amount = "0.25".parse()?;
+/// let tax: Amount = "1.5".parse()?;
+/// let total = try_sum_of!(Amount::try_from(10)?, fee, tax)?;
+/// assert_eq!(total, "11.75".parse()?);
+///
+/// assert_eq!(try_sum_of!(Amount::MAX, fee, tax), Err(ArithmeticError::Overflow));
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+///
+/// [cadd]: ./ops/trait.CheckedAdd.html#tymethod.cadd
+#[macro_export]
+macro_rules! try_sum_of {
+    ($first:expr $(, $rest:expr)+ $(,)?) => {{
+        let sum = ::core::result::Result::Ok($first);
+        $(
+            let sum = match sum {
+                ::core::result::Result::Ok(sum) => $crate::ops::CheckedAdd::cadd(sum, $rest),
+                ::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+            };
+        )+
+        sum
+    }};
+}
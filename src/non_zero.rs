@@ -0,0 +1,143 @@
+//! Non-zero layouts, e.g. `FixedPoint<NonZeroI64, P>`.
+//!
+//! They have a niche, so `Option<FixedPoint<NonZeroI64, P>>` has the same size as `i64`.
+//! Arithmetic goes through the plain layout and returns `Err(ZeroResult)` if the result is zero,
+//! because zero cannot be represented.
+
+use core::{fmt, num::*, str::FromStr};
+
+use crate::{
+    ops::*, ArithmeticError, ConvertError, ConvertErrorKind, FixedPoint, Precision, Result,
+};
+
+macro_rules! impl_non_zero {
+    ($(#[$attr:meta])? $non_zero:ident => $layout:ident) => {
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$non_zero, P> {
+            /// Creates a non-zero number if the given one is not zero.
+            #[inline]
+            pub const fn new(value: FixedPoint<$layout, P>) -> Option<Self> {
+                match $non_zero::new(value.inner) {
+                    Some(inner) => Some(Self::from_bits(inner)),
+                    None => None,
+                }
+            }
+
+            /// Returns the number as a plain one.
+            #[inline]
+            pub const fn get(self) -> FixedPoint<$layout, P> {
+                FixedPoint::from_bits(self.inner.get())
+            }
+
+            const fn from_inner(inner: $layout) -> Self {
+                match $non_zero::new(inner) {
+                    Some(inner) => Self::from_bits(inner),
+                    None => panic!("zero value"),
+                }
+            }
+
+            #[inline]
+            fn from_result(result: Result<FixedPoint<$layout, P>>) -> Result<Self> {
+                result.and_then(|value| Self::new(value).ok_or(ArithmeticError::ZeroResult))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> Bounded for FixedPoint<$non_zero, P> {
+            // Unsigned layouts start from `EPSILON`.
+            const MIN: Self = Self::from_inner(if $layout::MIN == 0 { 1 } else { $layout::MIN });
+            const MAX: Self = Self::from_inner($layout::MAX);
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> One for FixedPoint<$non_zero, P> {
+            const ONE: Self = Self::from_inner(FixedPoint::<$layout, P>::ONE.inner);
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedAdd for FixedPoint<$non_zero, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> Result<Self> {
+                Self::from_result(self.get().cadd(rhs.get()))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedSub for FixedPoint<$non_zero, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn csub(self, rhs: Self) -> Result<Self> {
+                Self::from_result(self.get().csub(rhs.get()))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingMul for FixedPoint<$non_zero, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                Self::from_result(self.get().rmul(rhs.get(), mode))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingDiv for FixedPoint<$non_zero, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            /// Rounding division. Unlike plain layouts, it never returns `Err(DivisionByZero)`.
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                Self::from_result(self.get().rdiv(rhs.get(), mode))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FromStr for FixedPoint<$non_zero, P> {
+            type Err = ConvertError;
+
+            fn from_str(str: &str) -> Result<Self, Self::Err> {
+                Self::new(str.parse()?)
+                    .ok_or_else(|| ConvertError::new(ConvertErrorKind::OutOfDomain, "zero value"))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Debug for FixedPoint<$non_zero, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Display for FixedPoint<$non_zero, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "i16")))] NonZeroI16 => i16);
+#[cfg(feature = "i32")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "i32")))] NonZeroI32 => i32);
+#[cfg(feature = "i64")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "i64")))] NonZeroI64 => i64);
+#[cfg(feature = "i128")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "i128")))] NonZeroI128 => i128);
+#[cfg(feature = "u16")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "u16")))] NonZeroU16 => u16);
+#[cfg(feature = "u32")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "u32")))] NonZeroU32 => u32);
+#[cfg(feature = "u64")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "u64")))] NonZeroU64 => u64);
+#[cfg(feature = "u128")]
+impl_non_zero!(#[cfg_attr(docsrs, doc(cfg(feature = "u128")))] NonZeroU128 => u128);
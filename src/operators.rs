@@ -0,0 +1,212 @@
+//! Implementations of `core::ops` operators that panic on overflow.
+//!
+//! They are intended for prototyping, the checked API remains the recommended one.
+//! Use `cadd_assign` and `saturating_add_assign` (and others) to accumulate without panics.
+
+use core::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    ops::{CheckedAdd, CheckedMul, CheckedSub, RoundMode, RoundingDiv, Zero},
+    FixedPoint, Precision,
+};
+
+/// # Panics
+/// On overflow, like primitive integers in debug builds (but regardless of the profile).
+#[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+impl<I, P> Add for FixedPoint<I, P>
+where
+    Self: CheckedAdd<Output = Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self {
+        self.cadd(rhs)
+            .unwrap_or_else(|_| panic!("attempt to add with overflow"))
+    }
+}
+
+/// # Panics
+/// On overflow, like primitive integers in debug builds (but regardless of the profile).
+#[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+impl<I, P> Sub for FixedPoint<I, P>
+where
+    Self: CheckedSub<Output = Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self {
+        self.csub(rhs)
+            .unwrap_or_else(|_| panic!("attempt to subtract with overflow"))
+    }
+}
+
+/// # Panics
+/// On overflow, like primitive integers in debug builds (but regardless of the profile).
+#[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+impl<I, P> Mul<I> for FixedPoint<I, P>
+where
+    Self: CheckedMul<I, Output = Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: I) -> Self {
+        self.cmul(rhs)
+            .unwrap_or_else(|_| panic!("attempt to multiply with overflow"))
+    }
+}
+
+/// Rounds towards zero like primitive integers do.
+/// Use [`rdiv`](RoundingDiv::rdiv) to choose the rounding.
+///
+/// # Panics
+/// If `rhs` is zero or the division overflows.
+#[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+impl<I, P> Div<I> for FixedPoint<I, P>
+where
+    Self: RoundingDiv<I, Output = Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: I) -> Self {
+        self.rdiv(rhs, RoundMode::TowardZero)
+            .unwrap_or_else(|_| panic!("attempt to divide by zero or with overflow"))
+    }
+}
+
+/// Use [`csum`](FixedPoint::csum) to handle an overflow.
+///
+/// # Panics
+/// On overflow, like [`Add`]. Intermediate sums are checked too, so the result depends on
+/// the order of values near the bounds.
+#[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+impl<I, P> Sum for FixedPoint<I, P>
+where
+    Self: Add<Output = Self> + Zero,
+{
+    #[inline]
+    #[track_caller]
+    fn sum<It: Iterator<Item = Self>>(iter: It) -> Self {
+        let mut sum = Self::ZERO;
+        for value in iter {
+            sum = sum + value;
+        }
+        sum
+    }
+}
+
+/// # Panics
+/// The same as `Sum<Self>`.
+#[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+impl<'a, I, P> Sum<&'a Self> for FixedPoint<I, P>
+where
+    Self: Sum + Copy,
+{
+    #[inline]
+    #[track_caller]
+    fn sum<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        iter.copied().sum()
+    }
+}
+
+macro_rules! impl_neg {
+    ($layout:ty) => {
+        /// # Panics
+        /// On `MIN`, which can't be negated, like primitive integers in debug builds
+        /// (but regardless of the profile). Use [`cneg`](FixedPoint::cneg) to handle it.
+        #[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+        impl<P: Precision> Neg for FixedPoint<$layout, P> {
+            type Output = Self;
+
+            #[inline]
+            #[track_caller]
+            fn neg(self) -> Self {
+                self.cneg()
+                    .unwrap_or_else(|_| panic!("attempt to negate with overflow"))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_neg!(i16);
+#[cfg(feature = "i32")]
+impl_neg!(i32);
+#[cfg(feature = "i64")]
+impl_neg!(i64);
+#[cfg(feature = "i128")]
+impl_neg!(i128);
+#[cfg(feature = "i256")]
+impl_neg!(crate::I256);
+
+macro_rules! impl_product {
+    ($layout:ty) => {
+        /// Multiplies with the only rounding towards zero like [`Div`] does,
+        /// see [`checked_product`](FixedPoint::checked_product) for details and other modes.
+        ///
+        /// # Panics
+        /// On overflow.
+        #[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+        impl<P: Precision> Product for FixedPoint<$layout, P> {
+            #[inline]
+            #[track_caller]
+            fn product<It: Iterator<Item = Self>>(iter: It) -> Self {
+                Self::checked_product(iter, RoundMode::TowardZero)
+                    .unwrap_or_else(|_| panic!("attempt to multiply with overflow"))
+            }
+        }
+
+        /// # Panics
+        /// The same as `Product<Self>`.
+        #[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+        impl<'a, P: Precision> Product<&'a Self> for FixedPoint<$layout, P> {
+            #[inline]
+            #[track_caller]
+            fn product<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+                iter.copied().product()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_product!(i16);
+#[cfg(feature = "i32")]
+impl_product!(i32);
+#[cfg(feature = "i64")]
+impl_product!(i64);
+#[cfg(feature = "i128")]
+impl_product!(i128);
+
+macro_rules! impl_assign {
+    ($trait:ident<$rhs:ident>::$method:ident via $op:ident::$op_method:ident) => {
+        /// # Panics
+        /// The same as the corresponding operator.
+        #[cfg_attr(docsrs, doc(cfg(feature = "ops")))]
+        impl<I, P> $trait<$rhs> for FixedPoint<I, P>
+        where
+            Self: $op<$rhs, Output = Self> + Copy,
+        {
+            #[inline]
+            #[track_caller]
+            fn $method(&mut self, rhs: $rhs) {
+                *self = $op::$op_method(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_assign!(AddAssign<Self>::add_assign via Add::add);
+impl_assign!(SubAssign<Self>::sub_assign via Sub::sub);
+impl_assign!(MulAssign<I>::mul_assign via Mul::mul);
+impl_assign!(DivAssign<I>::div_assign via Div::div);
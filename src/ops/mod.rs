@@ -1,6 +1,8 @@
 //! Contains traits for checked and rounding operations.
 
-use crate::ArithmeticError;
+use core::{cmp::Ordering, fmt, hash::Hash, str::FromStr};
+
+use crate::{ArithmeticError, ConvertError};
 
 pub(crate) mod sqrt;
 
@@ -94,6 +96,41 @@ pub trait CheckedAdd<Rhs = Self> {
             }
         })
     }
+
+    /// Checked addition in place. Returns `Err` on overflow, leaving `self` unchanged.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedAdd};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let mut total = Amount::default();
+    /// for price in ["0.1", "0.2", "0.3"] {
+    ///     total.cadd_assign(price.parse()?)?;
+    /// }
+    /// assert_eq!(total, "0.6".parse()?);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn cadd_assign(&mut self, rhs: Rhs) -> Result<(), Self::Error>
+    where
+        Self: CheckedAdd<Rhs, Output = Self> + Copy,
+    {
+        *self = self.cadd(rhs)?;
+        Ok(())
+    }
+
+    /// Saturating addition in place. See [`saturating_add`](Self::saturating_add).
+    fn saturating_add_assign(&mut self, rhs: Rhs)
+    where
+        Self: CheckedAdd<Rhs, Output = Self> + Bounded + Copy,
+        Rhs: PartialOrd + Zero,
+    {
+        *self = self.saturating_add(rhs);
+    }
 }
 
 /// Checked subtraction.
@@ -166,6 +203,24 @@ pub trait CheckedSub<Rhs = Self> {
             }
         })
     }
+
+    /// Checked subtraction in place. Returns `Err` on overflow, leaving `self` unchanged.
+    fn csub_assign(&mut self, rhs: Rhs) -> Result<(), Self::Error>
+    where
+        Self: CheckedSub<Rhs, Output = Self> + Copy,
+    {
+        *self = self.csub(rhs)?;
+        Ok(())
+    }
+
+    /// Saturating subtraction in place. See [`saturating_sub`](Self::saturating_sub).
+    fn saturating_sub_assign(&mut self, rhs: Rhs)
+    where
+        Self: CheckedSub<Rhs, Output = Self> + Bounded + Copy,
+        Rhs: PartialOrd + Zero,
+    {
+        *self = self.saturating_sub(rhs);
+    }
 }
 
 /// Checked multiplication.
@@ -242,18 +297,149 @@ pub trait CheckedMul<Rhs = Self> {
             }
         })
     }
+
+    /// Checked multiplication in place. Returns `Err` on overflow, leaving `self` unchanged.
+    fn cmul_assign(&mut self, rhs: Rhs) -> Result<(), Self::Error>
+    where
+        Self: CheckedMul<Rhs, Output = Self> + Copy,
+    {
+        *self = self.cmul(rhs)?;
+        Ok(())
+    }
+
+    /// Saturating multiplication in place. See [`saturating_mul`](Self::saturating_mul).
+    fn saturating_mul_assign(&mut self, rhs: Rhs)
+    where
+        Self: CheckedMul<Rhs, Output = Self> + PartialOrd + Zero + Bounded + Copy,
+        Rhs: PartialOrd + Zero,
+    {
+        *self = self.saturating_mul(rhs);
+    }
+}
+
+/// Checked remainder and Euclidean division.
+///
+/// All operations are exact, so no rounding is involved. An integer `rhs` of
+/// [`FixedPoint`] is converted to the same type first, so `Err(Overflow)` is returned
+/// if it doesn't fit.
+///
+/// [`FixedPoint`]: ../struct.FixedPoint.html
+pub trait CheckedRem<Rhs = Self> {
+    /// Result of remainder operations.
+    type Output;
+    /// Result of Euclidean division, usually an integer.
+    type Quotient;
+    /// Usually [`ArithmeticError`].
+    type Error;
+
+    /// Checked remainder of truncated division, which has the sign of `self`.
+    /// Returns `Err` on attempt to divide by zero.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedRem};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "-7.5".parse()?;
+    /// let two: Amount = "2".parse()?;
+    /// assert_eq!(a.crem(two)?, "-1.5".parse()?);
+    /// assert_eq!(a.crem(2)?, "-1.5".parse()?);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn crem(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
+
+    /// Checked least non-negative remainder, i.e. `self - rhs * self.div_euclid(rhs)`.
+    /// Returns `Err` on attempt to divide by zero.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedRem};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "-7.5".parse()?;
+    /// let two: Amount = "2".parse()?;
+    /// assert_eq!(a.rem_euclid(two)?, "0.5".parse()?);
+    /// assert_eq!(a.rem_euclid(-2)?, "0.5".parse()?);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn rem_euclid(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
+
+    /// Checked Euclidean division, i.e. `q` such that `self = rhs * q + r`, where `r` is
+    /// [`rem_euclid`][CheckedRem::rem_euclid]. Returns `Err` on overflow or attempt to divide by zero.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::CheckedRem};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "-7.5".parse()?;
+    /// let two: Amount = "2".parse()?;
+    /// assert_eq!(a.div_euclid(two)?, -4);
+    /// assert_eq!(a.div_euclid(-2)?, 4);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn div_euclid(self, rhs: Rhs) -> Result<Self::Quotient, Self::Error>;
 }
 
 /// Mode of rounding.
+///
+/// New modes can be added in minor releases, so matches over it need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum RoundMode {
     /// Rounds up. The result is equal or greater than a mathematical result.
     Ceil = 1,
     /// Rounds to nearest representable value.
     /// The result is nearest to a mathematical result.
+    ///
+    /// Half-way cases are rounded away from zero, symmetrically for negative numbers,
+    /// e.g. `0.5` to `1` and `-0.5` to `-1`. It's guaranteed for all operations taking
+    /// a [`RoundMode`] (`rmul`, `rdiv`, `rmul_div`, `integral`, `rescale` and others),
+    /// `round`, parsing (`FromStr` and `fixnum_round!`) and conversions from `f64`,
+    /// so they all agree on exact ties.
     Nearest = 0,
     /// Rounds down. The result is equal or less than a mathematical result.
     Floor = -1,
+    /// Rounds to nearest representable value, but half-way cases to the even one
+    /// (so-called banker's rounding), e.g. `0.5` to `0`, `1.5` and `2.5` to `2`.
+    HalfEven = 2,
+    /// Rounds towards zero, i.e. truncates. Unlike `Floor`, it's symmetric: `-1.5` goes to `-1`.
+    TowardZero = 3,
+    /// Rounds away from zero. Unlike `Ceil`, it's symmetric: `-1.5` goes to `-2`.
+    AwayFromZero = 4,
+}
+
+impl RoundMode {
+    /// Decides whether an inexact result truncated towards zero must be rounded away from zero.
+    ///
+    /// `half` is the ordering of the lost magnitude relative to the half of the last unit,
+    /// `odd` tells whether the truncated result is odd.
+    #[inline]
+    pub(crate) const fn is_away_from_zero(self, negative: bool, half: Ordering, odd: bool) -> bool {
+        // `matches!` instead of `==` to be usable in `const fn`.
+        match self {
+            RoundMode::Ceil => !negative,
+            RoundMode::Floor => negative,
+            RoundMode::Nearest => !matches!(half, Ordering::Less),
+            RoundMode::HalfEven => {
+                matches!(half, Ordering::Greater) || (matches!(half, Ordering::Equal) && odd)
+            }
+            RoundMode::TowardZero => false,
+            RoundMode::AwayFromZero => true,
+        }
+    }
 }
 
 /// Rounding multiplication.
@@ -375,13 +561,210 @@ pub trait RoundingDiv<Rhs = Self> {
     /// [FixedPoint]: ../struct.FixedPoint.html
     /// [RoundMode]: ./enum.RoundMode.html
     fn rdiv(self, rhs: Rhs, mode: RoundMode) -> Result<Self::Output, Self::Error>;
+
+    /// Saturating rounding division. Computes `self / rhs`, saturating at the numeric bounds
+    /// ([`MIN`][MIN], [`MAX`][MAX]) instead of overflowing.
+    ///
+    /// # Panics
+    /// If `rhs` is zero, like [`i64::saturating_div`].
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{Bounded, RoundingDiv, RoundMode::*}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "1".parse()?;
+    /// let b: Amount = "3".parse()?;
+    /// assert_eq!(a.saturating_rdiv(b, Floor), "0.333333333".parse()?);
+    /// assert_eq!(a.saturating_rdiv(3, Ceil), "0.333333334".parse()?);
+    ///
+    /// // MAX / (SaturatingFloor) 0.5 = MAX
+    /// assert_eq!(Amount::MAX.saturating_rdiv("0.5".parse::<Amount>()?, Floor), Amount::MAX);
+    /// // MIN / (SaturatingFloor) -1 = MAX
+    /// assert_eq!(Amount::MIN.saturating_rdiv(-1, Floor), Amount::MAX);
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [MAX]: ./trait.Bounded.html#associatedconstant.MAX
+    /// [MIN]: ./trait.Bounded.html#associatedconstant.MIN
+    #[track_caller]
+    fn saturating_rdiv(self, rhs: Rhs, mode: RoundMode) -> Self::Output
+    where
+        Self: PartialOrd + Zero + Sized,
+        Rhs: PartialOrd + Zero,
+        Self::Output: Bounded,
+    {
+        let is_lhs_negative = self < Self::ZERO;
+        let is_rhs_negative = rhs < Rhs::ZERO;
+        assert!(rhs != Rhs::ZERO, "attempt to divide by zero");
+        self.rdiv(rhs, mode).unwrap_or({
+            if is_lhs_negative == is_rhs_negative {
+                Self::Output::MAX
+            } else {
+                Self::Output::MIN
+            }
+        })
+    }
+}
+
+/// Fused rounding multiplication and division.
+pub trait RoundingMulDiv<Rhs = Self, Div = Rhs> {
+    /// Result of the operation.
+    type Output;
+    /// Usually [`ArithmeticError`].
+    type Error;
+
+    /// Checked rounded `self * rhs / divisor`. Returns `Err` on overflow or attempt to divide
+    /// by zero.
+    ///
+    /// The intermediate product is kept in the promoted type, so it's allowed to be out of
+    /// the layout's range, and the result is rounded only once, unlike `rmul` followed by `rdiv`.
+    ///
+    /// ```
+    /// # #[cfg(feature = "i64")]
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use fixnum::{FixedPoint, typenum::U9, ops::{RoundingDiv, RoundingMul, RoundingMulDiv, RoundMode::*}};
+    ///
+    /// type Amount = FixedPoint<i64, U9>;
+    ///
+    /// let a: Amount = "0.000000001".parse()?;
+    /// let b: Amount = "0.5".parse()?;
+    /// let c: Amount = "0.25".parse()?;
+    /// // 1e-9 * 0.5 / 0.25 = 2e-9
+    /// assert_eq!(a.rmul_div(b, c, Floor)?, "0.000000002".parse()?);
+    /// // Rounding after every step leads to a different result.
+    /// assert_eq!(a.rmul(b, Floor)?.rdiv(c, Floor)?, "0".parse()?);
+    ///
+    /// // The product overflows the layout, but the result fits.
+    /// let big: Amount = "9000000000".parse()?;
+    /// assert_eq!(big.rmul_div(big, big, Floor)?, big);
+    /// assert!(big.rmul(big, Floor).is_err());
+    /// # Ok(()) }
+    /// # #[cfg(not(feature = "i64"))]
+    /// # fn main() {}
+    /// ```
+    fn rmul_div(self, rhs: Rhs, divisor: Div, mode: RoundMode)
+        -> Result<Self::Output, Self::Error>;
+}
+
+/// Operations of all [`FixedPoint`] layouts in one trait for writing layout- and
+/// precision-generic code.
+///
+/// The trait is sealed and implemented for every enabled layout, including `I256`.
+///
+/// ```
+/// # #[cfg(all(feature = "i64", feature = "i128"))]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{ArithmeticError, FixedPoint, typenum::{U9, U18}, ops::{FixedPointOps, RoundMode::*}};
+///
+/// fn hypot<F: FixedPointOps>(a: F, b: F) -> Result<F, ArithmeticError> {
+///     a.rmul(a, Floor)?.cadd(b.rmul(b, Floor)?)?.rsqrt(Floor)
+/// }
+///
+/// let a: FixedPoint<i64, U9> = "3".parse()?;
+/// assert_eq!(hypot(a, "4".parse()?)?, "5".parse()?);
+/// let a: FixedPoint<i128, U18> = "0.3".parse()?;
+/// assert_eq!(hypot(a, "0.4".parse()?)?, "0.5".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(all(feature = "i64", feature = "i128")))]
+/// # fn main() {}
+/// ```
+///
+/// [`FixedPoint`]: ../struct.FixedPoint.html
+pub trait FixedPointOps:
+    Copy
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + FromStr<Err = ConvertError>
+    + From<bool>
+    + Into<f64>
+    + TryFrom<i64, Error = ConvertError>
+    + Zero
+    + One
+    + Bounded
+    + CheckedAdd<Output = Self, Error = ArithmeticError>
+    + CheckedSub<Output = Self, Error = ArithmeticError>
+    + RoundingMul<Output = Self, Error = ArithmeticError>
+    + RoundingDiv<Output = Self, Error = ArithmeticError>
+    + RoundingMulDiv<Output = Self, Error = ArithmeticError>
+    + CheckedRem<Output = Self, Quotient = <Self as FixedPointOps>::Layout, Error = ArithmeticError>
+    + sealed::Sealed
+{
+    /// The underlying integer type.
+    type Layout: Copy + Ord + Hash + fmt::Debug + fmt::Display;
+
+    /// The number of digits in the fractional part.
+    const PRECISION: i32;
+    /// The difference between `0.0` and the next larger representable number.
+    const EPSILON: Self;
+
+    /// Creates from the raw representation.
+    fn from_bits(raw: Self::Layout) -> Self;
+
+    /// Returns the raw representation.
+    fn into_bits(self) -> Self::Layout;
+
+    /// Checked [rounding][RoundMode] square root. Returns `Err` for negative argument.
+    fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError>;
+
+    /// Returns `1/n`.
+    fn recip(self, mode: RoundMode) -> Result<Self, ArithmeticError>;
+
+    /// Constructs the [rounded][RoundMode] quotient of two integers.
+    fn from_ratio(
+        numerator: Self::Layout,
+        denominator: Self::Layout,
+        mode: RoundMode,
+    ) -> Result<Self, ArithmeticError>;
+
+    /// Calculates `(a + b) / 2`. It never fails.
+    fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self;
+
+    /// Takes [rounded][RoundMode] integral part of the number.
+    fn integral(self, mode: RoundMode) -> Self::Layout;
+
+    /// Returns the largest integer less than or equal to the number.
+    fn floor(self) -> Self;
+
+    /// Returns the smallest integer greater than or equal to the number.
+    fn ceil(self) -> Self;
+
+    /// Returns the nearest integer, half-way cases away from zero.
+    fn round(self) -> Self;
+
+    /// Creates a new number from separate mantissa and exponent.
+    fn from_decimal(mantissa: Self::Layout, exponent: i32) -> Result<Self, ConvertError>;
+
+    /// Returns a pair `(mantissa, exponent)` where `exponent` is in `[-PRECISION, max_exponent]`.
+    ///
+    /// # Panics
+    /// If `max_exponent` is less than `-PRECISION`.
+    fn to_decimal(&self, max_exponent: i32) -> (Self::Layout, i32);
+}
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
 }
 
 // Impls for primitives.
 
 macro_rules! impl_for_ints {
     ($( $int:ty ),+ $(,)?) => {
-        $( impl_for_ints!(@single $int); )*
+        $(
+            impl_for_ints!(@single $int);
+            impl_for_ints!(@signed $int);
+        )*
+    };
+    (unsigned: $( $int:ty ),+ $(,)?) => {
+        $(
+            impl_for_ints!(@single $int);
+            impl_for_ints!(@unsigned $int);
+        )*
     };
     (@single $int:ty) => {
         impl Zero for $int {
@@ -441,7 +824,8 @@ macro_rules! impl_for_ints {
                 <$int>::saturating_mul(self, rhs)
             }
         }
-
+    };
+    (@signed $int:ty) => {
         impl RoundingDiv for $int {
             type Output = $int;
             type Error = ArithmeticError;
@@ -452,20 +836,16 @@ macro_rules! impl_for_ints {
                     return Err(ArithmeticError::DivisionByZero);
                 }
 
-                let mut result = self / rhs;
+                // Only `MIN / -1` overflows.
+                let mut result = self.checked_div(rhs).ok_or(ArithmeticError::Overflow)?;
                 let loss = self - result * rhs;
 
                 if loss != 0 {
                     let sign = self.signum() * rhs.signum();
+                    let loss_abs = loss.unsigned_abs();
+                    let half = loss_abs.cmp(&(rhs.unsigned_abs() - loss_abs));
 
-                    let add_signed_one = if mode == RoundMode::Nearest {
-                        let loss_abs = loss.abs();
-                        loss_abs + loss_abs >= rhs.abs()
-                    } else {
-                        mode as i32 == sign as i32
-                    };
-
-                    if add_signed_one {
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
                         result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
                     }
                 }
@@ -474,6 +854,29 @@ macro_rules! impl_for_ints {
             }
         }
     };
+    (@unsigned $int:ty) => {
+        impl RoundingDiv for $int {
+            type Output = $int;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self::Output, Self::Error> {
+                if rhs == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let result = self / rhs;
+                let loss = self % rhs;
+
+                let add_one =
+                    loss != 0 && mode.is_away_from_zero(false, loss.cmp(&(rhs - loss)), result % 2 != 0);
+
+                // `result + 1` can't overflow, because there is a loss only if `rhs > 1`.
+                Ok(if add_one { result + 1 } else { result })
+            }
+        }
+    };
 }
 
-impl_for_ints!(i8, i16, i32, i64, i128); // TODO: unsigned?
+impl_for_ints!(i8, i16, i32, i64, i128);
+impl_for_ints!(unsigned: u8, u16, u32, u64, u128);
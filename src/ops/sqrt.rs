@@ -15,13 +15,14 @@ macro_rules! impl_sqrt {
             fn sqrt(self) -> Self::Layout {
                 type Layout = <$prom as Promotion>::Layout;
 
-                #[cfg(feature = "std")]
+                // Any positive guess converges to the same result, it affects only performance.
+                #[cfg(all(feature = "std", not(feature = "deterministic")))]
                 #[inline]
                 fn guess(v: $prom) -> Layout {
                     v.as_positive_f64().sqrt() as Layout
                 }
 
-                #[cfg(not(feature = "std"))]
+                #[cfg(any(not(feature = "std"), feature = "deterministic"))]
                 #[inline]
                 fn guess(v: $prom) -> Layout {
                     #[inline]
@@ -30,7 +31,14 @@ macro_rules! impl_sqrt {
                         (core::mem::size_of::<$prom>() as u32 * 8) - 1 - v.leading_zeros()
                     }
 
-                    1 << ((log2_estimate(v) + 1) / 2)
+                    // The guess must not be less than the root, so it's `MAX` if the power of two
+                    // doesn't fit, which is possible only for unsigned layouts.
+                    let shift = (log2_estimate(v) + 1) >> 1;
+                    if shift < core::mem::size_of::<Layout>() as u32 * 8 {
+                        1 << shift
+                    } else {
+                        <Layout>::MAX
+                    }
                 }
 
                 #[inline]
@@ -49,12 +57,17 @@ macro_rules! impl_sqrt {
 
                 debug_assert!(self >= <$prom as Zero>::ZERO);
 
-                if self < <$prom>::from(4i8) {
-                    return ((self > <$prom as Zero>::ZERO) as i8).into();
+                if self < <$prom>::from(4 as Layout) {
+                    return (self > <$prom as Zero>::ZERO) as Layout;
                 }
 
                 // https://en.wikipedia.org/wiki/Methods_of_computing_square_roots#Babylonian_method
-                let next = |x: Layout| (self.div_l(x).as_layout() + x) >> 1;
+                // Unsigned layouts can overflow near `MAX`: the quotient is saturated and
+                // the sum of halves is used instead of the halved sum.
+                let next = |x: Layout| {
+                    let q = self.div_l(x).try_into().unwrap_or(<Layout>::MAX);
+                    (q >> 1) + (x >> 1) + (q & x & 1)
+                };
                 fixpoint(guess(self), next)
             }
         }
@@ -67,5 +80,15 @@ impl_sqrt!(i32);
 impl_sqrt!(i64);
 #[cfg(feature = "i64")]
 impl_sqrt!(i128);
+#[cfg(feature = "split-i128")]
+impl_sqrt!(crate::split_i128::SplitI128);
 #[cfg(feature = "i128")]
 impl_sqrt!(crate::i256);
+#[cfg(feature = "u16")]
+impl_sqrt!(u32);
+#[cfg(feature = "u32")]
+impl_sqrt!(u64);
+#[cfg(feature = "u64")]
+impl_sqrt!(u128);
+#[cfg(feature = "u128")]
+impl_sqrt!(crate::u256);
@@ -0,0 +1,280 @@
+//! Converters between `FixedPoint` and well-known protobuf types of `google.type`:
+//! [`Money`] and [`Decimal`], which are [`prost`] messages compatible with
+//! `google/type/money.proto` and `google/type/decimal.proto`.
+//!
+//! Conversions are exact: a number, which can't be represented by the target type without
+//! rounding, results in an error. Both directions validate messages as required by the spec:
+//! `nanos` of [`Money`] must be in `[-999_999_999, 999_999_999]` and have the same sign as
+//! `units` (if `units` isn't zero).
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use fixnum::{prost::{Decimal, Money}, FixedPoint, typenum::U9};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! let amount: Amount = "-1.75".parse()?;
+//! let money = amount.to_money("USD")?;
+//! assert_eq!((money.units, money.nanos), (-1, -750_000_000));
+//! assert_eq!(Amount::try_from(&money)?, amount);
+//!
+//! let decimal = Decimal::from(amount);
+//! assert_eq!(decimal.value, "-1.75");
+//! assert_eq!(Amount::try_from(&Decimal { value: "-175e-2".into() })?, amount);
+//! # Ok(()) }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+
+use crate::{ConvertError, ConvertErrorKind, FixedPoint};
+
+/// Represents an amount of money with its currency type, `google.type.Money`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Money {
+    /// The three-letter currency code defined in ISO 4217.
+    #[prost(string, tag = "1")]
+    pub currency_code: String,
+    /// The whole units of the amount.
+    #[prost(int64, tag = "2")]
+    pub units: i64,
+    /// Number of nano (10^-9) units of the amount, in `[-999_999_999, 999_999_999]`.
+    /// Must have the same sign as `units` if `units` isn't zero.
+    #[prost(int32, tag = "3")]
+    pub nanos: i32,
+}
+
+/// A representation of a decimal value, `google.type.Decimal`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Decimal {
+    /// The decimal value as a string, e.g. `"-1.75"` or `"2.5e-3"`.
+    #[prost(string, tag = "1")]
+    pub value: String,
+}
+
+const NANOS_PRECISION: u32 = 9;
+const NANOS_COEF: u128 = 10u128.pow(NANOS_PRECISION);
+const MAX_NANOS: i32 = NANOS_COEF as i32 - 1;
+
+impl Money {
+    fn integral_overflow() -> ConvertError {
+        let min = i64::MIN as i128 * NANOS_COEF as i128 - MAX_NANOS as i128;
+        let max = i64::MAX as u128 * NANOS_COEF + MAX_NANOS as u128;
+        ConvertError::integral_overflow(min, max, NANOS_PRECISION)
+    }
+
+    /// Validates the message and returns `(negative, units, nanos)` as absolute values.
+    fn to_parts(&self) -> Result<(bool, u128, u128), ConvertError> {
+        if !(-MAX_NANOS..=MAX_NANOS).contains(&self.nanos) {
+            return Err(ConvertError::new(
+                ConvertErrorKind::OutOfDomain,
+                "nanos must be in [-999999999, 999999999]",
+            ));
+        }
+
+        if self.units > 0 && self.nanos < 0 || self.units < 0 && self.nanos > 0 {
+            return Err(ConvertError::new(
+                ConvertErrorKind::OutOfDomain,
+                "units and nanos must have the same sign",
+            ));
+        }
+
+        let negative = self.units < 0 || self.nanos < 0;
+        let units = u128::from(self.units.unsigned_abs());
+        let nanos = u128::from(self.nanos.unsigned_abs());
+        Ok((negative, units, nanos))
+    }
+}
+
+/// Rewrites `google.type.Decimal` into the form accepted by `from_str_exact`,
+/// i.e. without the exponent, a leading `+` and omitted parts around the point.
+fn normalize_decimal(value: &str) -> Result<String, ConvertError> {
+    // Any layout has less than 40 integral digits, so longer shifts don't change the outcome.
+    const MAX_SHIFT: i64 = 80;
+
+    let invalid = || ConvertError::new(ConvertErrorKind::InvalidSyntax, "invalid decimal");
+
+    let (negative, unsigned) = match value.as_bytes().first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    };
+
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(pos) => {
+            let exponent = &unsigned[pos + 1..];
+            let digits = exponent.trim_start_matches(['+', '-']);
+            if digits.is_empty() || exponent.len() - digits.len() > 1 {
+                return Err(invalid());
+            }
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            // Saturate huge exponents, they're clamped by `MAX_SHIFT` anyway.
+            let exponent = exponent
+                .parse::<i64>()
+                .unwrap_or(if exponent.starts_with('-') {
+                    i64::MIN / 2
+                } else {
+                    i64::MAX / 2
+                });
+            (&unsigned[..pos], exponent)
+        }
+        None => (unsigned, 0),
+    };
+
+    let (integral, fractional) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integral.is_empty() && fractional.is_empty()
+        || !is_digits(integral)
+        || !is_digits(fractional)
+    {
+        return Err(invalid());
+    }
+
+    // Significant digits and the position of the point relative to their beginning.
+    let mut all = String::with_capacity(integral.len() + fractional.len());
+    all.push_str(integral);
+    all.push_str(fractional);
+    let first = match all.bytes().position(|b| b != b'0') {
+        Some(first) => first,
+        None => return Ok("0".into()),
+    };
+    let last = all.bytes().rposition(|b| b != b'0').unwrap_or(first);
+    let digits = &all[first..=last];
+    let point = (integral.len() as i64 - first as i64)
+        .saturating_add(exponent)
+        .clamp(-MAX_SHIFT, MAX_SHIFT);
+
+    let mut normalized = String::with_capacity(digits.len() + MAX_SHIFT as usize + 3);
+    if negative {
+        normalized.push('-');
+    }
+
+    if point <= 0 {
+        normalized.push_str("0.");
+        (0..-point).for_each(|_| normalized.push('0'));
+        normalized.push_str(digits);
+    } else if point as usize >= digits.len() {
+        normalized.push_str(digits);
+        (digits.len()..point as usize).for_each(|_| normalized.push('0'));
+    } else {
+        let (integral, fractional) = digits.split_at(point as usize);
+        let _ = write!(normalized, "{}.{}", integral, fractional);
+    }
+
+    Ok(normalized)
+}
+
+macro_rules! impl_prost {
+    ($layout:ty) => {
+        impl_prost!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: crate::Precision> FixedPoint<$layout, P> {
+            /// Converts the number to `google.type.Money` with the given currency code.
+            ///
+            /// Returns `Err` if the number has more than 9 fractional digits
+            /// or its integral part doesn't fit `i64`.
+            pub fn to_money(self, currency_code: impl Into<String>) -> Result<Money, ConvertError> {
+                let (negative, integral, fractional) = self.to_parts();
+
+                let units = i128::try_from(integral)
+                    .ok()
+                    .map(|units| if negative { -units } else { units })
+                    .and_then(|units| i64::try_from(units).ok())
+                    .ok_or_else(Money::integral_overflow)?;
+
+                let precision = Self::PRECISION as u32;
+                let nanos = if precision <= NANOS_PRECISION {
+                    fractional * 10u128.pow(NANOS_PRECISION - precision)
+                } else {
+                    let coef = 10u128.pow(precision - NANOS_PRECISION);
+                    if fractional % coef != 0 {
+                        return Err(ConvertError::fractional_precision(NANOS_PRECISION));
+                    }
+                    fractional / coef
+                };
+
+                let nanos = if negative { -(nanos as i32) } else { nanos as i32 };
+
+                Ok(Money {
+                    currency_code: currency_code.into(),
+                    units,
+                    nanos,
+                })
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: crate::Precision> TryFrom<&Money> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Converts `google.type.Money` exactly, the currency code is ignored.
+            ///
+            /// Returns `Err` if the message is invalid, `nanos` have more digits
+            /// than `PRECISION` allows or the number doesn't fit the layout.
+            fn try_from(money: &Money) -> Result<Self, Self::Error> {
+                let (negative, units, nanos) = money.to_parts()?;
+
+                let precision = Self::PRECISION as u32;
+                let fractional = if precision >= NANOS_PRECISION {
+                    nanos * 10u128.pow(precision - NANOS_PRECISION)
+                } else {
+                    let coef = 10u128.pow(NANOS_PRECISION - precision);
+                    if nanos % coef != 0 {
+                        return Err(ConvertError::fractional_precision(precision));
+                    }
+                    nanos / coef
+                };
+
+                Self::from_parts(negative, units, fractional)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: crate::Precision> From<FixedPoint<$layout, P>> for Decimal {
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                Self {
+                    value: value.to_string(),
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: crate::Precision> TryFrom<&Decimal> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Parses `google.type.Decimal` exactly, including the exponential notation.
+            ///
+            /// Returns `Err` if the value is invalid, has more fractional digits
+            /// than `PRECISION` allows or doesn't fit the layout.
+            fn try_from(decimal: &Decimal) -> Result<Self, Self::Error> {
+                Self::from_str_exact(&normalize_decimal(&decimal.value)?)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_prost!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_prost!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_prost!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_prost!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
+#[cfg(feature = "u16")]
+impl_prost!(u16, #[cfg_attr(docsrs, doc(cfg(feature = "u16")))]);
+#[cfg(feature = "u32")]
+impl_prost!(u32, #[cfg_attr(docsrs, doc(cfg(feature = "u32")))]);
+#[cfg(feature = "u64")]
+impl_prost!(u64, #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]);
+#[cfg(feature = "u128")]
+impl_prost!(u128, #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]);
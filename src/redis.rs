@@ -0,0 +1,75 @@
+use core::fmt::Display;
+
+use redis::{
+    FromRedisValue, NumericBehavior, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value,
+};
+
+use crate::FixedPoint;
+
+/// Writes the canonical string, e.g. `"-1.75"`, without any conversions through `f64`.
+impl<I, P> ToRedisArgs for FixedPoint<I, P>
+where
+    Self: Display,
+{
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg_fmt(self);
+    }
+
+    fn describe_numeric_behavior(&self) -> NumericBehavior {
+        NumericBehavior::NumberIsFloat
+    }
+}
+
+impl<I, P> ToSingleRedisArg for FixedPoint<I, P> where Self: Display {}
+
+macro_rules! impl_redis {
+    ($layout:ty) => {
+        impl_redis!($layout,);
+    };
+    ($layout:ty, $(#[$attr:meta])?) => {
+        $(#[$attr])?
+        impl<P: crate::Precision> FromRedisValue for FixedPoint<$layout, P> {
+            /// Parses strings and integers exactly, like `from_str_exact`.
+            ///
+            /// Doubles of RESP3 are rejected, because they have been rounded already.
+            fn from_redis_value(value: Value) -> Result<Self, ParsingError> {
+                let parse = |s: &str| {
+                    Self::from_str_exact(s)
+                        .map_err(|err| ParsingError::from(format!("{} (value was {:?})", err, s)))
+                };
+
+                match value {
+                    Value::BulkString(bytes) => parse(core::str::from_utf8(&bytes)?),
+                    Value::SimpleString(s) | Value::VerbatimString { text: s, .. } => parse(&s),
+                    Value::Int(int) => parse(itoa::Buffer::new().format(int)),
+                    Value::Attribute { data, .. } => Self::from_redis_value(*data),
+                    value => Err(format!(
+                        "response type not convertible to FixedPoint (value was {:?})",
+                        value
+                    )
+                    .into()),
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_redis!(i16, #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]);
+#[cfg(feature = "i32")]
+impl_redis!(i32, #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]);
+#[cfg(feature = "i64")]
+impl_redis!(i64, #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]);
+#[cfg(feature = "i128")]
+impl_redis!(i128, #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]);
+#[cfg(feature = "u16")]
+impl_redis!(u16, #[cfg_attr(docsrs, doc(cfg(feature = "u16")))]);
+#[cfg(feature = "u32")]
+impl_redis!(u32, #[cfg_attr(docsrs, doc(cfg(feature = "u32")))]);
+#[cfg(feature = "u64")]
+impl_redis!(u64, #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]);
+#[cfg(feature = "u128")]
+impl_redis!(u128, #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]);
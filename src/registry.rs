@@ -0,0 +1,157 @@
+//! A registry of fixed-point types compiled into the binary.
+//!
+//! Large systems often mix many aliases with different precisions. Types declared by
+//! [`fixnum_type!`](crate::fixnum_type) are registered automatically, other ones can be registered
+//! by [`register_type!`](crate::register_type). All registered types are listed by
+//! [`introspect()`], e.g. for ops dashboards or validating configs.
+//!
+//! Registration is based on the [`inventory`] crate, so it works across crates
+//! and doesn't require any initialization at runtime.
+//!
+//! ```
+//! # #[cfg(all(feature = "i64", feature = "i128"))]
+//! # mod types {
+//! use fixnum::{fixnum_type, register_type, typenum::U18, FixedPoint};
+//!
+//! fixnum_type! {
+//!     /// Signed fixed point amount over 64 bits, 9 decimal places.
+//!     pub type Amount = FixedPoint<i64, U9>;
+//! }
+//!
+//! pub type Ratio = FixedPoint<i128, U18>;
+//! register_type!(Ratio);
+//! # }
+//!
+//! # #[cfg(all(feature = "i64", feature = "i128"))]
+//! # fn main() {
+//! let amount = fixnum::introspect().find(|info| info.name() == "Amount").unwrap();
+//! assert_eq!((amount.layout(), amount.precision()), ("i64", 9));
+//! assert_eq!(amount.to_string(), "Amount: i64, 9 decimal places, [-9223372036.854775808, 9223372036.854775807]");
+//!
+//! let ratio = fixnum::introspect().find(|info| info.name() == "Ratio").unwrap();
+//! assert_eq!((ratio.layout(), ratio.precision()), ("i128", 18));
+//! # }
+//! # #[cfg(not(all(feature = "i64", feature = "i128")))]
+//! # fn main() {}
+//! ```
+//!
+//! [`inventory`]: https://docs.rs/inventory
+
+use core::fmt;
+
+use crate::errors::write_bits;
+
+/// Information about a registered fixed-point type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeInfo {
+    name: &'static str,
+    layout: &'static str,
+    precision: u32,
+    min: i128,
+    max: u128,
+}
+
+impl TypeInfo {
+    /// Describes the type `T` registered under `name`.
+    pub const fn of<T: Layout>(name: &'static str) -> Self {
+        Self {
+            name,
+            layout: T::LAYOUT,
+            precision: T::PRECISION,
+            min: T::MIN_BITS,
+            max: T::MAX_BITS,
+        }
+    }
+
+    /// Returns the name of the type, usually the alias.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the name of the underlying integer type, e.g. `"i64"`.
+    pub const fn layout(&self) -> &'static str {
+        self.layout
+    }
+
+    /// Returns the number of digits in the fractional part.
+    pub const fn precision(&self) -> u32 {
+        self.precision
+    }
+
+    /// Returns the raw representation of `MIN`.
+    pub const fn min_bits(&self) -> i128 {
+        self.min
+    }
+
+    /// Returns the raw representation of `MAX`.
+    pub const fn max_bits(&self) -> u128 {
+        self.max
+    }
+}
+
+impl fmt::Display for TypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}, {} decimal places, [",
+            self.name, self.layout, self.precision
+        )?;
+        write_bits(f, self.min < 0, self.min.unsigned_abs(), self.precision)?;
+        f.write_str(", ")?;
+        write_bits(f, false, self.max, self.precision)?;
+        f.write_str("]")
+    }
+}
+
+/// Layouts of [`FixedPoint`](crate::FixedPoint) types that can be registered.
+///
+/// The trait is sealed and implemented for every enabled primitive layout.
+pub trait Layout: crate::ops::sealed::Sealed {
+    /// The name of the underlying integer type.
+    const LAYOUT: &'static str;
+    /// The number of digits in the fractional part.
+    const PRECISION: u32;
+    /// The raw representation of `MIN`.
+    const MIN_BITS: i128;
+    /// The raw representation of `MAX`.
+    const MAX_BITS: u128;
+}
+
+inventory::collect!(TypeInfo);
+
+/// Returns all registered fixed-point types in an unspecified order.
+pub fn introspect() -> impl Iterator<Item = &'static TypeInfo> {
+    inventory::iter::<TypeInfo>.into_iter()
+}
+
+/// Registers a fixed-point type to be listed by [`introspect()`].
+///
+/// Types declared by [`fixnum_type!`](crate::fixnum_type) are registered automatically.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # mod types {
+/// use fixnum::{register_type, typenum::U2, FixedPoint};
+///
+/// pub type Price = FixedPoint<i64, U2>;
+/// register_type!(Price);
+/// # }
+/// # fn main() {}
+/// ```
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "inventory")))]
+macro_rules! register_type {
+    ($ty:ident) => {
+        $crate::_priv::inventory::submit! {
+            $crate::registry::TypeInfo::of::<$ty>(stringify!($ty))
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_type {
+    ($ty:ident) => {
+        $crate::register_type!($ty);
+    };
+}
@@ -7,6 +7,15 @@
 //!
 //! By default, `FixedPoint` is deserialized from strings, floats and integers for human readable
 //! formats and `repr` for binary ones.
+//!
+//! Thus, `FixedPoint` can be used as a key of maps, e.g. `BTreeMap<Price, Size>` for price
+//! levels: keys are strings in human readable formats (as required by JSON) and `repr` in binary
+//! ones.
+//!
+//! Deserialization never panics, whatever the input is: malformed strings, numbers with
+//! any number of digits, values out of the layout's range, `NaN` and infinite floats, unexpected
+//! types and nesting result in errors. It holds for all modules here and is covered by
+//! property-based tests, so they can be used for untrusted input of internet-facing services.
 
 #[cfg(feature = "quick-xml")]
 extern crate alloc;
@@ -22,7 +31,11 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::{string::Stringify, FixedPoint};
+use crate::{
+    ops::{RoundMode, RoundingDiv},
+    string::Stringify,
+    FixedPoint, Precision,
+};
 
 impl<I, P> Serialize for FixedPoint<I, P>
 where
@@ -145,6 +158,54 @@ where
     // TODO: support serde_json/arbitrary_precision.
 }
 
+/// Deserializes a borrowed or owned string, e.g. a map key or a string of `serde_json::Value`,
+/// and passes it to `parse`, which returns the expected value's description on error.
+fn deserialize_str<'de, D, T>(
+    deserializer: D,
+    parse: impl FnOnce(&str) -> Result<T, &'static str>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct StrVisitor<F>(F);
+
+    impl<'de, T, F> de::Visitor<'de> for StrVisitor<F>
+    where
+        F: FnOnce(&str) -> Result<T, &'static str>,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("string containing a fixed-point number")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+            (self.0)(s).map_err(|expected| E::invalid_value(de::Unexpected::Str(s), &expected))
+        }
+    }
+
+    deserializer.deserialize_str(StrVisitor(parse))
+}
+
+/// `FixedPoint` deserialized only from a string.
+struct FromString<I, P>(FixedPoint<I, P>);
+
+impl<'de, I, P> Deserialize<'de> for FromString<I, P>
+where
+    FixedPoint<I, P>: FromStr,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_str(deserializer, |s| {
+            s.parse()
+                .map(FromString)
+                .map_err(|_| "string containing a fixed-point number")
+        })
+    }
+}
+
 /// (De)serializes `FixedPoint` as inner representation.
 pub mod repr {
     use super::*;
@@ -228,13 +289,7 @@ pub mod str {
         D: Deserializer<'de>,
         FixedPoint<I, P>: FromStr,
     {
-        let s = <&str>::deserialize(deserializer)?;
-        s.parse().map(F::from).map_err(|_| {
-            D::Error::invalid_value(
-                de::Unexpected::Str(s),
-                &"string containing a fixed-point number",
-            )
-        })
+        FromString::deserialize(deserializer).map(|s| F::from(s.0))
     }
 }
 
@@ -265,16 +320,7 @@ pub mod str_option {
         D: Deserializer<'de>,
         FixedPoint<I, P>: FromStr,
     {
-        let s = Option::<&str>::deserialize(deserializer)?;
-        s.map(|s| {
-            s.parse().map(F::from).map_err(|_| {
-                D::Error::invalid_value(
-                    de::Unexpected::Str(s),
-                    &"string containing a fixed-point number",
-                )
-            })
-        })
-        .transpose()
+        Option::<FromString<I, P>>::deserialize(deserializer).map(|s| s.map(|s| F::from(s.0)))
     }
 }
 
@@ -354,3 +400,590 @@ pub mod float_option {
         .transpose()
     }
 }
+
+/// (De)serializes `FixedPoint` as a struct `{"value": "1.23", "scale": 9}`,
+/// where `scale` is the precision used by the producer.
+///
+/// On deserialization, the value is checked to have at most `scale` fractional digits and
+/// then rescaled to the target precision. It fails if the value cannot be represented exactly,
+/// trailing zeros are ignored.
+pub mod scaled {
+    use serde::{
+        de::{MapAccess, SeqAccess},
+        ser::SerializeStruct,
+    };
+
+    use super::*;
+
+    const FIELDS: &[&str] = &["value", "scale"];
+
+    /// Serializes to a struct with `value` and `scale` fields.
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        P: Precision,
+        S: Serializer,
+        FixedPoint<I, P>: Stringify,
+    {
+        let mut buf = Default::default();
+        fp.clone().into().stringify(&mut buf);
+
+        let mut state = serializer.serialize_struct("FixedPoint", FIELDS.len())?;
+        state.serialize_field("value", buf.as_str())?;
+        state.serialize_field("scale", &P::U32)?;
+        state.end()
+    }
+
+    /// Deserializes from a struct with `value` and `scale` fields.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        P: Precision,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: FromStr,
+    {
+        deserializer
+            .deserialize_struct("FixedPoint", FIELDS, ScaledVisitor(PhantomData))
+            .map(F::from)
+    }
+
+    struct ScaledVisitor<I, P>(PhantomData<(I, P)>);
+
+    impl<'de, I, P> de::Visitor<'de> for ScaledVisitor<I, P>
+    where
+        P: Precision,
+        FixedPoint<I, P>: FromStr,
+    {
+        type Value = FixedPoint<I, P>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("struct with a fixed-point number `value` and its `scale`")
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let value = seq
+                .next_element::<Value<I, P>>()?
+                .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+            let scale = seq
+                .next_element::<u32>()?
+                .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+            value.check_scale(scale)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut value = None;
+            let mut scale = None;
+
+            while let Some(field) = map.next_key::<Field>()? {
+                match field {
+                    Field::Value if value.is_some() => {
+                        return Err(A::Error::duplicate_field("value"))
+                    }
+                    Field::Value => value = Some(map.next_value::<Value<I, P>>()?),
+                    Field::Scale if scale.is_some() => {
+                        return Err(A::Error::duplicate_field("scale"))
+                    }
+                    Field::Scale => scale = Some(map.next_value::<u32>()?),
+                }
+            }
+
+            let value = value.ok_or_else(|| A::Error::missing_field("value"))?;
+            let scale = scale.ok_or_else(|| A::Error::missing_field("scale"))?;
+
+            value.check_scale(scale)
+        }
+    }
+
+    enum Field {
+        Value,
+        Scale,
+    }
+
+    impl<'de> Deserialize<'de> for Field {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct FieldVisitor;
+
+            impl<'de> de::Visitor<'de> for FieldVisitor {
+                type Value = Field;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("`value` or `scale`")
+                }
+
+                fn visit_str<E: de::Error>(self, s: &str) -> Result<Field, E> {
+                    match s {
+                        "value" => Ok(Field::Value),
+                        "scale" => Ok(Field::Scale),
+                        _ => Err(E::unknown_field(s, FIELDS)),
+                    }
+                }
+            }
+
+            deserializer.deserialize_identifier(FieldVisitor)
+        }
+    }
+
+    /// The `value` field, parsed before `scale` is known, which can come later.
+    struct Value<I, P> {
+        fp: FixedPoint<I, P>,
+        /// The number of fractional digits including trailing zeros.
+        fractional_len: usize,
+    }
+
+    impl<I, P> Value<I, P> {
+        fn check_scale<E: de::Error>(self, scale: u32) -> Result<FixedPoint<I, P>, E> {
+            if self.fractional_len > scale as usize {
+                return Err(E::invalid_value(
+                    de::Unexpected::Unsigned(scale.into()),
+                    &"scale not less than the number of fractional digits in `value`",
+                ));
+            }
+
+            Ok(self.fp)
+        }
+    }
+
+    impl<'de, I, P> Deserialize<'de> for Value<I, P>
+    where
+        P: Precision,
+        FixedPoint<I, P>: FromStr,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserialize_str(deserializer, |value| {
+                let trimmed = value.trim();
+                let (integral, fractional) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+                let fractional_len = fractional.len();
+
+                // Trailing zeros don't affect the value, so they can be dropped to fit the precision.
+                let fractional = fractional.trim_end_matches('0');
+                if fractional.len() > P::USIZE {
+                    return Err(
+                        "string containing a fixed-point number representable in the target precision",
+                    );
+                }
+
+                let exact = if fractional.is_empty() {
+                    integral
+                } else {
+                    &trimmed[..integral.len() + 1 + fractional.len()]
+                };
+
+                exact
+                    .parse()
+                    .map(|fp| Value { fp, fractional_len })
+                    .map_err(|_| "string containing a fixed-point number")
+            })
+        }
+    }
+}
+
+/// (De)serializes `FixedPoint` at `N` decimal places regardless of the precision of the type,
+/// e.g. amounts stored with 9 decimal places can be sent with 2 ones:
+/// `#[serde(with = "fixnum::serde::as_scale::<2>")]`.
+///
+/// Human readable formats use a string, binary ones use the inner representation scaled
+/// to `N` decimal places, like the default implementation does for `FixedPoint<_, U{N}>`.
+///
+/// If `N` is less than the precision, the value is rounded to the nearest on serialization,
+/// otherwise it's rounded to the nearest on deserialization. Strings with more than `N`
+/// fractional digits are rejected. It fails if the result doesn't fit the layout or `10 ^ N`
+/// doesn't fit the layout.
+///
+/// It's a type instead of a module to be generic over `N`, but is named like other modules here.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U9};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Payment {
+///     #[serde(with = "fixnum::serde::as_scale::<2>")]
+///     amount: FixedPoint<i64, U9>,
+/// }
+///
+/// let payment = Payment { amount: "12.345".parse()? };
+/// assert_eq!(serde_json::to_string(&payment)?, r#"{"amount":"12.35"}"#);
+///
+/// let payment: Payment = serde_json::from_str(r#"{"amount":"12.3"}"#)?;
+/// assert_eq!(payment.amount, "12.3".parse()?);
+/// assert!(serde_json::from_str::<Payment>(r#"{"amount":"12.345"}"#).is_err());
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[allow(non_camel_case_types)]
+pub struct as_scale<const N: u8>;
+
+impl<const N: u8> as_scale<N> {
+    /// Serializes at `N` decimal places.
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        I: Serialize,
+        S: Serializer,
+        FixedPoint<I, P>: Scale<Layout = I> + Stringify,
+    {
+        let mantissa =
+            fp.clone().into().to_scale(N.into()).ok_or_else(|| {
+                serde::ser::Error::custom("cannot be rescaled to `N` decimal places")
+            })?;
+
+        if serializer.is_human_readable() {
+            // The rounded number is exactly representable in the type if `N` is less,
+            // and the number is exactly representable at `N` decimal places otherwise.
+            let fp = FixedPoint::<I, P>::from_scale(mantissa, N.into())
+                .ok_or_else(|| serde::ser::Error::custom("too big number"))?;
+            let mut buf = Default::default();
+            fp.stringify(&mut buf);
+            serializer.serialize_str(buf.as_str())
+        } else {
+            mantissa.serialize(serializer)
+        }
+    }
+
+    /// Deserializes from `N` decimal places.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        I: Deserialize<'de>,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: Scale<Layout = I> + FromStr,
+    {
+        if !deserializer.is_human_readable() {
+            let mantissa = I::deserialize(deserializer)?;
+            return FixedPoint::<I, P>::from_scale(mantissa, N.into())
+                .map(F::from)
+                .ok_or_else(|| D::Error::custom("cannot be rescaled from `N` decimal places"));
+        }
+
+        deserialize_str(deserializer, |s| {
+            let fractional = s
+                .trim()
+                .split_once('.')
+                .map_or("", |(_, fractional)| fractional);
+
+            if fractional.len() > usize::from(N) {
+                return Err("string containing no more fractional digits than `N`");
+            }
+
+            // `FromStr` rounds to the nearest if `N` is greater than the precision.
+            s.parse()
+                .map(F::from)
+                .map_err(|_| "string containing a fixed-point number")
+        })
+    }
+}
+
+/// Deserializes `FixedPoint` from configuration files, catching floats that aren't exact,
+/// e.g. `fee = 0.1` in TOML or `fee: 0.1` in YAML. Serializes to a string.
+///
+/// A float is converted through its shortest representation, i.e. the number written in
+/// the file, instead of the binary value, so `0.1` becomes exactly `0.1` at any precision.
+/// Strings, integers and floats with more fractional digits than `PRECISION` are rejected.
+/// Use [`exact_or_warn`] to accept them with rounding instead.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U2};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "fixnum::serde::exact")]
+///     fee: FixedPoint<i64, U2>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"fee": 0.1}"#)?;
+/// assert_eq!(config.fee, "0.1".parse()?);
+/// assert!(serde_json::from_str::<Config>(r#"{"fee": 0.125}"#).is_err());
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod exact {
+    use super::{inexact::ExactVisitor, *};
+
+    /// Serializes to a string.
+    #[inline]
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        S: Serializer,
+        FixedPoint<I, P>: Stringify,
+    {
+        str::serialize(fp, serializer)
+    }
+
+    /// Deserializes from a string, an integer or a float without rounding.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        P: Precision,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
+    {
+        deserializer
+            .deserialize_any(ExactVisitor::new(false))
+            .map(F::from)
+    }
+}
+
+/// The same as [`exact`], but numbers with more fractional digits than `PRECISION` are rounded
+/// to the nearest and reported to the hook installed by [`set_inexact_hook`].
+///
+/// It's intended for migrations: configuration drift is logged at startup without breaking
+/// existing files.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, typenum::U2};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(with = "fixnum::serde::exact_or_warn")]
+///     fee: FixedPoint<i64, U2>,
+/// }
+///
+/// fixnum::serde::set_inexact_hook(|inexact| {
+///     eprintln!("{} is rounded to {} decimal places", inexact.input, inexact.precision);
+/// });
+///
+/// let config: Config = serde_json::from_str(r#"{"fee": 0.125}"#)?;
+/// assert_eq!(config.fee, "0.13".parse()?);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub mod exact_or_warn {
+    use super::{inexact::ExactVisitor, *};
+
+    /// Serializes to a string.
+    #[inline]
+    pub fn serialize<F, I, P, S>(fp: &F, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        F: Into<FixedPoint<I, P>> + Clone,
+        S: Serializer,
+        FixedPoint<I, P>: Stringify,
+    {
+        str::serialize(fp, serializer)
+    }
+
+    /// Deserializes from a string, an integer or a float, reporting rounding to the hook.
+    pub fn deserialize<'de, F, I, P, D>(deserializer: D) -> Result<F, D::Error>
+    where
+        F: From<FixedPoint<I, P>>,
+        P: Precision,
+        D: Deserializer<'de>,
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
+    {
+        deserializer
+            .deserialize_any(ExactVisitor::new(true))
+            .map(F::from)
+    }
+}
+
+#[cfg(feature = "std")]
+pub use inexact::{remove_inexact_hook, set_inexact_hook, Inexact};
+
+#[cfg(feature = "std")]
+mod inexact {
+    use std::{string::ToString, sync::RwLock};
+
+    use super::*;
+
+    /// A number rounded by [`exact_or_warn`](super::exact_or_warn), passed to the hook.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub struct Inexact<'a> {
+        /// The input as written, e.g. `"0.125"`. Floats are in their shortest representation.
+        pub input: &'a str,
+        /// The precision of the target type.
+        pub precision: u32,
+    }
+
+    type Hook = Box<dyn Fn(&Inexact<'_>) + Send + Sync>;
+
+    static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+    /// Installs the hook called on every number rounded by
+    /// [`exact_or_warn`](super::exact_or_warn), replacing the previous one.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn set_inexact_hook(hook: impl Fn(&Inexact<'_>) + Send + Sync + 'static) {
+        *HOOK.write().unwrap_or_else(|err| err.into_inner()) = Some(Box::new(hook));
+    }
+
+    /// Removes the installed hook, numbers are rounded silently then.
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn remove_inexact_hook() {
+        *HOOK.write().unwrap_or_else(|err| err.into_inner()) = None;
+    }
+
+    fn report(input: &str, precision: u32) {
+        if let Some(hook) = &*HOOK.read().unwrap_or_else(|err| err.into_inner()) {
+            hook(&Inexact { input, precision });
+        }
+    }
+
+    pub(super) struct ExactVisitor<I, P> {
+        warn: bool,
+        _marker: PhantomData<(I, P)>,
+    }
+
+    impl<I, P> ExactVisitor<I, P> {
+        pub(super) fn new(warn: bool) -> Self {
+            Self {
+                warn,
+                _marker: PhantomData,
+            }
+        }
+
+        fn parse<E: de::Error>(
+            &self,
+            s: &str,
+            unexpected: de::Unexpected<'_>,
+        ) -> Result<FixedPoint<I, P>, E>
+        where
+            P: Precision,
+            FixedPoint<I, P>: FromStr,
+        {
+            // Trailing zeros don't affect the value, so they don't make it inexact.
+            let fractional = s
+                .trim()
+                .split_once('.')
+                .map_or("", |(_, fractional)| fractional);
+            if fractional.trim_end_matches('0').len() > P::USIZE {
+                if !self.warn {
+                    return Err(E::invalid_value(
+                        unexpected,
+                        &"number with no more fractional digits than the precision",
+                    ));
+                }
+                report(s, P::U32);
+            }
+
+            // `FromStr` rounds to the nearest.
+            s.parse()
+                .map_err(|_| E::invalid_value(unexpected, &"fixed-point number"))
+        }
+    }
+
+    impl<'de, I, P> de::Visitor<'de> for ExactVisitor<I, P>
+    where
+        P: Precision,
+        FixedPoint<I, P>: FromStr + TryFrom<i64> + TryFrom<u64>,
+    {
+        type Value = FixedPoint<I, P>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("number or string containing a fixed-point number")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+            self.parse(s, de::Unexpected::Str(s))
+        }
+
+        fn visit_f64<E: de::Error>(self, f: f64) -> Result<Self::Value, E> {
+            // `Display` of floats prints the shortest representation without an exponent.
+            self.parse(&f.to_string(), de::Unexpected::Float(f))
+        }
+
+        fn visit_i64<E: de::Error>(self, i: i64) -> Result<Self::Value, E> {
+            Self::Value::try_from(i).map_err(|_| E::invalid_value(de::Unexpected::Signed(i), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, u: u64) -> Result<Self::Value, E> {
+            Self::Value::try_from(u)
+                .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(u), &self))
+        }
+    }
+}
+
+mod scale {
+    /// Conversion of the inner representation to another number of decimal places.
+    pub trait Scale: Sized {
+        type Layout;
+
+        /// Returns the inner representation at `scale` decimal places, rounded to the nearest.
+        fn to_scale(self, scale: u32) -> Option<Self::Layout>;
+
+        /// Creates from the inner representation at `scale` decimal places,
+        /// rounded to the nearest.
+        fn from_scale(mantissa: Self::Layout, scale: u32) -> Option<Self>;
+    }
+}
+
+use scale::Scale;
+
+macro_rules! impl_scale {
+    ($layout:ty) => {
+        impl<P: Precision> Scale for FixedPoint<$layout, P> {
+            type Layout = $layout;
+
+            fn to_scale(self, scale: u32) -> Option<$layout> {
+                let ten: $layout = 10;
+                ten.checked_pow(scale)?;
+
+                if scale >= P::U32 {
+                    self.inner.checked_mul(ten.pow(scale - P::U32))
+                } else {
+                    self.inner
+                        .rdiv(ten.pow(P::U32 - scale), RoundMode::Nearest)
+                        .ok()
+                }
+            }
+
+            fn from_scale(mantissa: $layout, scale: u32) -> Option<Self> {
+                let ten: $layout = 10;
+                ten.checked_pow(scale)?;
+
+                let inner = if scale <= P::U32 {
+                    mantissa.checked_mul(ten.pow(P::U32 - scale))?
+                } else {
+                    mantissa
+                        .rdiv(ten.pow(scale - P::U32), RoundMode::Nearest)
+                        .ok()?
+                };
+                Some(Self::from_bits(inner))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "i16")]
+impl_scale!(i16);
+#[cfg(feature = "i32")]
+impl_scale!(i32);
+#[cfg(feature = "i64")]
+impl_scale!(i64);
+#[cfg(feature = "i128")]
+impl_scale!(i128);
+#[cfg(feature = "u16")]
+impl_scale!(u16);
+#[cfg(feature = "u32")]
+impl_scale!(u32);
+#[cfg(feature = "u64")]
+impl_scale!(u64);
+#[cfg(feature = "u128")]
+impl_scale!(u128);
@@ -0,0 +1,83 @@
+//! Contains selection helpers for slices of fixed-point numbers.
+//!
+//! Numbers are compared by their raw representations, which is equivalent to comparing them
+//! numerically for every layout. Selection is done in place by [`slice::select_nth_unstable`],
+//! so it takes `O(n + k log k)` time without allocations. Copy the slice first to keep the
+//! original order.
+//!
+//! [`slice::select_nth_unstable`]: https://doc.rust-lang.org/std/primitive.slice.html#method.select_nth_unstable
+
+use crate::FixedPoint;
+
+/// Moves the `k` largest numbers to the end of the slice and returns them sorted in descending
+/// order. If `k` is greater than the length of the slice, the whole slice is returned.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, slice, typenum::U9};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let mut bids: Vec<Price> = ["100.5", "101", "99.75", "100", "101.25"]
+///     .iter()
+///     .map(|s| s.parse())
+///     .collect::<Result<_, _>>()?;
+///
+/// let best = slice::top_k(&mut bids, 2);
+/// assert_eq!(best, ["101.25".parse()?, "101".parse()?]);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub fn top_k<I: Ord, P>(values: &mut [FixedPoint<I, P>], k: usize) -> &mut [FixedPoint<I, P>] {
+    let len = values.len();
+    let k = k.min(len);
+
+    if k == 0 {
+        return &mut [];
+    }
+
+    // Numbers after `len - k` are not less than the selected one.
+    values.select_nth_unstable_by(len - k, |a, b| a.as_bits().cmp(b.as_bits()));
+
+    let top = &mut values[len - k..];
+    top.sort_unstable_by(|a, b| b.as_bits().cmp(a.as_bits()));
+    top
+}
+
+/// Moves the `k` smallest numbers to the beginning of the slice and returns them sorted in
+/// ascending order. If `k` is greater than the length of the slice, the whole slice is returned.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, slice, typenum::U9};
+///
+/// type Price = FixedPoint<i64, U9>;
+///
+/// let mut asks: Vec<Price> = ["100.5", "101", "99.75", "100", "101.25"]
+///     .iter()
+///     .map(|s| s.parse())
+///     .collect::<Result<_, _>>()?;
+///
+/// let best = slice::bottom_k(&mut asks, 3);
+/// assert_eq!(best, ["99.75".parse()?, "100".parse()?, "100.5".parse()?]);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub fn bottom_k<I: Ord, P>(values: &mut [FixedPoint<I, P>], k: usize) -> &mut [FixedPoint<I, P>] {
+    let k = k.min(values.len());
+
+    if k == 0 {
+        return &mut [];
+    }
+
+    // Numbers before `k - 1` are not greater than the selected one.
+    values.select_nth_unstable_by(k - 1, |a, b| a.as_bits().cmp(b.as_bits()));
+
+    let bottom = &mut values[..k];
+    bottom.sort_unstable_by(|a, b| a.as_bits().cmp(b.as_bits()));
+    bottom
+}
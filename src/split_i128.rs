@@ -0,0 +1,483 @@
+//! A 128-bit integer of two `u64` words used to promote the `i64` layout without native `i128`
+//! arithmetic, which is emulated by slow and bloaty `compiler-rt` routines on some targets.
+//!
+//! Only operations required by [`Promotion`] are implemented, all of them on `u64` words.
+
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::{layout::Promotion, ops::Zero, ConvertError, ConvertErrorKind};
+
+const LOW_HALF: u64 = u32::MAX as u64;
+
+/// A two's complement number `hi * 2^64 + lo`.
+///
+/// The order of fields matters: derived `Ord` compares them lexicographically.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct SplitI128 {
+    hi: i64,
+    lo: u64,
+}
+
+/// The magnitude `(hi, lo)`.
+type Magnitude = (u64, u64);
+
+impl SplitI128 {
+    const fn from_i64(x: i64) -> Self {
+        Self {
+            hi: x >> 63,
+            lo: x as u64,
+        }
+    }
+
+    #[inline]
+    fn is_negative(self) -> bool {
+        self.hi < 0
+    }
+
+    #[inline]
+    fn unsigned_abs(self) -> Magnitude {
+        let magnitude = (self.hi as u64, self.lo);
+        if self.is_negative() {
+            negate(magnitude)
+        } else {
+            magnitude
+        }
+    }
+
+    /// Wraps if the magnitude doesn't fit.
+    #[inline]
+    fn from_magnitude(negative: bool, magnitude: Magnitude) -> Self {
+        let (hi, lo) = if negative {
+            negate(magnitude)
+        } else {
+            magnitude
+        };
+        Self { hi: hi as i64, lo }
+    }
+
+    /// Returns `None` if the magnitude doesn't fit.
+    #[inline]
+    fn checked_from_magnitude(negative: bool, magnitude: Magnitude) -> Option<Self> {
+        let fits = match magnitude {
+            (hi, _) if hi < 1 << 63 => true,
+            // Only `MIN` has the magnitude `2 ^ 127`.
+            (hi, lo) => negative && hi == 1 << 63 && lo == 0,
+        };
+        fits.then(|| Self::from_magnitude(negative, magnitude))
+    }
+
+    #[inline]
+    fn div_rem_magnitude(self, rhs: i64) -> (Magnitude, u64) {
+        let (hi, lo) = self.unsigned_abs();
+        let divisor = rhs.unsigned_abs();
+        let (quotient_hi, remainder) = (hi / divisor, hi % divisor);
+        let (quotient_lo, remainder) = div_wide(remainder, lo, divisor);
+        ((quotient_hi, quotient_lo), remainder)
+    }
+}
+
+#[inline]
+fn negate((hi, lo): Magnitude) -> Magnitude {
+    let lo = (!lo).wrapping_add(1);
+    let hi = (!hi).wrapping_add((lo == 0) as u64);
+    (hi, lo)
+}
+
+/// Returns the full product `(hi, lo)` using 32-bit halves.
+#[inline]
+fn mul_wide(a: u64, b: u64) -> Magnitude {
+    let (a1, a0) = (a >> 32, a & LOW_HALF);
+    let (b1, b0) = (b >> 32, b & LOW_HALF);
+
+    let (p00, p01, p10, p11) = (a0 * b0, a0 * b1, a1 * b0, a1 * b1);
+    // At most `3 * (2^32 - 1)`, thus it fits.
+    let mid = (p00 >> 32) + (p01 & LOW_HALF) + (p10 & LOW_HALF);
+
+    let lo = (mid << 32) | (p00 & LOW_HALF);
+    let hi = p11 + (p01 >> 32) + (p10 >> 32) + (mid >> 32);
+    (hi, lo)
+}
+
+/// Divides `hi * 2^64 + lo` by `divisor`, returning `(quotient, remainder)`.
+/// Requires `hi < divisor`, so the quotient fits.
+///
+/// The algorithm is `divlu` from "Hacker's Delight" (Knuth's algorithm D for two digits).
+#[inline]
+fn div_wide(hi: u64, lo: u64, divisor: u64) -> (u64, u64) {
+    debug_assert!(hi < divisor);
+    const BASE: u64 = 1 << 32;
+
+    // Normalize the divisor, so the estimated digits are off by at most 2.
+    let shift = divisor.leading_zeros();
+    let divisor = divisor << shift;
+    let (d1, d0) = (divisor >> 32, divisor & LOW_HALF);
+
+    let n32 = if shift == 0 {
+        hi
+    } else {
+        (hi << shift) | (lo >> (64 - shift))
+    };
+    let n10 = lo << shift;
+    let (n1, n0) = (n10 >> 32, n10 & LOW_HALF);
+
+    let estimate = |n: u64, next: u64| {
+        let (mut q, mut rem) = (n / d1, n % d1);
+        while q >= BASE || q * d0 > (rem << 32 | next) {
+            q -= 1;
+            rem += d1;
+            if rem >= BASE {
+                break;
+            }
+        }
+        q
+    };
+
+    let q1 = estimate(n32, n1);
+    let n21 = (n32 << 32 | n1).wrapping_sub(q1.wrapping_mul(divisor));
+    let q0 = estimate(n21, n0);
+    let remainder = (n21 << 32 | n0).wrapping_sub(q0.wrapping_mul(divisor)) >> shift;
+
+    (q1 << 32 | q0, remainder)
+}
+
+impl Promotion for SplitI128 {
+    type Layout = i64;
+
+    #[inline]
+    fn as_layout(&self) -> Self::Layout {
+        self.lo as i64
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn as_positive_f64(&self) -> f64 {
+        debug_assert!(!self.is_negative());
+        let b2p64 = 18446744073709551616.;
+        self.hi as f64 * b2p64 + self.lo as f64
+    }
+
+    #[inline]
+    fn leading_zeros(&self) -> u32 {
+        if self.hi != 0 {
+            self.hi.leading_zeros()
+        } else {
+            64 + self.lo.leading_zeros()
+        }
+    }
+
+    #[inline]
+    fn checked_add(&self, rhs: Self) -> Option<Self> {
+        let sum = *self + rhs;
+        // Overflows only if both terms have the same sign, which differs from the sign of the sum.
+        let overflow =
+            self.is_negative() == rhs.is_negative() && sum.is_negative() != self.is_negative();
+        (!overflow).then_some(sum)
+    }
+
+    #[inline]
+    fn mul_l(&self, rhs: Self::Layout) -> Self {
+        let (hi, lo) = self.unsigned_abs();
+        let rhs_abs = rhs.unsigned_abs();
+        let (carry, lo) = mul_wide(lo, rhs_abs);
+        let hi = hi.wrapping_mul(rhs_abs).wrapping_add(carry);
+        Self::from_magnitude(self.is_negative() != (rhs < 0), (hi, lo))
+    }
+
+    #[inline]
+    fn checked_mul_l(&self, rhs: Self::Layout) -> Option<Self> {
+        let (hi, lo) = self.unsigned_abs();
+        let rhs_abs = rhs.unsigned_abs();
+        let (carry, lo) = mul_wide(lo, rhs_abs);
+        let hi = hi.checked_mul(rhs_abs)?.checked_add(carry)?;
+
+        let negative = self.is_negative() != (rhs < 0) && (hi, lo) != (0, 0);
+        Self::checked_from_magnitude(negative, (hi, lo))
+    }
+
+    #[inline]
+    fn div_l(&self, rhs: Self::Layout) -> Self {
+        self.div_rem_l(rhs).0
+    }
+
+    #[inline]
+    fn div_rem_l(&self, rhs: Self::Layout) -> (Self, Self::Layout) {
+        // Truncated division, like primitives do: the remainder has the sign of the dividend.
+        let (quotient, remainder) = self.div_rem_magnitude(rhs);
+        let quotient = Self::from_magnitude(self.is_negative() != (rhs < 0), quotient);
+        // `|remainder| < |rhs| <= 2^63`, thus it fits.
+        let remainder = if self.is_negative() {
+            (remainder as i64).wrapping_neg()
+        } else {
+            remainder as i64
+        };
+        (quotient, remainder)
+    }
+}
+
+impl Zero for SplitI128 {
+    const ZERO: Self = Self::from_i64(0);
+}
+
+impl Add for SplitI128 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let hi = self.hi.wrapping_add(rhs.hi).wrapping_add(carry as i64);
+        Self { hi, lo }
+    }
+}
+
+impl Sub for SplitI128 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let hi = self.hi.wrapping_sub(rhs.hi).wrapping_sub(borrow as i64);
+        Self { hi, lo }
+    }
+}
+
+impl Mul for SplitI128 {
+    type Output = Self;
+
+    /// Wrapping multiplication, the same for signed and unsigned numbers.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (carry, lo) = mul_wide(self.lo, rhs.lo);
+        let hi = carry
+            .wrapping_add((self.hi as u64).wrapping_mul(rhs.lo))
+            .wrapping_add(self.lo.wrapping_mul(rhs.hi as u64));
+        Self { hi: hi as i64, lo }
+    }
+}
+
+impl Div for SplitI128 {
+    type Output = Self;
+
+    /// Truncated division. Uses the bitwise long division unless `rhs` fits `i64`.
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        if let Ok(rhs) = i64::try_from(rhs) {
+            return self.div_l(rhs);
+        }
+
+        let (mut quotient, mut remainder): (Magnitude, Magnitude) = ((0, 0), (0, 0));
+        let (dividend, divisor) = (self.unsigned_abs(), rhs.unsigned_abs());
+
+        for bit in (0..128).rev() {
+            let next = if bit >= 64 {
+                (dividend.0 >> (bit - 64)) & 1
+            } else {
+                (dividend.1 >> bit) & 1
+            };
+            // The remainder is less than the divisor, which is less than `2^128`.
+            remainder = (
+                remainder.0 << 1 | remainder.1 >> 63,
+                remainder.1 << 1 | next,
+            );
+            quotient = (quotient.0 << 1 | quotient.1 >> 63, quotient.1 << 1);
+
+            if remainder >= divisor {
+                let (lo, borrow) = remainder.1.overflowing_sub(divisor.1);
+                remainder = (remainder.0 - divisor.0 - borrow as u64, lo);
+                quotient.1 |= 1;
+            }
+        }
+
+        Self::from_magnitude(self.is_negative() != rhs.is_negative(), quotient)
+    }
+}
+
+impl From<i64> for SplitI128 {
+    #[inline]
+    fn from(x: i64) -> Self {
+        Self::from_i64(x)
+    }
+}
+
+impl TryFrom<SplitI128> for i64 {
+    type Error = ConvertError;
+
+    #[inline]
+    fn try_from(x: SplitI128) -> Result<Self, Self::Error> {
+        let value = x.lo as i64;
+        match SplitI128::from_i64(value).cmp(&x) {
+            Ordering::Equal => Ok(value),
+            _ => Err(ConvertError::new(
+                ConvertErrorKind::IntegralOverflow,
+                "not in range",
+            )),
+        }
+    }
+}
+
+// The native `i128` is used only as a reference here.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(x: i128) -> SplitI128 {
+        SplitI128 {
+            hi: (x >> 64) as i64,
+            lo: x as u64,
+        }
+    }
+
+    fn native(x: SplitI128) -> i128 {
+        (i128::from(x.hi) << 64) | i128::from(x.lo)
+    }
+
+    const VALUES: [i128; 14] = [
+        0,
+        1,
+        -1,
+        7,
+        -7,
+        1_000_000_007,
+        -999_999_999_999,
+        i64::MAX as i128,
+        i64::MIN as i128,
+        u64::MAX as i128,
+        (i64::MAX as i128) * (i64::MAX as i128),
+        (i64::MIN as i128) * (i64::MAX as i128),
+        i128::MAX,
+        i128::MIN,
+    ];
+
+    const DIVISORS: [i64; 9] = [
+        1,
+        -1,
+        2,
+        -3,
+        10,
+        1_000_000_000,
+        u32::MAX as i64 + 1,
+        i64::MAX,
+        i64::MIN,
+    ];
+
+    #[test]
+    fn mul_wide_matches_native() {
+        for a in [
+            0,
+            1,
+            2,
+            u32::MAX as u64,
+            u32::MAX as u64 + 1,
+            u64::MAX - 1,
+            u64::MAX,
+        ] {
+            for b in [0, 1, 3, u32::MAX as u64, 1 << 63, u64::MAX] {
+                let product = u128::from(a) * u128::from(b);
+                assert_eq!(mul_wide(a, b), ((product >> 64) as u64, product as u64));
+            }
+        }
+    }
+
+    #[test]
+    fn div_wide_matches_native() {
+        for divisor in [
+            1,
+            3,
+            10,
+            u32::MAX as u64,
+            u32::MAX as u64 + 1,
+            1 << 63,
+            u64::MAX,
+        ] {
+            for hi in [0, 1, divisor / 2, divisor - 1] {
+                for lo in [0, 1, u32::MAX as u64, 1 << 63, u64::MAX] {
+                    if hi >= divisor {
+                        continue;
+                    }
+                    let dividend = (u128::from(hi) << 64) | u128::from(lo);
+                    let divisor_128 = u128::from(divisor);
+                    let expected = (
+                        (dividend / divisor_128) as u64,
+                        (dividend % divisor_128) as u64,
+                    );
+                    assert_eq!(
+                        div_wide(hi, lo, divisor),
+                        expected,
+                        "{}/{}",
+                        dividend,
+                        divisor
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn arithmetic_matches_native() {
+        for a in VALUES {
+            assert_eq!(native(split(a)), a);
+
+            for b in VALUES {
+                let (x, y) = (split(a), split(b));
+                assert_eq!(native(x + y), a.wrapping_add(b), "{} + {}", a, b);
+                assert_eq!(
+                    x.checked_add(y).map(native),
+                    a.checked_add(b),
+                    "{} + {}",
+                    a,
+                    b
+                );
+                assert_eq!(native(x - y), a.wrapping_sub(b), "{} - {}", a, b);
+                assert_eq!(native(x * y), a.wrapping_mul(b), "{} * {}", a, b);
+                assert_eq!(x.cmp(&y), a.cmp(&b), "{} <=> {}", a, b);
+
+                if b != 0 && !(a == i128::MIN && b == -1) {
+                    assert_eq!(native(x / y), a / b, "{} / {}", a, b);
+                }
+            }
+
+            for b in DIVISORS {
+                let x = split(a);
+                let product = a.checked_mul(i128::from(b));
+                assert_eq!(x.checked_mul_l(b).map(native), product, "{} * {}", a, b);
+                if let Some(product) = product {
+                    assert_eq!(native(x.mul_l(b)), product, "{} * {}", a, b);
+                }
+
+                if !(a == i128::MIN && b == -1) {
+                    let (div, rem) = x.div_rem_l(b);
+                    let b = i128::from(b);
+                    assert_eq!(
+                        (native(div), i128::from(rem)),
+                        (a / b, a % b),
+                        "{} / {}",
+                        a,
+                        b
+                    );
+                    assert_eq!(native(x.div_l(b as i64)), a / b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        for a in VALUES {
+            let expected = i64::try_from(a).ok();
+            assert_eq!(i64::try_from(split(a)).ok(), expected);
+            assert_eq!(split(a).leading_zeros(), a.leading_zeros());
+            if let Some(a) = expected {
+                assert_eq!(native(SplitI128::from(a)), i128::from(a));
+                assert_eq!(SplitI128::from(a).as_layout(), a);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn as_positive_f64() {
+        for a in VALUES.into_iter().filter(|a| *a >= 0) {
+            assert_eq!(split(a).as_positive_f64(), a as f64);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Contains helpers operating on slices of fixed-point numbers.
+
+use core::ops::Add;
+
+use crate::{
+    ops::{CheckedAdd, CheckedSub, One, RoundMode, RoundingDiv, Zero},
+    ArithmeticError, FixedPoint,
+};
+
+/// Scales non-negative weights in place so that they sum exactly to `ONE`.
+///
+/// Every weight is divided by the total using the provided [`RoundMode`]. Then the rounding dust
+/// (the difference between `ONE` and the sum of rounded weights, which is less than `EPSILON`
+/// times the number of weights) is distributed deterministically: the largest weights receive
+/// (or give) one `EPSILON` each, ties are broken by position in the slice.
+/// Zero weights always stay zero.
+///
+/// Returns:
+/// * `Err(DomainViolation)` if some weight is negative,
+/// * `Err(DivisionByZero)` if the slice is empty or all weights are zero,
+/// * `Err(Overflow)` if the total doesn't fit the layout.
+///
+/// The slice isn't changed on error.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, stats, typenum::U9, ops::RoundMode::*};
+///
+/// type Weight = FixedPoint<i64, U9>;
+///
+/// let mut weights: [Weight; 3] = ["1".parse()?, "1".parse()?, "1".parse()?];
+/// stats::normalize(&mut weights, Nearest)?;
+/// assert_eq!(weights, ["0.333333334".parse()?, "0.333333333".parse()?, "0.333333333".parse()?]);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+pub fn normalize<I, P>(
+    weights: &mut [FixedPoint<I, P>],
+    mode: RoundMode,
+) -> Result<(), ArithmeticError>
+where
+    I: Copy + One + Add<Output = I>,
+    FixedPoint<I, P>: Copy
+        + Ord
+        + Zero
+        + One
+        + CheckedAdd<Output = FixedPoint<I, P>, Error = ArithmeticError>
+        + CheckedSub<Output = FixedPoint<I, P>, Error = ArithmeticError>
+        + RoundingDiv<Output = FixedPoint<I, P>, Error = ArithmeticError>
+        + RoundingDiv<I, Output = FixedPoint<I, P>, Error = ArithmeticError>,
+{
+    let zero = FixedPoint::<I, P>::ZERO;
+    let epsilon = FixedPoint::<I, P>::from_bits(I::ONE);
+
+    let mut total = zero;
+    for &weight in weights.iter() {
+        if weight < zero {
+            return Err(ArithmeticError::DomainViolation);
+        }
+        total = total.cadd(weight)?;
+    }
+
+    if total == zero {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+
+    let normalize = |weight: FixedPoint<I, P>| weight.rdiv(total, mode);
+
+    let mut sum = zero;
+    for &weight in weights.iter() {
+        sum = sum.cadd(normalize(weight)?)?;
+    }
+
+    let (dust, delta) = if sum <= FixedPoint::ONE {
+        (FixedPoint::ONE.csub(sum)?, epsilon)
+    } else {
+        (sum.csub(FixedPoint::ONE)?, zero.csub(epsilon)?)
+    };
+
+    if dust == zero {
+        for weight in weights.iter_mut() {
+            *weight = normalize(*weight)?;
+        }
+        return Ok(());
+    }
+
+    // Weights are ordered by normalized value descending, then by position. The first `dust`
+    // nonzero ones are adjusted, each at most once, because the dust is less than their count.
+    //
+    // `normalize` is monotonic, so the last adjusted weight has the same normalized value as
+    // the `dust`-th largest weight, which is found by binary search over weights instead of
+    // selecting weights one by one.
+    //
+    // Counts are in terms of `EPSILON` to be compared with the dust.
+    let count_from = |threshold: FixedPoint<I, P>| {
+        weights
+            .iter()
+            .filter(|&&w| w != zero && w >= threshold)
+            .try_fold(zero, |count, _| count.cadd(epsilon))
+    };
+
+    let two = I::ONE + I::ONE;
+    let (mut low, mut high) = (epsilon, weights.iter().copied().max().unwrap_or(zero));
+    while low < high {
+        let middle = low.cadd(high.csub(low)?.rdiv(two, RoundMode::Ceil)?)?;
+        if count_from(middle)? >= dust {
+            low = middle;
+        } else {
+            high = middle.csub(epsilon)?;
+        }
+    }
+
+    let last = normalize(low)?;
+
+    // Ties with the last adjusted weight are adjusted in order of position while the dust remains.
+    let mut ties = dust;
+    for &weight in weights.iter() {
+        if weight != zero && normalize(weight)? > last {
+            ties = ties.csub(epsilon)?;
+        }
+    }
+
+    for weight in weights.iter_mut() {
+        let normalized = normalize(*weight)?;
+        let is_tie = *weight != zero && normalized == last && ties > zero;
+        if is_tie {
+            ties = ties.csub(epsilon)?;
+        }
+
+        *weight = if is_tie || (*weight != zero && normalized > last) {
+            normalized.cadd(delta)?
+        } else {
+            normalized
+        };
+    }
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
-use core::str::{self, FromStr};
+use core::{
+    num::{IntErrorKind, ParseIntError},
+    str::{self, FromStr},
+};
 
-use crate::{ConvertError, FixedPoint, Precision};
+use crate::{ConvertError, ConvertErrorKind, FixedPoint, Precision};
 
 #[allow(unreachable_pub)]
 pub trait Stringify {
@@ -33,21 +36,26 @@ macro_rules! impl_for {
             fn parse_str<const EXACT: bool>(str: &str) -> Result<Self, ConvertError> {
                 let str = str.trim();
 
+                if let Some(value) = Self::parse_integer(str.as_bytes()) {
+                    return Ok(value);
+                }
+
                 let (integral_str, mut fractional_str) = if let Some(parts) = str.split_once('.') {
                     parts
                 } else {
                     return str
                         .parse::<$layout>()
-                        .map_err(|_| ConvertError::new("can't parse integer"))?
+                        .map_err(|err| Self::parse_int_error(err, "can't parse integer"))?
                         .try_into();
                 };
 
                 let integral: $layout = integral_str
                     .parse()
-                    .map_err(|_| ConvertError::new("can't parse integral part"))?;
+                    .map_err(|err| Self::parse_int_error(err, "can't parse integral part"))?;
 
                 if !fractional_str.chars().all(|c| c.is_digit(10)) {
                     return Err(ConvertError::new(
+                        ConvertErrorKind::InvalidSyntax,
                         "can't parse fractional part: must contain digits only",
                     ));
                 }
@@ -57,7 +65,7 @@ macro_rules! impl_for {
 
                 if EXACT {
                     if fractional_str.len() > Self::PRECISION.unsigned_abs() as usize {
-                        return Err(ConvertError::new("requested precision is too high"));
+                        return Err(ConvertError::fractional_precision(Self::PRECISION as u32));
                     }
                 }
 
@@ -73,18 +81,21 @@ macro_rules! impl_for {
                 let exp = ten.pow(fractional_str.len() as u32);
 
                 if EXACT && exp > Self::COEF {
-                    return Err(ConvertError::new("requested precision is too high"));
+                    return Err(ConvertError::fractional_precision(Self::PRECISION as u32));
                 }
 
                 debug_assert!(exp <= Self::COEF);
 
-                let fractional: $layout = fractional_str
-                    .parse()
-                    .map_err(|_| ConvertError::new("can't parse fractional part"))?;
+                let fractional: $layout = fractional_str.parse().map_err(|_| {
+                    ConvertError::new(
+                        ConvertErrorKind::InvalidSyntax,
+                        "can't parse fractional part",
+                    )
+                })?;
 
                 let final_integral = integral
                     .checked_mul(Self::COEF)
-                    .ok_or(ConvertError::new("too big integral"))?;
+                    .ok_or_else(Self::integral_overflow)?;
 
                 let mut final_fractional = signum * Self::COEF / exp * fractional;
                 if let Some(round) = round {
@@ -95,7 +106,50 @@ macro_rules! impl_for {
                 final_integral
                     .checked_add(final_fractional)
                     .map(Self::from_bits)
-                    .ok_or_else(|| ConvertError::new("too big number"))
+                    .ok_or_else(Self::integral_overflow)
+            }
+
+            fn parse_int_error(err: ParseIntError, reason: &'static str) -> ConvertError {
+                match err.kind() {
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => {
+                        Self::integral_overflow()
+                    }
+                    _ => ConvertError::new(ConvertErrorKind::InvalidSyntax, reason),
+                }
+            }
+
+            /// A fast path for integer-only inputs (e.g. `"12345"` or `"-42"`), which are common
+            /// for quantities and prices in feeds. Returns `None` if the input isn't an integer or
+            /// doesn't fit the layout, the general path handles such inputs and reports errors.
+            #[inline]
+            fn parse_integer(bytes: &[u8]) -> Option<Self> {
+                let (negative, digits) = match bytes.split_first()? {
+                    (b'-', rest) => (true, rest),
+                    (b'+', rest) => (false, rest),
+                    _ => (false, bytes),
+                };
+
+                if digits.is_empty() {
+                    return None;
+                }
+
+                // Accumulate negative values separately to support `MIN`.
+                let mut integral: $layout = 0;
+                for &byte in digits {
+                    let digit = byte.wrapping_sub(b'0');
+                    if digit > 9 {
+                        return None;
+                    }
+
+                    integral = integral.checked_mul(10)?;
+                    integral = if negative {
+                        integral.checked_sub(digit as $layout)?
+                    } else {
+                        integral.checked_add(digit as $layout)?
+                    };
+                }
+
+                integral.checked_mul(Self::COEF).map(Self::from_bits)
             }
         }
 
@@ -108,15 +162,162 @@ macro_rules! impl_for {
                     let _ = buf.push('-');
                 }
 
-                let integral = (self.inner / Self::COEF).abs();
-                let fractional = (self.inner % Self::COEF).abs();
+                // `unsigned_abs()` doesn't overflow on `MIN`.
+                let integral = (self.inner / Self::COEF).unsigned_abs();
+                let fractional = (self.inner % Self::COEF).unsigned_abs();
 
                 let _ = buf.push_str(fmt.format(integral));
                 let _ = buf.push('.');
 
                 if fractional > 0 {
-                    let fractional_with_leading_one = fractional + Self::COEF;
-                    let s = &fmt.format(fractional_with_leading_one)[1..];
+                    // `fractional + COEF` can overflow the layout, so pad zeros manually.
+                    let s = fmt.format(fractional);
+                    for _ in s.len()..Self::PRECISION as usize {
+                        let _ = buf.push('0');
+                    }
+                    let _ = buf.push_str(s.trim_end_matches('0'));
+                } else {
+                    let _ = buf.push('0');
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "u16", feature = "u32", feature = "u64", feature = "u128"))]
+macro_rules! impl_for_unsigned {
+    ($layout:tt) => {
+        impl<P: Precision> FromStr for FixedPoint<$layout, P> {
+            type Err = ConvertError;
+
+            /// Parses a string slice into a fixed point.
+            /// If the value cannot be represented, it will be rounded to the nearest value.
+            ///
+            /// Use `from_str_exact` to parse without rounding.
+            fn from_str(str: &str) -> Result<Self, Self::Err> {
+                Self::parse_str::<false>(str)
+            }
+        }
+
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Parses a string slice into a fixed point.
+            /// If the value cannot be represented then this will return an error.
+            ///
+            /// Use the `FromStr` instance to parse with rounding.
+            pub fn from_str_exact(str: &str) -> Result<Self, ConvertError> {
+                Self::parse_str::<true>(str)
+            }
+
+            fn parse_str<const EXACT: bool>(str: &str) -> Result<Self, ConvertError> {
+                let str = str.trim();
+
+                // Negative numbers are rejected after parsing in order to accept `-0`.
+                let (negative, str) = match str.strip_prefix('-') {
+                    Some(rest) => (true, rest),
+                    None => (false, str.strip_prefix('+').unwrap_or(str)),
+                };
+
+                if str.starts_with(['+', '-']) {
+                    return Err(ConvertError::new(
+                        ConvertErrorKind::InvalidSyntax,
+                        "can't parse integer",
+                    ));
+                }
+
+                let value = Self::parse_unsigned::<EXACT>(str)?;
+
+                if negative && value.inner != 0 {
+                    return Err(Self::integral_overflow());
+                }
+
+                Ok(value)
+            }
+
+            fn parse_unsigned<const EXACT: bool>(str: &str) -> Result<Self, ConvertError> {
+                let (integral_str, mut fractional_str) = if let Some(parts) = str.split_once('.') {
+                    parts
+                } else {
+                    return str
+                        .parse::<$layout>()
+                        .map_err(|err| Self::parse_int_error(err, "can't parse integer"))?
+                        .try_into();
+                };
+
+                let integral: $layout = integral_str
+                    .parse()
+                    .map_err(|err| Self::parse_int_error(err, "can't parse integral part"))?;
+
+                if fractional_str.is_empty() || !fractional_str.chars().all(|c| c.is_digit(10)) {
+                    return Err(ConvertError::new(
+                        ConvertErrorKind::InvalidSyntax,
+                        "can't parse fractional part: must contain digits only",
+                    ));
+                }
+
+                let prec = Self::PRECISION as usize;
+
+                if EXACT && fractional_str.len() > prec {
+                    return Err(ConvertError::fractional_precision(Self::PRECISION as u32));
+                }
+
+                let round_up = if !EXACT && fractional_str.len() > prec {
+                    let extra = fractional_str.as_bytes()[prec];
+                    fractional_str = &fractional_str[..prec];
+                    extra >= b'5'
+                } else {
+                    false
+                };
+
+                let ten: $layout = 10;
+                let exp = ten.pow(fractional_str.len() as u32);
+
+                let fractional: $layout = if fractional_str.is_empty() {
+                    0
+                } else {
+                    fractional_str.parse().map_err(|_| {
+                        ConvertError::new(
+                            ConvertErrorKind::InvalidSyntax,
+                            "can't parse fractional part",
+                        )
+                    })?
+                };
+
+                let final_integral = integral
+                    .checked_mul(Self::COEF)
+                    .ok_or_else(Self::integral_overflow)?;
+
+                let final_fractional = Self::COEF / exp * fractional + round_up as $layout;
+
+                final_integral
+                    .checked_add(final_fractional)
+                    .map(Self::from_bits)
+                    .ok_or_else(Self::integral_overflow)
+            }
+
+            fn parse_int_error(err: ParseIntError, reason: &'static str) -> ConvertError {
+                match err.kind() {
+                    IntErrorKind::PosOverflow => Self::integral_overflow(),
+                    _ => ConvertError::new(ConvertErrorKind::InvalidSyntax, reason),
+                }
+            }
+        }
+
+        impl<P: Precision> Stringify for FixedPoint<$layout, P> {
+            fn stringify(&self, buf: &mut StrBuf) {
+                let mut fmt = itoa::Buffer::new();
+
+                let integral = self.inner / Self::COEF;
+                let fractional = self.inner % Self::COEF;
+
+                let _ = buf.push_str(fmt.format(integral));
+                let _ = buf.push('.');
+
+                if fractional > 0 {
+                    // `fractional + COEF` can overflow the layout, so pad zeros manually.
+                    let s = fmt.format(fractional);
+                    for _ in s.len()..Self::PRECISION as usize {
+                        let _ = buf.push('0');
+                    }
                     let _ = buf.push_str(s.trim_end_matches('0'));
                 } else {
                     let _ = buf.push('0');
@@ -129,7 +330,11 @@ macro_rules! impl_for {
 // Serialize as a string in case of human readable formats.
 // The maximum length can be calculated as `len(str(-2**bits)) + 1`,
 // where `1` is reserved for `.` after integral part.
-const MAX_LEN: usize = if cfg!(feature = "i128") { 41 } else { 21 };
+const MAX_LEN: usize = if cfg!(any(feature = "i128", feature = "u128")) {
+    41
+} else {
+    21
+};
 
 // TODO: try `staticvec` after stabilization.
 // Now it works faster than `arrayvec`.
@@ -186,3 +391,41 @@ impl_for!(i32);
 impl_for!(i64);
 #[cfg(feature = "i128")]
 impl_for!(i128);
+#[cfg(feature = "u16")]
+impl_for_unsigned!(u16);
+#[cfg(feature = "u32")]
+impl_for_unsigned!(u32);
+#[cfg(feature = "u64")]
+impl_for_unsigned!(u64);
+#[cfg(feature = "u128")]
+impl_for_unsigned!(u128);
+
+// `Display` writes to `dyn fmt::Write`, which `#[no_panic]` can't see through,
+// so the formatting itself is checked. See `tests/it/no_panic.rs` for other operations.
+#[cfg(all(test, feature = "i64", not(debug_assertions)))]
+mod tests {
+    use no_panic::no_panic;
+    use typenum::U9;
+
+    use super::*;
+    use crate::ops::Bounded;
+
+    type Amount = FixedPoint<i64, U9>;
+
+    #[no_panic]
+    fn stringify(value: Amount, buf: &mut StrBuf) {
+        value.stringify(buf);
+    }
+
+    #[test]
+    fn stringify_has_no_panics() {
+        for (value, expected) in [
+            (Amount::MIN, "-9223372036.854775808"),
+            (Amount::MAX, "9223372036.854775807"),
+        ] {
+            let mut buf = StrBuf::default();
+            stringify(value, &mut buf);
+            assert_eq!(buf.as_str(), expected);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Deterministic generation of fixed-point numbers for property tests and fuzzing.
+//!
+//! Unlike uniform sampling of raw bits, which almost never hits interesting values,
+//! [`Generator`] prefers edge cases: numbers around `0`, `±1`, `MIN` and `MAX`, powers of ten,
+//! integers and half-way points of rounding. The same seed always produces the same sequence
+//! on all platforms and with all features.
+//!
+//! ```
+//! # #[cfg(feature = "i64")]
+//! # fn main() {
+//! use fixnum::{FixedPoint, testgen::Generator, typenum::U9};
+//!
+//! type Amount = FixedPoint<i64, U9>;
+//!
+//! let mut gen = Generator::new(42);
+//! let values: Vec<Amount> = (0..100).map(|_| gen.generate()).collect();
+//!
+//! let mut same = Generator::new(42);
+//! assert!(values.iter().all(|&value| value == same.generate::<Amount>()));
+//! # }
+//! # #[cfg(not(feature = "i64"))]
+//! # fn main() {}
+//! ```
+
+use crate::{ops::One, FixedPoint, Precision};
+
+/// A seedable pseudo-random generator of edge-case-heavy values.
+///
+/// It's based on SplitMix64, which is fast and good enough for tests, but not cryptographically
+/// secure.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Creates a generator from the seed.
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next uniformly distributed number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns the next value of `T`.
+    pub fn generate<T: Generate>(&mut self) -> T {
+        T::generate(self)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        u128::from(self.next_u64()) << 64 | u128::from(self.next_u64())
+    }
+
+    /// Returns a number in `[0, n)`, the modulo bias is negligible for small `n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
+
+/// Types that can be produced by [`Generator`].
+pub trait Generate: Sized {
+    /// Generates a value using the generator.
+    fn generate(gen: &mut Generator) -> Self;
+}
+
+macro_rules! impl_generate {
+    ($($layout:ty),*) => {$(
+        impl<P: Precision> Generate for FixedPoint<$layout, P> {
+            fn generate(gen: &mut Generator) -> Self {
+                let ten: $layout = 10;
+                let coef = Self::ONE.into_bits();
+
+                // The number of powers of ten that fit the layout.
+                let mut powers = 0;
+                let mut power: $layout = 1;
+                while let Some(next) = power.checked_mul(ten) {
+                    power = next;
+                    powers += 1;
+                }
+                let power_of_ten = |k: u64| ten.pow(k as u32);
+
+                let offset = gen.below(17) as $layout;
+                let nudge = |value: $layout, gen: &mut Generator| match gen.below(3) {
+                    0 => value,
+                    1 => value.saturating_add(offset),
+                    _ => value.saturating_sub(offset),
+                };
+
+                let (inner, flip_sign) = match gen.below(8) {
+                    0 => (gen.next_u128() as $layout, false),
+                    1 => (offset, true),
+                    2 => (nudge(coef, gen), true),
+                    3 => (nudge(<$layout>::MAX, gen), false),
+                    4 => (nudge(<$layout>::MIN, gen), false),
+                    5 => (nudge(power_of_ten(gen.below(powers + 1)), gen), true),
+                    6 => {
+                        // `m * 10^k + 5 * 10^(k - 1)` is a tie for rounding to `k` digits,
+                        // ties between integers are the most common ones.
+                        let k = match P::U64 {
+                            0 => 1 + gen.below(powers),
+                            precision if gen.below(2) == 0 => precision,
+                            _ => 1 + gen.below(powers),
+                        };
+                        let half = 5 * power_of_ten(k - 1);
+                        let tie = (gen.below(10) as $layout)
+                            .checked_mul(power_of_ten(k))
+                            .and_then(|m| m.checked_add(half));
+                        (tie.unwrap_or(half), true)
+                    }
+                    _ => {
+                        let integer = (gen.below(1000) as $layout).checked_mul(coef);
+                        (integer.unwrap_or(coef), true)
+                    }
+                };
+
+                // It saturates to zero for unsigned layouts.
+                let zero: $layout = 0;
+                Self::from_bits(if flip_sign && gen.below(2) == 0 {
+                    zero.saturating_sub(inner)
+                } else {
+                    inner
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "i16")]
+impl_generate!(i16);
+#[cfg(feature = "i32")]
+impl_generate!(i32);
+#[cfg(feature = "i64")]
+impl_generate!(i64);
+#[cfg(feature = "i128")]
+impl_generate!(i128);
+#[cfg(feature = "u16")]
+impl_generate!(u16);
+#[cfg(feature = "u32")]
+impl_generate!(u32);
+#[cfg(feature = "u64")]
+impl_generate!(u64);
+#[cfg(feature = "u128")]
+impl_generate!(u128);
@@ -0,0 +1,1018 @@
+//! Transcendental functions.
+//!
+//! Arguments are reduced and series are summed with the internal precision depending on
+//! the layout (`4` for `i16`, `9` for `i32`, `18` for `i64` and `37` for `i128`),
+//! thus all digits beyond `PRECISION` are guard digits. The result is rounded once.
+//! `PRECISION` must not exceed the internal one, it is checked at compile time.
+//!
+//! Trigonometric functions (the `trig` feature) rotate a vector with CORDIC on the same
+//! internal precision, so they use only integer shifts and additions.
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "i128")]
+use crate::i256_polyfill::i256;
+use crate::{
+    ops::{CheckedAdd, One, RoundMode, RoundingDiv, RoundingMul, Zero},
+    ArithmeticError, FixedPoint, Precision, Result,
+};
+
+/// `ln(2) * 10^38`, rounded to nearest.
+const LN_2: u128 = 69_314_718_055_994_530_941_723_212_145_817_656_808;
+
+/// `ln(10) * 10^38`, rounded to nearest.
+const LN_10: u128 = 230_258_509_299_404_568_401_799_145_468_436_420_760;
+
+/// `pi / 2 * 10^38`, rounded to nearest.
+#[cfg(feature = "trig")]
+const FRAC_PI_2: u128 = 157_079_632_679_489_661_923_132_169_163_975_144_210;
+
+/// The inverse of the CORDIC gain `prod(sqrt(1 + 2^(-2i)))` multiplied by `10^38`,
+/// rounded to nearest.
+#[cfg(feature = "trig")]
+const CORDIC_K: u128 = 60_725_293_500_888_125_616_944_675_250_492_826_311;
+
+/// `atan(2^-i) * 10^38`, rounded to nearest.
+/// For bigger `i`, `atan(2^-i)` is `2^-i` with 38 decimal places.
+#[cfg(feature = "trig")]
+const ATAN: [u128; 42] = [
+    78_539_816_339_744_830_961_566_084_581_987_572_105,
+    46_364_760_900_080_611_621_425_623_146_121_440_203,
+    24_497_866_312_686_415_417_208_248_121_127_581_091,
+    12_435_499_454_676_143_503_135_484_916_387_102_557,
+    6_241_880_999_595_734_847_397_911_298_550_511_361,
+    3_123_983_343_026_827_625_371_174_489_249_097_703,
+    1_562_372_862_047_683_080_280_152_125_657_031_891,
+    781_234_106_010_111_129_646_339_184_219_928_162,
+    390_623_013_196_697_182_762_866_531_142_438_714,
+    195_312_251_647_881_868_512_148_262_507_671_393,
+    97_656_218_955_931_943_040_343_019_971_729_085,
+    48_828_121_119_489_827_546_923_962_564_484_867,
+    24_414_062_014_936_176_401_672_294_325_965_999,
+    12_207_031_189_367_020_423_905_864_611_795_630,
+    6_103_515_617_420_877_502_166_256_917_382_915,
+    3_051_757_811_552_609_686_182_595_343_853_602,
+    1_525_878_906_131_576_210_723_193_581_269_789,
+    762_939_453_110_197_026_338_848_234_010_509,
+    381_469_726_560_649_628_292_307_561_637_299,
+    190_734_863_281_018_703_536_536_930_591_724,
+    95_367_431_640_596_087_942_067_068_992_311,
+    47_683_715_820_308_885_992_758_382_144_925,
+    23_841_857_910_155_798_249_094_797_721_893,
+    11_920_928_955_078_068_531_136_849_713_792,
+    5_960_464_477_539_055_441_392_106_214_179,
+    2_980_232_238_769_530_367_674_013_276_771,
+    1_490_116_119_384_765_514_709_251_659_596,
+    745_058_059_692_382_798_713_656_457_450,
+    372_529_029_846_191_404_526_707_057_181,
+    186_264_514_923_095_702_909_588_382_148,
+    93_132_257_461_547_851_535_573_547_768,
+    46_566_128_730_773_925_777_884_193_471,
+    23_283_064_365_386_962_890_204_274_184,
+    11_641_532_182_693_481_445_259_909_273,
+    5_820_766_091_346_740_722_649_676_159,
+    2_910_383_045_673_370_361_327_303_270,
+    1_455_191_522_836_685_180_663_959_784,
+    727_595_761_418_342_590_332_018_410,
+    363_797_880_709_171_295_166_014_020,
+    181_898_940_354_585_647_583_007_612,
+    90_949_470_177_292_823_791_503_881,
+    45_474_735_088_646_411_895_751_950,
+];
+
+/// Returns `constant * 10^(precision - 38)` for a constant with 38 decimal places,
+/// rounded to nearest.
+const fn with_precision(constant: u128, precision: u32) -> u128 {
+    let divisor = 10u128.pow(38 - precision);
+    (constant + divisor / 2) / divisor
+}
+
+/// The range of an argument of `e^x`.
+enum ExpRange {
+    Overflow,
+    Underflow,
+    Normal,
+}
+
+macro_rules! impl_transcendental {
+    (
+        $(#[$attr:meta])?
+        inner = $layout:tt;
+        promoted_to = $promotion:tt;
+        internal = $internal:ty;
+    ) => {const _: () = {
+        use $crate::_priv::Promotion as _;
+
+        /// A number with the maximal precision, used for intermediate results.
+        type Internal = FixedPoint<$layout, $internal>;
+
+        const ZERO: $layout = 0;
+        const INTERNAL_COEF: $layout = Internal::ONE.inner;
+        const INTERNAL_LN_2: $layout = with_precision(LN_2, Internal::PRECISION as u32) as $layout;
+        /// Doesn't fit `i32`, so it's split into `INTERNAL_LN_10_HIGH * 10 + INTERNAL_LN_10_LOW`.
+        const INTERNAL_LN_10_HIGH: $layout =
+            (with_precision(LN_10, Internal::PRECISION as u32) / 10) as $layout;
+        const INTERNAL_LN_10_LOW: $layout =
+            (with_precision(LN_10, Internal::PRECISION as u32) % 10) as $layout;
+
+        /// Calculates `ln(x) * 10^INTERNAL_PRECISION` for positive `value = x * 10^INTERNAL_PRECISION`.
+        fn ln_internal(value: $promotion) -> Result<$promotion> {
+            debug_assert!(value > $promotion::from(ZERO));
+
+            // Find `k` and `m` such that `x = m * 2^k`, where `m` is in `[0.75, 1.5)`.
+            // At first, `m` is in `[0.25, 1)`, then it's doubled (exactly) if needed.
+            let internal_coef = $promotion::from(INTERNAL_COEF);
+            let mut k = internal_coef.leading_zeros() as i32 - value.leading_zeros() as i32 + 1;
+            let mut m = if k >= 0 {
+                (value >> k as u32).as_layout()
+            } else {
+                (value << (-k) as u32).as_layout()
+            };
+
+            while m < INTERNAL_COEF / 4 * 3 {
+                m *= 2;
+                k -= 1;
+            }
+
+            // `ln(m) = 2 * atanh(z)`, where `z = (m - 1) / (m + 1)` is in `(-0.15, 0.2)`.
+            let z = ($promotion::from(m - INTERNAL_COEF).mul_l(INTERNAL_COEF)
+                / ($promotion::from(m) + internal_coef))
+                .as_layout();
+            let z = Internal::from_bits(z);
+            let z2 = z.rmul(z, RoundMode::Nearest)?;
+
+            // `atanh(z) = z + z^3 / 3 + z^5 / 5 + ...`
+            let mut power = z;
+            let mut sum = z;
+            for n in (3..).step_by(2) {
+                power = power.rmul(z2, RoundMode::Nearest)?;
+                let term = power.rdiv(n as $layout, RoundMode::Nearest)?;
+                if term == Internal::ZERO {
+                    break;
+                }
+                sum = sum.cadd(term)?;
+            }
+
+            let ln_m = $promotion::from(sum.inner).mul_l(2);
+            let ln_2k = $promotion::from(INTERNAL_LN_2).mul_l(k as $layout);
+            Ok(ln_2k + ln_m)
+        }
+
+        /// Calculates `e^x * 10^INTERNAL_PRECISION` for `value = x * 10^INTERNAL_PRECISION`.
+        /// The caller must ensure `x` is in `(-BITS, BITS)`, bigger values return `Err(Overflow)`.
+        fn exp_internal(value: $promotion) -> Result<$promotion> {
+            const BITS: $layout = $layout::BITS as $layout;
+
+            // `x = k * ln(2) + r`, where `|r| <= ln(2) / 2`.
+            let (k, r) = value.div_rem_l(INTERNAL_LN_2);
+            let (mut k, mut r) = (k.as_layout(), r);
+            if r > INTERNAL_LN_2 / 2 {
+                k += 1;
+                r -= INTERNAL_LN_2;
+            } else if r < -INTERNAL_LN_2 / 2 {
+                k -= 1;
+                r += INTERNAL_LN_2;
+            }
+
+            // `e^x = 2^k * e^r` cannot fit the layout.
+            if k >= BITS - 1 {
+                return Err(ArithmeticError::Overflow);
+            }
+
+            // `e^r - 1 = r + r^2 / 2! + r^3 / 3! + ...`
+            let r = Internal::from_bits(r);
+            let mut term = r;
+            let mut sum = r;
+            for n in 2.. {
+                term = term.rmul(r, RoundMode::Nearest)?.rdiv(n as $layout, RoundMode::Nearest)?;
+                if term == Internal::ZERO {
+                    break;
+                }
+                sum = sum.cadd(term)?;
+            }
+
+            let exp_r = $promotion::from(sum.inner) + $promotion::from(INTERNAL_COEF);
+            Ok(if k >= 0 {
+                exp_r << k as u32
+            } else {
+                exp_r >> (-k) as u32
+            })
+        }
+
+        /// Enough iterations to make the last angle `atan(2^-i)` less than `10^-INTERNAL_PRECISION`.
+        #[cfg(feature = "trig")]
+        const CORDIC_ITERATIONS: usize = Internal::PRECISION as usize * 10 / 3 + 2;
+        #[cfg(feature = "trig")]
+        const INTERNAL_FRAC_PI_2: $layout = with_precision(FRAC_PI_2, Internal::PRECISION as u32) as $layout;
+        #[cfg(feature = "trig")]
+        const INTERNAL_CORDIC_K: $layout = with_precision(CORDIC_K, Internal::PRECISION as u32) as $layout;
+        #[cfg(feature = "trig")]
+        const INTERNAL_ATAN: [$layout; CORDIC_ITERATIONS] = {
+            let mut table = [ZERO; CORDIC_ITERATIONS];
+            let mut i = 0;
+            while i < CORDIC_ITERATIONS {
+                table[i] = if i < ATAN.len() {
+                    with_precision(ATAN[i], Internal::PRECISION as u32) as $layout
+                } else {
+                    (INTERNAL_COEF + (1 << (i - 1))) >> i
+                };
+                i += 1;
+            }
+            table
+        };
+
+        /// Calculates `(sin(x), cos(x)) * 10^INTERNAL_PRECISION`
+        /// for `value = x * 10^INTERNAL_PRECISION`.
+        #[cfg(feature = "trig")]
+        fn sin_cos_internal(value: $promotion) -> ($layout, $layout) {
+            // `x = k * pi / 2 + r`, where `|r| <= pi / 4`.
+            let (k, mut r) = value.div_rem_l(INTERNAL_FRAC_PI_2);
+            let mut k = k.as_layout();
+            if r > INTERNAL_FRAC_PI_2 / 2 {
+                k += 1;
+                r -= INTERNAL_FRAC_PI_2;
+            } else if r < -INTERNAL_FRAC_PI_2 / 2 {
+                k -= 1;
+                r += INTERNAL_FRAC_PI_2;
+            }
+
+            // Rotate `(K, 0)` by `r` using only shifts, `K` compensates the gain of rotations.
+            let (mut x, mut y, mut z) = (INTERNAL_CORDIC_K, ZERO, r);
+            for (i, &atan) in INTERNAL_ATAN.iter().enumerate() {
+                let (dx, dy) = (y >> i, x >> i);
+                if z >= 0 {
+                    x -= dx;
+                    y += dy;
+                    z -= atan;
+                } else {
+                    x += dx;
+                    y -= dy;
+                    z += atan;
+                }
+            }
+
+            // `k mod 4` is the quadrant, `&` works for negative `k` as well.
+            match k & 3 {
+                0 => (y, x),
+                1 => (x, -y),
+                2 => (-y, -x),
+                _ => (-x, y),
+            }
+        }
+
+        /// Calculates `atan2(y, x) * 10^INTERNAL_PRECISION`, where `y` and `x` have any common
+        /// scale and aren't both zero.
+        #[cfg(feature = "trig")]
+        fn atan2_internal(y: $layout, x: $layout) -> $promotion {
+            debug_assert!(y != 0 || x != 0);
+
+            let (y_negative, x_negative) = (y < 0, x < 0);
+            let (y, x) = ($promotion::from(y), $promotion::from(x));
+            let mut y_abs = if y_negative { -y } else { y };
+            let mut x_abs = if x_negative { -x } else { x };
+
+            // Only the ratio matters, so scale both to `(INTERNAL_COEF / 4, INTERNAL_COEF / 2]`
+            // leaving room for the gain of rotations.
+            let limit = $promotion::from(INTERNAL_COEF / 2);
+            let max = if y_abs > x_abs { y_abs } else { x_abs };
+            let shift = max.leading_zeros() as i32 - limit.leading_zeros() as i32;
+            (y_abs, x_abs) = if shift >= 0 {
+                (y_abs << shift as u32, x_abs << shift as u32)
+            } else {
+                (y_abs >> (-shift) as u32, x_abs >> (-shift) as u32)
+            };
+            if y_abs > limit || x_abs > limit {
+                (y_abs, x_abs) = (y_abs >> 1, x_abs >> 1);
+            }
+
+            // Rotate `(x, y)` to the `x` axis summing angles of rotations.
+            let (mut x, mut y, mut z) = (x_abs.as_layout(), y_abs.as_layout(), ZERO);
+            for (i, &atan) in INTERNAL_ATAN.iter().enumerate() {
+                let (dx, dy) = (y >> i, x >> i);
+                if y > 0 {
+                    x += dx;
+                    y -= dy;
+                    z += atan;
+                } else {
+                    x -= dx;
+                    y += dy;
+                    z -= atan;
+                }
+            }
+
+            // `z` is in `[0, pi / 2]`, restore the quadrant. `pi` doesn't fit `i32`.
+            let angle = $promotion::from(z);
+            let angle = if x_negative {
+                $promotion::from(INTERNAL_FRAC_PI_2).mul_l(2) - angle
+            } else {
+                angle
+            };
+            if y_negative {
+                -angle
+            } else {
+                angle
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// `10 ^ (INTERNAL_PRECISION - PRECISION)`, the coefficient of guard digits.
+            const GUARD_COEF: $layout = {
+                assert!(
+                    P::I32 <= Internal::PRECISION,
+                    concat!(
+                        "precision is too large for transcendental functions of `",
+                        stringify!($layout),
+                        "`, the maximum is the internal precision",
+                    ),
+                );
+                INTERNAL_COEF / FixedPoint::<$layout, P>::COEF
+            };
+
+            /// Calculates `ln(1 + self)`, accurate for tiny values as well.
+            /// Returns `Err(DomainViolation)` if `self <= -1`.
+            ///
+            /// The error doesn't exceed `1` ULP if there are at least two guard digits,
+            /// i.e. `PRECISION` is less than the internal one (`18` for `i64`, `37` for `i128`)
+            /// by two.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let rate: Amount = "0.000000001".parse()?;
+            /// assert_eq!(rate.rlog1p(Floor)?, "0".parse()?);
+            /// assert_eq!(rate.rlog1p(Nearest)?, rate);
+            /// let one: Amount = "1".parse()?;
+            /// assert_eq!(one.rlog1p(Nearest)?, "0.693147181".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            ///
+            /// `PRECISION` greater than the internal one is a compile error for all transcendental
+            /// functions, e.g. `FixedPoint<i128, U38>`, because the internal precision of `i128` is `37`:
+            ///
+            /// ```compile_fail
+            /// # #[cfg(feature = "i128")]
+            /// # fn main() {
+            /// use fixnum::{FixedPoint, typenum::U38, ops::{One, RoundMode::*}};
+            ///
+            /// let _ = FixedPoint::<i128, U38>::ONE.rln(Nearest);
+            /// # }
+            /// # #[cfg(not(feature = "i128"))]
+            /// # compile_error!("requires the `i128` feature");
+            /// ```
+            pub fn rlog1p(self, mode: RoundMode) -> Result<Self> {
+                if self.inner <= -Self::COEF {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                // `(1 + self) * 10^INTERNAL_PRECISION`.
+                let value = ($promotion::from(self.inner) + $promotion::from(Self::COEF))
+                    .mul_l(Self::GUARD_COEF);
+                Self::round_internal(ln_internal(value)?, mode)
+            }
+
+            /// Calculates the natural logarithm, e.g. a log-return `ln(price / prev_price)`.
+            /// Returns `Err(DomainViolation)` if `self <= 0`.
+            ///
+            /// The error doesn't exceed `1` ULP if there are at least two guard digits,
+            /// i.e. `PRECISION` is less than the internal one (`18` for `i64`, `37` for `i128`)
+            /// by two. `ln(1)` is exactly zero. Use [`rlog1p`][Self::rlog1p] for values close
+            /// to `1` to avoid rounding `1 + x`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let ratio: Amount = "1.05".parse()?;
+            /// assert_eq!(ratio.rln(Nearest)?, "0.048790164".parse()?);
+            /// assert_eq!(ratio.rln(Nearest)?.rexp(Nearest)?, ratio);
+            /// let zero: Amount = "0".parse()?;
+            /// assert_eq!(zero.rln(Nearest), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rln(self, mode: RoundMode) -> Result<Self> {
+                if self.inner <= 0 {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                let value = $promotion::from(self.inner).mul_l(Self::GUARD_COEF);
+                Self::round_internal(ln_internal(value)?, mode)
+            }
+
+            /// Calculates the binary logarithm. Returns `Err(DomainViolation)` if `self <= 0`.
+            ///
+            /// Powers of two, including negative ones like `0.25`, give exact results.
+            /// Otherwise, the error doesn't exceed `1` ULP under the same conditions as
+            /// for [`rln`][Self::rln].
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let x: Amount = "0.25".parse()?;
+            /// assert_eq!(x.rlog2(Floor)?, "-2".parse()?);
+            /// let x: Amount = "3".parse()?;
+            /// assert_eq!(x.rlog2(Nearest)?, "1.584962501".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rlog2(self, mode: RoundMode) -> Result<Self> {
+                self.rlog(2, $promotion::from(INTERNAL_LN_2), mode)
+            }
+
+            /// Calculates the decimal logarithm. Returns `Err(DomainViolation)` if `self <= 0`.
+            ///
+            /// Powers of ten, including negative ones like `0.01`, give exact results.
+            /// Otherwise, the error doesn't exceed `1` ULP under the same conditions as
+            /// for [`rln`][Self::rln].
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let x: Amount = "1000".parse()?;
+            /// assert_eq!(x.rlog10(Ceil)?, "3".parse()?);
+            /// let x: Amount = "2".parse()?;
+            /// assert_eq!(x.rlog10(Nearest)?, "0.301029996".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rlog10(self, mode: RoundMode) -> Result<Self> {
+                let ln_10 = $promotion::from(INTERNAL_LN_10_HIGH).mul_l(10)
+                    + $promotion::from(INTERNAL_LN_10_LOW);
+                self.rlog(10, ln_10, mode)
+            }
+
+            /// Calculates the logarithm to `base` as `ln(self) / ln(base)`,
+            /// where `ln_base` is `ln(base) * 10^INTERNAL_PRECISION`.
+            fn rlog(self, base: $layout, ln_base: $promotion, mode: RoundMode) -> Result<Self> {
+                if self.inner <= 0 {
+                    return Err(ArithmeticError::DomainViolation);
+                }
+
+                if let Some(exponent) = self.exact_log(base) {
+                    return exponent
+                        .checked_mul(Self::COEF)
+                        .map(Self::from_bits)
+                        .ok_or(ArithmeticError::Overflow);
+                }
+
+                let value = $promotion::from(self.inner).mul_l(Self::GUARD_COEF);
+                let ln = ln_internal(value)?;
+
+                // `ln * INTERNAL_COEF` can overflow the promoted type, so divide in two steps.
+                let integral = ln / ln_base;
+                let rest = ln - integral * ln_base;
+                let fractional = rest.mul_l(INTERNAL_COEF) / ln_base;
+                Self::round_internal(integral.mul_l(INTERNAL_COEF) + fractional, mode)
+            }
+
+            /// Returns `k` if `self` is exactly `base ^ k`.
+            fn exact_log(self, base: $layout) -> Option<$layout> {
+                let (mut power, sign) = if self.inner % Self::COEF == 0 {
+                    (self.inner / Self::COEF, 1)
+                } else if Self::COEF % self.inner == 0 {
+                    (Self::COEF / self.inner, -1)
+                } else {
+                    return None;
+                };
+
+                let mut exponent = 0;
+                while power % base == 0 {
+                    power /= base;
+                    exponent += 1;
+                }
+
+                (power == 1).then(|| sign * exponent)
+            }
+
+            /// Calculates `e^self - 1`, accurate for tiny values as well.
+            /// Returns `Err(Overflow)` if the result doesn't fit the layout.
+            ///
+            /// The error doesn't exceed `1` ULP for `|self| <= 1` if there are at least
+            /// two guard digits, i.e. `PRECISION` is less than the internal one (`18` for `i64`,
+            /// `37` for `i128`) by two. For bigger values, the relative error is less than
+            /// `10 ^ -(INTERNAL_PRECISION - 2)`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let rate: Amount = "0.000000001".parse()?;
+            /// assert_eq!(rate.rexpm1(Ceil)?, "0.000000002".parse()?);
+            /// assert_eq!(rate.rexpm1(Nearest)?, rate);
+            /// let one: Amount = "1".parse()?;
+            /// assert_eq!(one.rexpm1(Floor)?, "1.718281828".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rexpm1(self, mode: RoundMode) -> Result<Self> {
+                let value = $promotion::from(self.inner).mul_l(Self::GUARD_COEF);
+
+                match Self::exp_range(value) {
+                    ExpRange::Overflow => Err(ArithmeticError::Overflow),
+                    // The result is in `(-1, -1 + EPSILON / 10)`.
+                    ExpRange::Underflow => Ok(Self::from_bits(
+                        if mode.is_away_from_zero(true, Ordering::Greater, true) {
+                            -Self::COEF
+                        } else {
+                            -Self::COEF + 1
+                        },
+                    )),
+                    ExpRange::Normal => {
+                        let exp = exp_internal(value)?;
+                        Self::round_internal(exp - $promotion::from(INTERNAL_COEF), mode)
+                    }
+                }
+            }
+
+            /// Calculates `e^self`, e.g. a discount factor `e^(-rate * time)`.
+            /// Returns `Err(Overflow)` if the result doesn't fit the layout.
+            ///
+            /// The error doesn't exceed `1` ULP for `self <= 1` if there are at least two guard
+            /// digits, i.e. `PRECISION` is less than the internal one (`18` for `i64`, `37` for
+            /// `i128`) by two. For bigger values, the relative error is less than
+            /// `10 ^ -(INTERNAL_PRECISION - 2)`. Use [`rexpm1`][Self::rexpm1] for tiny values
+            /// to get `e^self - 1` without cancellation.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let one: Amount = "1".parse()?;
+            /// assert_eq!(one.rexp(Floor)?, "2.718281828".parse()?);
+            /// let exponent: Amount = "-0.05".parse()?;
+            /// assert_eq!(exponent.rexp(Nearest)?, "0.951229425".parse()?);
+            /// let exponent: Amount = "-30".parse()?;
+            /// assert_eq!(exponent.rexp(Nearest)?, "0".parse()?);
+            /// assert_eq!(exponent.rexp(Ceil)?, "0.000000001".parse()?);
+            /// let exponent: Amount = "30".parse()?;
+            /// assert_eq!(exponent.rexp(Nearest), Err(ArithmeticError::Overflow));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rexp(self, mode: RoundMode) -> Result<Self> {
+                let value = $promotion::from(self.inner).mul_l(Self::GUARD_COEF);
+
+                match Self::exp_range(value) {
+                    ExpRange::Overflow => Err(ArithmeticError::Overflow),
+                    // The result is in `(0, EPSILON / 10)`.
+                    ExpRange::Underflow => Ok(Self::from_bits(
+                        mode.is_away_from_zero(false, Ordering::Less, false) as $layout,
+                    )),
+                    ExpRange::Normal => Self::round_internal(exp_internal(value)?, mode),
+                }
+            }
+
+            /// Raises `self` to the power of `exponent` as `e^(exponent * ln(self))`.
+            ///
+            /// Returns
+            /// * `Err(DomainViolation)` for a negative base with a non-integer exponent,
+            /// * `Err(DivisionByZero)` for a zero base with a negative exponent,
+            /// * `Err(Overflow)` if the result doesn't fit the layout.
+            ///
+            /// `0^0` is `1`. Intermediate results have guard digits (see [`rexpm1`][Self::rexpm1]),
+            /// so the relative error is less than `(1 + |exponent|) * 10 ^ -(INTERNAL_PRECISION - 2)`.
+            /// For small non-negative integer exponents [`rpowi`][Self::rpowi] is usually both faster
+            /// and more precise.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Amount = FixedPoint<i64, U9>;
+            ///
+            /// let vol: Amount = "0.04".parse()?;
+            /// assert_eq!(vol.rpow("0.5".parse()?, Nearest)?, "0.2".parse()?);
+            /// assert_eq!(vol.rpow("1.5".parse()?, Nearest)?, "0.008".parse()?);
+            ///
+            /// let base: Amount = "-2".parse()?;
+            /// assert_eq!(base.rpow("3".parse()?, Nearest)?, "-8".parse()?);
+            /// assert_eq!(base.rpow("0.5".parse()?, Nearest), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rpow(self, exponent: Self, mode: RoundMode) -> Result<Self> {
+                if exponent.inner == 0 {
+                    return Ok(Self::ONE);
+                }
+
+                if self.inner == 0 {
+                    return if exponent.inner > 0 {
+                        Ok(Self::ZERO)
+                    } else {
+                        Err(ArithmeticError::DivisionByZero)
+                    };
+                }
+
+                let negative = if self.inner > 0 {
+                    false
+                } else if exponent.inner % Self::COEF == 0 {
+                    (exponent.inner / Self::COEF) % 2 != 0
+                } else {
+                    return Err(ArithmeticError::DomainViolation);
+                };
+
+                // `|self| * 10^INTERNAL_PRECISION`, `MIN` is handled by the promotion.
+                let base = $promotion::from(self.inner).mul_l(Self::GUARD_COEF);
+                let base = if self.inner > 0 { base } else { -base };
+
+                // `exponent * ln|self| * 10^INTERNAL_PRECISION`
+                let ln = ln_internal(base)?;
+                let value = match ln.checked_mul_l(exponent.inner) {
+                    Some(value) => value.div_l(Self::COEF),
+                    // Too big power, thus the result is either too big or too small.
+                    None if (ln < $promotion::from(ZERO)) == (exponent.inner < 0) => {
+                        return Err(ArithmeticError::Overflow)
+                    }
+                    None => $promotion::from(INTERNAL_COEF).mul_l($layout::MIN),
+                };
+
+                let result = match Self::exp_range(value) {
+                    ExpRange::Overflow => return Err(ArithmeticError::Overflow),
+                    // The result is less than `EPSILON / 10` by magnitude.
+                    ExpRange::Underflow => {
+                        let inner = match mode.is_away_from_zero(negative, Ordering::Less, false) {
+                            true if negative => -1,
+                            true => 1,
+                            false => 0,
+                        };
+                        return Ok(Self::from_bits(inner));
+                    }
+                    ExpRange::Normal => exp_internal(value)?,
+                };
+
+                let result = if negative { -result } else { result };
+                Self::round_internal(result, mode)
+            }
+
+            /// Calculates the logistic function `1 / (1 + e^-self)`, e.g. an activation
+            /// of a neural network. The result is in `[0, 1]`, so it never fails.
+            ///
+            /// The error is less than `1.01` ULP if there are at least two guard digits,
+            /// i.e. `PRECISION` is less than the internal one (`18` for `i64`, `37` for `i128`)
+            /// by two, and within a few ULP otherwise.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Activation = FixedPoint<i64, U9>;
+            ///
+            /// let x: Activation = "0".parse()?;
+            /// assert_eq!(x.rsigmoid(Nearest)?, "0.5".parse()?);
+            /// let x: Activation = "2".parse()?;
+            /// assert_eq!(x.rsigmoid(Nearest)?, "0.880797078".parse()?);
+            /// let x: Activation = "-2".parse()?;
+            /// assert_eq!(x.rsigmoid(Nearest)?, "0.119202922".parse()?);
+            /// let x: Activation = "100".parse()?;
+            /// assert_eq!(x.rsigmoid(Nearest)?, "1".parse()?);
+            /// assert_eq!(x.rsigmoid(Floor)?, "0.999999999".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rsigmoid(self, mode: RoundMode) -> Result<Self> {
+                let negative = self.inner < 0;
+                let value = $promotion::from(self.inner).mul_l(Self::GUARD_COEF);
+
+                let Some(t) = Self::exp_neg_abs(value)? else {
+                    let inner = if negative {
+                        // The result is in `(0, EPSILON / 10)`.
+                        mode.is_away_from_zero(false, Ordering::Less, false) as $layout
+                    } else if mode.is_away_from_zero(false, Ordering::Greater, false) {
+                        // The result is in `(1 - EPSILON / 10, 1)`.
+                        Self::COEF
+                    } else {
+                        Self::COEF - 1
+                    };
+                    return Ok(Self::from_bits(inner));
+                };
+
+                // `1 / (1 + t)` for `x >= 0` and `t / (1 + t)` otherwise, where `t = e^-|x|`.
+                let numerator = if negative { t } else { INTERNAL_COEF };
+                let sigmoid = $promotion::from(numerator)
+                    .mul_l(INTERNAL_COEF)
+                    .div_l(INTERNAL_COEF + t);
+                Self::round_internal(sigmoid, mode)
+            }
+
+            /// Calculates the hyperbolic tangent, e.g. an activation of a neural network.
+            /// The result is in `[-1, 1]`, so it never fails.
+            ///
+            /// The error is less than `1.01` ULP if there are at least two guard digits,
+            /// i.e. `PRECISION` is less than the internal one (`18` for `i64`, `37` for `i128`)
+            /// by two, and within a few ULP otherwise.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Activation = FixedPoint<i64, U9>;
+            ///
+            /// let x: Activation = "0.5".parse()?;
+            /// assert_eq!(x.rtanh(Nearest)?, "0.462117157".parse()?);
+            /// let x: Activation = "-0.5".parse()?;
+            /// assert_eq!(x.rtanh(Nearest)?, "-0.462117157".parse()?);
+            /// let x: Activation = "100".parse()?;
+            /// assert_eq!(x.rtanh(Nearest)?, "1".parse()?);
+            /// assert_eq!(x.rtanh(Floor)?, "0.999999999".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            pub fn rtanh(self, mode: RoundMode) -> Result<Self> {
+                let negative = self.inner < 0;
+                let value = $promotion::from(self.inner).mul_l(Self::GUARD_COEF).mul_l(2);
+
+                let Some(t) = Self::exp_neg_abs(value)? else {
+                    // The result is in `(1 - EPSILON / 5, 1)` by magnitude.
+                    let inner = if mode.is_away_from_zero(negative, Ordering::Greater, false) {
+                        Self::COEF
+                    } else {
+                        Self::COEF - 1
+                    };
+                    return Ok(Self::from_bits(if negative { -inner } else { inner }));
+                };
+
+                // `tanh|x| = (1 - t) / (1 + t)`, where `t = e^-2|x|`.
+                let tanh = $promotion::from(INTERNAL_COEF - t)
+                    .mul_l(INTERNAL_COEF)
+                    .div_l(INTERNAL_COEF + t);
+                Self::round_internal(if negative { -tanh } else { tanh }, mode)
+            }
+
+            /// Calculates the sine of an angle in radians with CORDIC, using only integer
+            /// shifts and additions, so results are the same on every platform.
+            ///
+            /// The error doesn't exceed `1` ULP if there are at least three guard digits,
+            /// i.e. `PRECISION` is less than the internal one (`18` for `i64`, `37` for `i128`)
+            /// by three. The reduction of the argument modulo `pi / 2` adds an absolute error
+            /// of about `|self| * 10 ^ -INTERNAL_PRECISION`, which matters for huge angles only.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let angle: Angle = "0.5".parse()?;
+            /// assert_eq!(angle.rsin(Nearest)?, "0.479425539".parse()?);
+            /// let angle: Angle = "-3.141592654".parse()?;
+            /// assert_eq!(angle.rsin(Nearest)?, "0".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[cfg(feature = "trig")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "trig")))]
+            pub fn rsin(self, mode: RoundMode) -> Result<Self> {
+                if self.inner == 0 {
+                    return Ok(Self::ZERO);
+                }
+
+                let (sin, _) = sin_cos_internal($promotion::from(self.inner).mul_l(Self::GUARD_COEF));
+                Self::round_internal($promotion::from(sin), mode)
+            }
+
+            /// Calculates the cosine of an angle in radians with CORDIC.
+            ///
+            /// The error doesn't exceed `1` ULP under the same conditions as
+            /// for [`rsin`][Self::rsin].
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let angle: Angle = "0.5".parse()?;
+            /// assert_eq!(angle.rcos(Nearest)?, "0.877582562".parse()?);
+            /// let angle: Angle = "0".parse()?;
+            /// assert_eq!(angle.rcos(Floor)?, "1".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[cfg(feature = "trig")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "trig")))]
+            pub fn rcos(self, mode: RoundMode) -> Result<Self> {
+                if self.inner == 0 {
+                    return Ok(Self::ONE);
+                }
+
+                let (_, cos) = sin_cos_internal($promotion::from(self.inner).mul_l(Self::GUARD_COEF));
+                Self::round_internal($promotion::from(cos), mode)
+            }
+
+            /// Calculates the tangent of an angle in radians as `sin / cos` with CORDIC.
+            /// Returns `Err(Overflow)` if the result doesn't fit the layout.
+            ///
+            /// The error doesn't exceed `1` ULP under the same conditions as
+            /// for [`rsin`][Self::rsin] unless the angle is close to a pole: the relative error
+            /// is about `10 ^ -INTERNAL_PRECISION / |cos(self)|`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let angle: Angle = "0.5".parse()?;
+            /// assert_eq!(angle.rtan(Nearest)?, "0.54630249".parse()?);
+            /// let angle: Angle = "-1".parse()?;
+            /// assert_eq!(angle.rtan(Nearest)?, "-1.557407725".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[cfg(feature = "trig")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "trig")))]
+            pub fn rtan(self, mode: RoundMode) -> Result<Self> {
+                if self.inner == 0 {
+                    return Ok(Self::ZERO);
+                }
+
+                let (sin, cos) = sin_cos_internal($promotion::from(self.inner).mul_l(Self::GUARD_COEF));
+                if cos == 0 {
+                    return Err(ArithmeticError::Overflow);
+                }
+
+                let tan = $promotion::from(sin).mul_l(INTERNAL_COEF) / $promotion::from(cos);
+                Self::round_internal(tan, mode)
+            }
+
+            /// Calculates the arctangent in radians, in `[-pi / 2, pi / 2]`, with CORDIC.
+            ///
+            /// The error doesn't exceed `1` ULP under the same conditions as
+            /// for [`rsin`][Self::rsin], the reduction of the argument is exact.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Angle = FixedPoint<i64, U9>;
+            ///
+            /// let slope: Angle = "1".parse()?;
+            /// assert_eq!(slope.ratan(Nearest)?, "0.785398163".parse()?);
+            /// let slope: Angle = "-1000000".parse()?;
+            /// assert_eq!(slope.ratan(Nearest)?, "-1.570795327".parse()?);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[cfg(feature = "trig")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "trig")))]
+            pub fn ratan(self, mode: RoundMode) -> Result<Self> {
+                if self.inner == 0 {
+                    return Ok(Self::ZERO);
+                }
+
+                Self::round_internal(atan2_internal(self.inner, Self::COEF), mode)
+            }
+
+            /// Calculates the angle in radians, in `[-pi, pi]`, between the `x` axis and
+            /// the vector `(x, self)`, i.e. the arctangent of `self / x` in the right quadrant.
+            /// Returns `Err(DomainViolation)` if both `self` and `x` are zero.
+            ///
+            /// The error doesn't exceed `1` ULP under the same conditions as
+            /// for [`rsin`][Self::rsin]. The result depends only on the ratio, so it's exact for
+            /// vectors of any length, e.g. deltas of coordinates.
+            ///
+            /// ```
+            /// # #[cfg(feature = "i64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{ArithmeticError, FixedPoint, typenum::U9, ops::RoundMode::*};
+            ///
+            /// type Coord = FixedPoint<i64, U9>;
+            ///
+            /// let (dx, dy): (Coord, Coord) = ("-3".parse()?, "3".parse()?);
+            /// assert_eq!(dy.ratan2(dx, Nearest)?, "2.35619449".parse()?);
+            /// let (dx, dy): (Coord, Coord) = ("0".parse()?, "-0.5".parse()?);
+            /// assert_eq!(dy.ratan2(dx, Nearest)?, "-1.570796327".parse()?);
+            /// let zero: Coord = "0".parse()?;
+            /// assert_eq!(zero.ratan2(zero, Nearest), Err(ArithmeticError::DomainViolation));
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "i64"))]
+            /// # fn main() {}
+            /// ```
+            #[cfg(feature = "trig")]
+            #[cfg_attr(docsrs, doc(cfg(feature = "trig")))]
+            pub fn ratan2(self, x: Self, mode: RoundMode) -> Result<Self> {
+                match (self.inner, x.inner) {
+                    (0, 0) => Err(ArithmeticError::DomainViolation),
+                    (0, x) if x > 0 => Ok(Self::ZERO),
+                    (y, x) => Self::round_internal(atan2_internal(y, x), mode),
+                }
+            }
+
+            /// Classifies `x * 10^INTERNAL_PRECISION` as an argument of `e^x`.
+            fn exp_range(value: $promotion) -> ExpRange {
+                let internal_coef = $promotion::from(INTERNAL_COEF);
+
+                if value >= internal_coef.mul_l($layout::BITS as $layout) {
+                    // `e^x > 2^BITS` for `x >= BITS`.
+                    ExpRange::Overflow
+                } else if value <= internal_coef.mul_l(-3 * (Self::PRECISION as $layout + 1)) {
+                    // `e^x < 10^-(PRECISION + 1)` for `x <= -3 * (PRECISION + 1)`.
+                    ExpRange::Underflow
+                } else {
+                    ExpRange::Normal
+                }
+            }
+
+            /// Calculates `e^-|x| * 10^INTERNAL_PRECISION` for `value = x * 10^INTERNAL_PRECISION`.
+            /// Returns `None` if `e^-|x|` is less than `10^-(PRECISION + 1)`.
+            fn exp_neg_abs(value: $promotion) -> Result<Option<$layout>> {
+                let value = if value > $promotion::from(ZERO) { -value } else { value };
+
+                match Self::exp_range(value) {
+                    ExpRange::Underflow => Ok(None),
+                    _ => Ok(Some(exp_internal(value)?.as_layout())),
+                }
+            }
+
+            /// Rounds a number with the internal precision to `PRECISION`.
+            fn round_internal(value: $promotion, mode: RoundMode) -> Result<Self> {
+                let (result, loss) = value.div_rem_l(Self::GUARD_COEF);
+                let mut result = $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                if loss != 0 {
+                    let sign = loss.signum();
+                    let loss_abs = loss.abs();
+                    let half = loss_abs.cmp(&(Self::GUARD_COEF - loss_abs));
+
+                    if mode.is_away_from_zero(sign < 0, half, result % 2 != 0) {
+                        result = result.checked_add(sign).ok_or(ArithmeticError::Overflow)?;
+                    }
+                }
+
+                Ok(Self::from_bits(result))
+            }
+        }
+    };};
+}
+
+#[cfg(feature = "i16")]
+impl_transcendental!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i16")))]
+    inner = i16;
+    promoted_to = i32;
+    internal = typenum::U4;
+);
+#[cfg(feature = "i32")]
+impl_transcendental!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i32")))]
+    inner = i32;
+    promoted_to = i64;
+    internal = typenum::U9;
+);
+#[cfg(feature = "i64")]
+impl_transcendental!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i64")))]
+    inner = i64;
+    promoted_to = i128;
+    internal = typenum::U18;
+);
+#[cfg(feature = "i128")]
+impl_transcendental!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "i128")))]
+    inner = i128;
+    promoted_to = i256;
+    internal = typenum::U37;
+);
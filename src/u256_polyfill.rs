@@ -0,0 +1,201 @@
+use core::cmp::{Ordering, PartialOrd};
+use core::ops::{Add, Div, Mul, Sub};
+
+use ::i256::u256 as u256_;
+
+use crate::{
+    layout::Promotion,
+    ops::{One, Zero},
+    ConvertError, ConvertErrorKind,
+};
+
+/// A polyfill for u256.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub(crate) struct u256(pub u256_);
+
+static_assertions::assert_eq_size!(u256, [u128; 2]);
+
+impl u256 {
+    const U128_MAX: Self = Self::from_u128(u128::MAX);
+
+    pub(crate) const fn from_u128(x: u128) -> Self {
+        Self(u256_::from_u128(x))
+    }
+}
+
+impl Promotion for u256 {
+    type Layout = u128;
+
+    #[inline]
+    fn as_layout(&self) -> Self::Layout {
+        self.0.as_u128()
+    }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    fn as_positive_f64(&self) -> f64 {
+        let hi = self.0.high() as f64;
+        let lo = self.0.low() as f64;
+        let b2p128 = 3.402823669209385e38;
+        hi * b2p128 + lo
+    }
+
+    #[inline]
+    fn leading_zeros(&self) -> u32 {
+        self.0.leading_zeros()
+    }
+
+    #[inline]
+    fn checked_add(&self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    #[inline]
+    fn mul_l(&self, rhs: Self::Layout) -> Self {
+        Self(self.0.mul_uwide(rhs))
+    }
+
+    #[inline]
+    fn checked_mul_l(&self, rhs: Self::Layout) -> Option<Self> {
+        self.0.checked_mul_uwide(rhs).map(Self)
+    }
+
+    #[inline]
+    fn div_l(&self, rhs: Self::Layout) -> Self {
+        Self(self.0.div_uwide(rhs))
+    }
+
+    #[inline]
+    fn div_rem_l(&self, rhs: Self::Layout) -> (Self, Self::Layout) {
+        let (div, rem) = self.0.div_rem_uwide(rhs);
+        (Self(div), rem)
+    }
+}
+
+impl One for u256 {
+    const ONE: Self = Self::from_u128(1);
+}
+
+impl Zero for u256 {
+    const ZERO: Self = Self::from_u128(0);
+}
+
+impl Mul for u256 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Div for u256 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Self(self.0 / rhs.0)
+    }
+}
+
+impl Add for u256 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for u256 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Ord for u256 {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl PartialOrd for u256 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<u128> for u256 {
+    #[inline]
+    fn from(x: u128) -> Self {
+        Self::from_u128(x)
+    }
+}
+
+impl TryFrom<u256> for u128 {
+    type Error = ConvertError;
+
+    #[inline]
+    fn try_from(x: u256) -> Result<Self, Self::Error> {
+        if x > u256::U128_MAX {
+            return Err(ConvertError::new(
+                ConvertErrorKind::IntegralOverflow,
+                "not in range",
+            ));
+        }
+
+        Ok(x.0.as_u128())
+    }
+}
+
+// Simple smoke tests to check that the underlying implementation is adequate.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_u128() {
+        assert_eq!(u128::try_from(u256::U128_MAX).unwrap(), u128::MAX);
+        assert!(u128::try_from(u256::U128_MAX + u256::ONE).is_err());
+    }
+
+    #[test]
+    fn mul_div() {
+        let x = u256::from(u128::MAX).mul_l(1_000);
+        assert!(u128::try_from(x).is_err());
+        assert_eq!(x.div_rem_l(1_000), (u256::from(u128::MAX), 0));
+        assert_eq!(x.div_rem_l(7).1, u128::MAX % 7 * (1_000 % 7) % 7);
+
+        let square = u256::from(u128::MAX).checked_mul_l(u128::MAX).unwrap();
+        assert_eq!(square.div_l(u128::MAX), u256::from(u128::MAX));
+        assert_eq!(square.checked_mul_l(2), None);
+    }
+
+    #[test]
+    fn sqrt() {
+        use crate::ops::sqrt::Sqrt;
+
+        let square = u256::from(u128::MAX).mul_l(u128::MAX);
+        assert_eq!(square.sqrt(), u128::MAX);
+        assert_eq!((square - u256::ONE).sqrt(), u128::MAX - 1);
+        assert_eq!(u256::from(1u128 << 100).sqrt(), 1 << 50);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn as_positive_f64() {
+        fn t(x: u256, expected: f64) {
+            assert_eq!(x.as_positive_f64(), expected);
+        }
+        t(0u128.into(), 0.0);
+        t(1u128.into(), 1.0);
+        t(u128::MAX.into(), 3.402823669209385e38);
+    }
+}
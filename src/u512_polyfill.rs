@@ -0,0 +1,134 @@
+use ::i256::u256;
+
+/// A polyfill for u512, used only as an intermediate result of `u256 * u256`.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct u512 {
+    hi: u256,
+    lo: u256,
+}
+
+impl u512 {
+    /// Calculates the full product `a * b`.
+    pub(crate) fn mul(a: u256, b: u256) -> Self {
+        let (a0, a1) = (u256::from_u128(a.low()), u256::from_u128(a.high()));
+        let (b0, b1) = (u256::from_u128(b.low()), u256::from_u128(b.high()));
+
+        // Products of 128-bit halves always fit 256 bits.
+        let (mid, mid_carry) = (a0 * b1).overflowing_add(a1 * b0);
+        let (lo, lo_carry) = (a0 * b0).overflowing_add(u256::from_u128(mid.low()) << 128u32);
+
+        // The full product is less than `2 ^ 512`, so the high part cannot overflow.
+        let mut hi = a1 * b1 + u256::from_u128(mid.high());
+        if mid_carry {
+            hi += u256::from_u128(1) << 128u32;
+        }
+        if lo_carry {
+            hi += u256::from_u128(1);
+        }
+
+        Self { hi, lo }
+    }
+
+    /// Calculates `(self / divisor, self % divisor)`.
+    /// Returns `None` if the quotient doesn't fit `u256`.
+    ///
+    /// # Panics
+    /// If `divisor` is zero.
+    pub(crate) fn div_rem(self, divisor: u256) -> Option<(u256, u256)> {
+        assert!(divisor != u256::from_u128(0), "division by zero");
+
+        if self.hi == u256::from_u128(0) {
+            return Some((self.lo / divisor, self.lo % divisor));
+        }
+
+        // The quotient has more than 256 bits.
+        if self.hi >= divisor {
+            return None;
+        }
+
+        // Long division: the remainder is always less than `divisor`.
+        let one = u256::from_u128(1);
+        let mut quotient = u256::from_u128(0);
+        let mut remainder = self.hi;
+        for bit in (0..256u32).rev() {
+            let carry = remainder.leading_zeros() == 0;
+            remainder = (remainder << 1u32) | ((self.lo >> bit) & one);
+            if carry || remainder >= divisor {
+                remainder = remainder.wrapping_sub(divisor);
+                quotient |= one << bit;
+            }
+        }
+
+        Some((quotient, remainder))
+    }
+
+    /// Calculates `floor(sqrt(self))`, which always fits `u256`.
+    pub(crate) fn sqrt(self) -> u256 {
+        let one = u256::from_u128(1);
+        let mut root = u256::from_u128(0);
+
+        // Finds bits of the root from the highest one: `root^2 <= self` is monotonic.
+        for bit in (0..256u32).rev() {
+            let candidate = root | (one << bit);
+            let square = Self::mul(candidate, candidate);
+            if (square.hi, square.lo) <= (self.hi, self.lo) {
+                root = candidate;
+            }
+        }
+
+        root
+    }
+
+    /// Returns the lowest 256 bits, i.e. `self mod 2 ^ 256`.
+    pub(crate) fn low(self) -> u256 {
+        self.lo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mul() {
+        let x = u512::mul(u256::MAX, u256::MAX);
+        // (2^256 - 1)^2 = 2^512 - 2^257 + 1
+        assert_eq!(x.hi, u256::MAX - u256::from_u128(1));
+        assert_eq!(x.lo, u256::from_u128(1));
+
+        let x = u512::mul(u256::from_u128(u128::MAX), u256::from_u128(3));
+        assert_eq!(x.hi, u256::from_u128(0));
+        assert_eq!(x.lo, u256::from_u128(u128::MAX) * u256::from_u128(3));
+    }
+
+    #[test]
+    fn div_rem() {
+        let x = u512::mul(u256::MAX, u256::MAX);
+        assert_eq!(x.div_rem(u256::MAX), Some((u256::MAX, u256::from_u128(0))));
+        assert_eq!(x.div_rem(u256::from_u128(2)), None);
+
+        let seven = u256::from_u128(7);
+        let x = u512::mul(u256::MAX, u256::from_u128(1_000));
+        let (q, r) = x.div_rem(u256::MAX - seven).unwrap();
+        // MAX * 1000 = (MAX - 7) * 1000 + 7000
+        assert_eq!(q, u256::from_u128(1_000));
+        assert_eq!(r, u256::from_u128(7_000));
+
+        let x = u512::mul(u256::from_u128(100), u256::from_u128(3));
+        assert_eq!(
+            x.div_rem(seven),
+            Some((u256::from_u128(42), u256::from_u128(6)))
+        );
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(u512::mul(u256::MAX, u256::MAX).sqrt(), u256::MAX);
+        let x = u512::mul(u256::MAX, u256::MAX - u256::from_u128(1));
+        assert_eq!(x.sqrt(), u256::MAX - u256::from_u128(1));
+        let x = u512::mul(u256::from_u128(1 << 100), u256::from_u128(3));
+        assert_eq!(x.sqrt(), u256::from_u128(1_950_115_842_888_490));
+        assert_eq!(u512::mul(u256::MIN, u256::MIN).sqrt(), u256::MIN);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    ops::{CheckedSub, One, RoundMode, RoundingMul, Zero},
+    ConvertError, ConvertErrorKind, FixedPoint,
+};
+
+/// A number that is guaranteed to be in `[0, 1]`, e.g. a probability or a weight.
+///
+/// Because of the restricted domain, multiplication by such a ratio never overflows.
+///
+/// ```
+/// # #[cfg(feature = "i64")]
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use fixnum::{FixedPoint, UnitInterval, typenum::U9, ops::{Bounded, RoundMode::*}};
+///
+/// type Amount = FixedPoint<i64, U9>;
+///
+/// let weight = UnitInterval::new("0.25".parse::<Amount>()?)?;
+/// assert_eq!(weight.rmul("100".parse()?, Floor), "25".parse()?);
+/// assert_eq!(weight.complement().get(), "0.75".parse()?);
+/// assert_eq!(weight.rmul(Amount::MAX, Ceil), "2305843009.213693952".parse()?);
+///
+/// assert!(UnitInterval::new("1.5".parse::<Amount>()?).is_err());
+/// assert_eq!(UnitInterval::new_clamped("1.5".parse::<Amount>()?), UnitInterval::ONE);
+/// # Ok(()) }
+/// # #[cfg(not(feature = "i64"))]
+/// # fn main() {}
+/// ```
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct UnitInterval<F>(F);
+
+impl<F> UnitInterval<F> {
+    /// Returns the underlying number.
+    #[inline]
+    pub fn get(self) -> F {
+        self.0
+    }
+}
+
+impl<F: Zero + One> UnitInterval<F> {
+    /// Represents `0`.
+    pub const ZERO: Self = Self(F::ZERO);
+    /// Represents `1`.
+    pub const ONE: Self = Self(F::ONE);
+}
+
+impl<F: PartialOrd + Zero + One> UnitInterval<F> {
+    /// Creates a ratio. Returns `Err` if the value isn't in `[0, 1]`.
+    #[inline]
+    pub fn new(value: F) -> Result<Self, ConvertError> {
+        if F::ZERO <= value && value <= F::ONE {
+            Ok(Self(value))
+        } else {
+            Err(ConvertError::new(
+                ConvertErrorKind::OutOfDomain,
+                "not in [0, 1]",
+            ))
+        }
+    }
+
+    /// Creates a ratio, clamping the value to `[0, 1]`.
+    #[inline]
+    pub fn new_clamped(value: F) -> Self {
+        if value < F::ZERO {
+            Self::ZERO
+        } else if value > F::ONE {
+            Self::ONE
+        } else {
+            Self(value)
+        }
+    }
+}
+
+impl<F: CheckedSub<Output = F> + One> UnitInterval<F> {
+    /// Returns `1 - self`, which is a ratio too.
+    #[inline]
+    pub fn complement(self) -> Self {
+        // Both operands are in `[0, 1]`, so it cannot overflow.
+        Self(F::ONE.csub(self.0).unwrap_or_else(|_| unreachable!()))
+    }
+}
+
+impl<F: RoundingMul<Output = F>> UnitInterval<F> {
+    /// [Rounding][RoundMode] multiplication of the value by the ratio.
+    ///
+    /// Unlike [`RoundingMul::rmul`], it never fails, because the magnitude of the exact
+    /// product doesn't exceed `|value|`, which is representable.
+    #[inline]
+    pub fn rmul(self, value: F, mode: RoundMode) -> F {
+        value.rmul(self.0, mode).unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<I, P> From<UnitInterval<FixedPoint<I, P>>> for FixedPoint<I, P> {
+    #[inline]
+    fn from(ratio: UnitInterval<FixedPoint<I, P>>) -> Self {
+        ratio.0
+    }
+}
+
+impl<I, P> TryFrom<FixedPoint<I, P>> for UnitInterval<FixedPoint<I, P>>
+where
+    FixedPoint<I, P>: PartialOrd + Zero + One,
+{
+    type Error = ConvertError;
+
+    #[inline]
+    fn try_from(value: FixedPoint<I, P>) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
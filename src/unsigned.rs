@@ -0,0 +1,973 @@
+//! Implementations for unsigned layouts.
+//!
+//! Unsigned numbers can't be negative, so rounding towards zero and towards negative infinity
+//! are the same, and `csub` returns `Err(Overflow)` instead of going below zero.
+
+use core::{cmp::Ordering, fmt};
+
+#[cfg(feature = "u128")]
+use crate::u256_polyfill::u256;
+use crate::{
+    const_fn,
+    ops::{sqrt::Sqrt, *},
+    power_table,
+    string::Stringify,
+    ArithmeticError, ConvertError, ConvertErrorKind, FixedPoint, Precision, Result,
+};
+
+macro_rules! impl_unsigned_fixed_point {
+    (
+        $(#[$attr:meta])?
+        inner = $layout:tt;
+        promoted_to = $promotion:tt;
+        max_precision = $max_precision:literal;
+        try_from = [$($try_from:ty),*];
+    ) => {const _: () = {
+        use $crate::_priv::Promotion as _;
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// The number of digits in the fractional part.
+            pub const PRECISION: i32 = P::I32;
+            /// The difference between `0.0` and the next larger representable number.
+            pub const EPSILON: Self = Self::from_bits(1);
+            /// `0.5`. Fails to compile for the zero precision, where it isn't representable.
+            pub const ONE_HALF: Self = {
+                assert!(P::U32 > 0, "`ONE_HALF` isn't representable with the zero precision");
+                Self::from_bits(Self::COEF / 2)
+            };
+            /// `10`. Fails to compile if it doesn't fit the layout.
+            pub const TEN: Self = Self::from_bits(Self::COEF * 10);
+            /// `100`. Fails to compile if it doesn't fit the layout.
+            pub const HUNDRED: Self = Self::from_bits(Self::COEF * 100);
+            /// [`MAX`] in the decimal form, the same as `MAX.to_string()`, but available in const
+            /// contexts, e.g. it's used by [`fixnum_type!`](crate::fixnum_type).
+            ///
+            /// [`MAX`]: ./ops/trait.Bounded.html#associatedconstant.MAX
+            pub const MAX_STR: &'static str = const_fn::decimal_str(Self::MAX_DECIMAL);
+            /// [`EPSILON`](Self::EPSILON) in the decimal form, the same as `EPSILON.to_string()`.
+            pub const EPSILON_STR: &'static str = const_fn::decimal_str(Self::EPSILON_DECIMAL);
+
+            const MAX_DECIMAL: &'static const_fn::Decimal =
+                &const_fn::write_decimal(const_fn::to_limbs(<Self as Bounded>::MAX.inner as u128), P::U32);
+            const EPSILON_DECIMAL: &'static const_fn::Decimal =
+                &const_fn::write_decimal(const_fn::to_limbs(1), P::U32);
+
+            /// The largest precision of the layout, `10^MAX_PRECISION` is the largest power of
+            /// ten fitting in it.
+            pub const MAX_PRECISION: i32 = $max_precision;
+
+            pub(crate) const COEF: $layout = {
+                assert!(
+                    Self::PRECISION <= Self::MAX_PRECISION,
+                    concat!(
+                        "precision is too large for `",
+                        stringify!($layout),
+                        "`, the maximum is ",
+                        stringify!($max_precision),
+                    )
+                );
+                $layout::pow(10, P::U32)
+            };
+
+            pub(crate) fn integral_overflow() -> ConvertError {
+                ConvertError::integral_overflow(0, $layout::MAX as u128, Self::PRECISION as u32)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> Zero for FixedPoint<$layout, P> {
+            const ZERO: Self = Self::from_bits(0);
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> One for FixedPoint<$layout, P> {
+            const ONE: Self = Self::from_bits(Self::COEF);
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> Bounded for FixedPoint<$layout, P> {
+            const MIN: Self = Self::from_bits($layout::MIN);
+            const MAX: Self = Self::from_bits($layout::MAX);
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingMul for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                let value = $promotion::from(self.inner).mul_l(rhs.inner);
+                // `loss < COEF`, thus it fits in the layout.
+                let (result, loss) = value.div_rem_l(Self::COEF);
+
+                let result = $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                let add_one =
+                    loss != 0 && mode.is_away_from_zero(false, loss.cmp(&(Self::COEF - loss)), result % 2 != 0);
+
+                if add_one {
+                    result.checked_add(1).map(Self::from_bits).ok_or(ArithmeticError::Overflow)
+                } else {
+                    Ok(Self::from_bits(result))
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingDiv for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+                if rhs.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                let numerator = $promotion::from(self.inner).mul_l(Self::COEF);
+                // `loss < rhs`, thus it fits in the layout.
+                let (result, loss) = numerator.div_rem_l(rhs.inner);
+
+                let result = $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                let add_one =
+                    loss != 0 && mode.is_away_from_zero(false, loss.cmp(&(rhs.inner - loss)), result % 2 != 0);
+
+                if add_one {
+                    result.checked_add(1).map(Self::from_bits).ok_or(ArithmeticError::Overflow)
+                } else {
+                    Ok(Self::from_bits(result))
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingMulDiv for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rmul_div(self, rhs: Self, divisor: Self, mode: RoundMode) -> Result<Self> {
+                if divisor.inner == 0 {
+                    return Err(ArithmeticError::DivisionByZero);
+                }
+
+                // `COEF`s cancel each other out, so raw values are enough.
+                let value = $promotion::from(self.inner).mul_l(rhs.inner);
+                // `loss < divisor`, thus it fits in the layout.
+                let (result, loss) = value.div_rem_l(divisor.inner);
+
+                let result = $layout::try_from(result).map_err(|_| ArithmeticError::Overflow)?;
+
+                let add_one = loss != 0
+                    && mode.is_away_from_zero(false, loss.cmp(&(divisor.inner - loss)), result % 2 != 0);
+
+                if add_one {
+                    result.checked_add(1).map(Self::from_bits).ok_or(ArithmeticError::Overflow)
+                } else {
+                    Ok(Self::from_bits(result))
+                }
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedRem for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Quotient = $layout;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn crem(self, rhs: Self) -> Result<Self> {
+                self.inner
+                    .checked_rem(rhs.inner)
+                    .map(Self::from_bits)
+                    .ok_or(ArithmeticError::DivisionByZero)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: Self) -> Result<Self> {
+                self.crem(rhs)
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: Self) -> Result<$layout> {
+                self.inner.checked_div(rhs.inner).ok_or(ArithmeticError::DivisionByZero)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedRem<$layout> for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Quotient = $layout;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn crem(self, rhs: $layout) -> Result<Self> {
+                self.crem(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+            }
+
+            #[inline]
+            fn rem_euclid(self, rhs: $layout) -> Result<Self> {
+                self.crem(rhs)
+            }
+
+            #[inline]
+            fn div_euclid(self, rhs: $layout) -> Result<$layout> {
+                self.div_euclid(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingDiv<$layout> for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: $layout, mode: RoundMode) -> Result<Self> {
+                self.inner.rdiv(rhs, mode).map(Self::from_bits)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> RoundingDiv<FixedPoint<$layout, P>> for $layout {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn rdiv(self, rhs: FixedPoint<$layout, P>, mode: RoundMode) -> Result<FixedPoint<$layout, P>> {
+                let lhs = FixedPoint::<$layout, P>::try_from(self).map_err(|_| ArithmeticError::Overflow)?;
+                lhs.rdiv(rhs, mode)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedAdd for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cadd(self, rhs: Self) -> Result<Self> {
+                self.inner.cadd(rhs.inner).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn saturating_add(self, rhs: Self) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_add(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedSub for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            /// Checked subtraction. Returns `Err(Overflow)` if the result is negative.
+            #[inline]
+            fn csub(self, rhs: Self) -> Result<Self> {
+                self.inner.csub(rhs.inner).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn saturating_sub(self, rhs: Self) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_sub(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedMul<$layout> for FixedPoint<$layout, P> {
+            type Output = Self;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cmul(self, rhs: $layout) -> Result<Self> {
+                self.inner.cmul(rhs).map(Self::from_bits)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: $layout) -> Self::Output {
+                Self::Output::from_bits(self.inner.saturating_mul(rhs))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> CheckedMul<FixedPoint<$layout, P>> for $layout {
+            type Output = FixedPoint<$layout, P>;
+            type Error = ArithmeticError;
+
+            #[inline]
+            fn cmul(self, rhs: FixedPoint<$layout, P>) -> Result<FixedPoint<$layout, P>> {
+                rhs.cmul(self)
+            }
+
+            #[inline]
+            fn saturating_mul(self, rhs: FixedPoint<$layout, P>) -> Self::Output {
+                Self::Output::from_bits(self.saturating_mul(rhs.inner))
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> crate::ops::sealed::Sealed for FixedPoint<$layout, P> {}
+
+        #[cfg(feature = "inventory")]
+        impl<P: Precision> crate::registry::Layout for FixedPoint<$layout, P> {
+            const LAYOUT: &'static str = stringify!($layout);
+            const PRECISION: u32 = P::U32;
+            const MIN_BITS: i128 = $layout::MIN as i128;
+            const MAX_BITS: u128 = $layout::MAX as u128;
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPointOps for FixedPoint<$layout, P> {
+            type Layout = $layout;
+
+            const PRECISION: i32 = Self::PRECISION;
+            const EPSILON: Self = Self::EPSILON;
+
+            #[inline]
+            fn from_bits(raw: $layout) -> Self {
+                Self::from_bits(raw)
+            }
+
+            #[inline]
+            fn into_bits(self) -> $layout {
+                Self::into_bits(self)
+            }
+
+            #[inline]
+            fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::rsqrt(self, mode)
+            }
+
+            #[inline]
+            fn recip(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::recip(self, mode)
+            }
+
+            #[inline]
+            fn from_ratio(numerator: $layout, denominator: $layout, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                Self::from_ratio(numerator, denominator, mode)
+            }
+
+            #[inline]
+            fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+                Self::half_sum(a, b, mode)
+            }
+
+            #[inline]
+            fn integral(self, mode: RoundMode) -> $layout {
+                Self::integral(self, mode)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                Self::floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                Self::ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                Self::round(self)
+            }
+
+            #[inline]
+            fn from_decimal(mantissa: $layout, exponent: i32) -> Result<Self, ConvertError> {
+                Self::from_decimal(mantissa, exponent)
+            }
+
+            #[inline]
+            fn to_decimal(&self, max_exponent: i32) -> ($layout, i32) {
+                Self::to_decimal(self, max_exponent)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> FixedPoint<$layout, P> {
+            /// Returns `true` if the number is zero. It takes `&self` to be usable in
+            /// `#[serde(skip_serializing_if = "...")]`.
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.inner == 0
+            }
+
+            /// Returns `true` if the number is greater than zero.
+            #[inline]
+            pub const fn is_positive(&self) -> bool {
+                self.inner > 0
+            }
+
+            /// Returns `true` if the number has no fractional part.
+            #[inline]
+            pub const fn is_integer(&self) -> bool {
+                self.inner % Self::COEF == 0
+            }
+
+            /// Returns `true` if the number is in `[0, 1]`, e.g. it's a valid probability or weight.
+            #[inline]
+            pub fn is_ratio(self) -> bool {
+                self.inner <= Self::COEF
+            }
+
+            /// Clamps the number to `[0, 1]`.
+            #[inline]
+            pub fn clamp01(self) -> Self {
+                Self::from_bits(self.inner.min(Self::COEF))
+            }
+
+            /// Returns the minimum of two numbers, like [`Ord::min`], but usable in const contexts.
+            #[inline]
+            pub const fn min(self, other: Self) -> Self {
+                if other.inner < self.inner {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the maximum of two numbers, like [`Ord::max`], but usable in const contexts.
+            #[inline]
+            pub const fn max(self, other: Self) -> Self {
+                if other.inner < self.inner {
+                    self
+                } else {
+                    other
+                }
+            }
+
+            /// Restricts the number to `[min, max]`, like [`Ord::clamp`], but usable in const
+            /// contexts.
+            ///
+            /// # Panics
+            /// If `min > max`.
+            ///
+            /// ```
+            /// # #[cfg(feature = "u64")]
+            /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+            /// use fixnum::{FixedPoint, typenum::U9, fixnum_const};
+            ///
+            /// type Leverage = FixedPoint<u64, U9>;
+            ///
+            /// const MIN_LEVERAGE: Leverage = fixnum_const!(1, 9);
+            /// const MAX_LEVERAGE: Leverage = fixnum_const!(125, 9);
+            /// const REQUESTED_LEVERAGE: Leverage = fixnum_const!(200, 9);
+            /// const LEVERAGE: Leverage = REQUESTED_LEVERAGE.clamp(MIN_LEVERAGE, MAX_LEVERAGE);
+            ///
+            /// assert_eq!(LEVERAGE, MAX_LEVERAGE);
+            /// assert_eq!(MIN_LEVERAGE.min(MAX_LEVERAGE), MIN_LEVERAGE);
+            /// assert_eq!(MIN_LEVERAGE.max(MAX_LEVERAGE), MAX_LEVERAGE);
+            /// # Ok(()) }
+            /// # #[cfg(not(feature = "u64"))]
+            /// # fn main() {}
+            /// ```
+            #[inline]
+            #[track_caller]
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                assert!(min.inner <= max.inner, "assertion failed: min <= max");
+                if self.inner < min.inner {
+                    min
+                } else if self.inner > max.inner {
+                    max
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the absolute difference between two numbers, like [`u64::abs_diff`].
+            /// It never overflows.
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> Self {
+                Self::from_bits(self.inner.abs_diff(other.inner))
+            }
+
+            /// Returns `1/n`.
+            #[inline]
+            pub fn recip(self, mode: RoundMode) -> Result<Self> {
+                Self::ONE.rdiv(self, mode)
+            }
+
+            /// Divides by `rhs`, but returns `default` instead of `Err(DivisionByZero)`
+            /// if `rhs` is zero.
+            #[inline]
+            pub fn rdiv_or(self, rhs: Self, default: Self, mode: RoundMode) -> Result<Self> {
+                match self.rdiv(rhs, mode) {
+                    Err(ArithmeticError::DivisionByZero) => Ok(default),
+                    result => result,
+                }
+            }
+
+            /// Returns `1/n`, but returns `default` instead of `Err(DivisionByZero)`
+            /// if the number is zero.
+            #[inline]
+            pub fn recip_or(self, default: Self, mode: RoundMode) -> Result<Self> {
+                Self::ONE.rdiv_or(self, default, mode)
+            }
+
+            /// Constructs the quotient `numerator / denominator` of two integers, rounding it
+            /// according to `mode`. E.g. converts counts into rates.
+            #[inline]
+            pub fn from_ratio(numerator: $layout, denominator: $layout, mode: RoundMode) -> Result<Self> {
+                Self::from_bits(numerator).rdiv(Self::from_bits(denominator), mode)
+            }
+
+            /// Applies a slippage of `bps` basis points, i.e. calculates
+            /// `self * (1 + bps / 10000)` with the only rounding. Use `Ceil` for buys and `Floor`
+            /// for sells. Returns `Err(Overflow)` if the result doesn't fit.
+            #[inline]
+            pub fn apply_bps(self, bps: i32, mode: RoundMode) -> Result<Self> {
+                const BPS_PER_ONE: $layout = 10_000;
+
+                let factor = $layout::try_from(i64::from(bps) + 10_000)
+                    .map_err(|_| ArithmeticError::Overflow)?;
+                // `COEF`s cancel each other out, so raw values are enough.
+                self.rmul_div(Self::from_bits(factor), Self::from_bits(BPS_PER_ONE), mode)
+            }
+
+            /// Calculates `self + rhs`, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            #[inline]
+            pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_add(rhs.inner);
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Calculates `self - rhs`, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            #[inline]
+            pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_sub(rhs.inner);
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Calculates `self * rhs`, returning the wrapped result along with a boolean
+            /// indicating whether an overflow has occurred.
+            #[inline]
+            pub const fn overflowing_mul(self, rhs: $layout) -> (Self, bool) {
+                let (inner, overflow) = self.inner.overflowing_mul(rhs);
+                (Self::from_bits(inner), overflow)
+            }
+
+            /// Checked sum of all values. Returns `ZERO` for an empty iterator and `Err`
+            /// on the first overflow, so the rest of values isn't consumed.
+            #[inline]
+            pub fn csum(iter: impl IntoIterator<Item = Self>) -> Result<Self> {
+                iter.into_iter().try_fold(Self::ZERO, Self::cadd)
+            }
+
+            /// Calculates `(a + b) / 2`.
+            ///
+            /// It never fails: the result lies between `a` and `b`.
+            #[inline]
+            pub fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+                let min = a.inner.min(b.inner);
+                let max = a.inner.max(b.inner);
+                let (half_diff, loss) = ((max - min) / 2, (max - min) % 2);
+                let truncated = min + half_diff;
+
+                // `truncated + 1 <= max` if there is a loss.
+                if loss != 0 && mode.is_away_from_zero(false, Ordering::Equal, truncated % 2 != 0) {
+                    Self::from_bits(truncated + 1)
+                } else {
+                    Self::from_bits(truncated)
+                }
+            }
+
+            /// Takes [rounded][RoundMode] integral part of the number.
+            #[inline]
+            pub fn integral(self, mode: RoundMode) -> $layout {
+                let (int, frac) = (self.inner / Self::COEF, self.inner % Self::COEF);
+
+                let add_one =
+                    frac != 0 && mode.is_away_from_zero(false, frac.cmp(&(Self::COEF - frac)), int % 2 != 0);
+
+                if add_one {
+                    int + 1
+                } else {
+                    int
+                }
+            }
+
+            #[inline]
+            fn integral_fp(self, mode: RoundMode) -> Self {
+                let int = self.integral(mode);
+                // Rounding moves the value by less than `1`, thus the previous integer fits.
+                let inner = int
+                    .checked_mul(Self::COEF)
+                    .unwrap_or_else(|| (int - 1) * Self::COEF);
+                Self::from_bits(inner)
+            }
+
+            /// Returns the largest integer less than or equal to a number.
+            #[inline]
+            pub fn floor(self) -> Self {
+                self.integral_fp(RoundMode::Floor)
+            }
+
+            /// Returns the smallest integer greater than or equal to a number.
+            /// If the result doesn't fit (only possible near `MAX`), saturates to the nearest
+            /// representable integer, which is less than the number in this case.
+            #[inline]
+            pub fn ceil(self) -> Self {
+                self.integral_fp(RoundMode::Ceil)
+            }
+
+            /// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
+            /// If the result doesn't fit (only possible near `MAX`), saturates to the nearest
+            /// representable integer.
+            #[inline]
+            pub fn round(self) -> Self {
+                self.integral_fp(RoundMode::Nearest)
+            }
+
+            /// Returns the integer part of a number, i.e. rounds towards zero. It never fails.
+            #[inline]
+            pub fn trunc(self) -> Self {
+                Self::from_bits(self.inner / Self::COEF * Self::COEF)
+            }
+
+            /// Returns the fractional part of a number.
+            /// `trunc() + fract()` is equal to the number.
+            #[inline]
+            pub fn fract(self) -> Self {
+                Self::from_bits(self.inner % Self::COEF)
+            }
+
+            /// Rounds towards zero by the provided precision.
+            ///
+            /// It never fails: the result lies between zero and `self`.
+            /// The number is returned unchanged if `precision` is zero.
+            #[inline]
+            pub fn round_towards_zero_by(self, precision: Self) -> Self {
+                // `None` only if `precision` is zero.
+                let loss = self.inner.checked_rem(precision.inner).unwrap_or(0);
+                Self::from_bits(self.inner - loss)
+            }
+
+            /// Rounds the number to `decimal_places` digits after the point according to `mode`.
+            /// The number is returned unchanged if `decimal_places >= PRECISION`.
+            ///
+            /// Returns `Err(Overflow)` if the rounded number doesn't fit (only possible near `MAX`).
+            #[inline]
+            pub fn round_to(self, decimal_places: u32, mode: RoundMode) -> Result<Self> {
+                if decimal_places >= P::U32 {
+                    return Ok(self);
+                }
+
+                // `step <= COEF`, thus it fits in the layout.
+                let step = $layout::pow(10, P::U32 - decimal_places);
+                let steps = self.inner.rdiv(step, mode)?;
+                steps.checked_mul(step).map(Self::from_bits).ok_or(ArithmeticError::Overflow)
+            }
+
+            /// Checked [rounding][RoundMode] square root.
+            ///
+            /// Square root of F is S such that:
+            /// * `Floor`, `TowardZero`: `S ≤ sqrt(F)`
+            /// * `Ceil`, `AwayFromZero`: `S ≥ sqrt(F)`
+            /// * `Nearest`, `HalfEven`: `Floor` or `Ceil`, which one is closer to `sqrt(F)`
+            ///
+            /// Unlike signed layouts, it never fails.
+            #[inline]
+            pub fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+                // See the implementation for signed layouts for details.
+                let squared = $promotion::from(self.inner).mul_l(Self::COEF);
+                let lo = squared.sqrt();
+
+                let lo2 = lo.wrapping_mul(lo);
+                let hi2 = lo2.wrapping_add(lo).wrapping_add(lo).wrapping_add(1);
+                let squared = squared.as_layout();
+                let half = squared.wrapping_sub(lo2).cmp(&hi2.wrapping_sub(squared));
+
+                let add_one = lo2 != squared && mode.is_away_from_zero(false, half, lo % 2 != 0);
+
+                Ok(Self::from_bits(if add_one { lo + 1 } else { lo }))
+            }
+
+            /// Creates a new number from separate mantissa and exponent.
+            pub fn from_decimal(mantissa: $layout, exponent: i32) -> Result<Self, ConvertError> {
+                if exponent < -Self::PRECISION || exponent > 10 {
+                    return Err(ConvertError::new(ConvertErrorKind::UnsupportedExponent, "unsupported exponent"));
+                }
+
+                let ten: $layout = 10;
+                let multiplier = ten.pow((exponent + Self::PRECISION) as u32);
+
+                mantissa
+                    .checked_mul(multiplier)
+                    .map(Self::from_bits)
+                    .map_or_else(|| Err(ConvertError::new(ConvertErrorKind::IntegralOverflow, "too big mantissa")), Ok)
+            }
+
+            /// Returns a pair `(mantissa, exponent)` where `exponent`
+            /// is in `[-PRECISION, max_exponent]`.
+            ///
+            /// # Panics
+            /// If `max_exponent` is less than `-PRECISION`.
+            pub fn to_decimal(&self, max_exponent: i32) -> ($layout, i32) {
+                let (mantissa, exponent, _) = self.to_decimal_stripped(max_exponent);
+                (mantissa, exponent)
+            }
+
+            /// The same as [`to_decimal`](Self::to_decimal), but additionally returns the number
+            /// of stripped trailing zeros, i.e. `exponent + PRECISION`.
+            ///
+            /// # Panics
+            /// If `max_exponent` is less than `-PRECISION`.
+            pub fn to_decimal_stripped(&self, max_exponent: i32) -> ($layout, i32, u32) {
+                assert!(max_exponent >= -Self::PRECISION);
+
+                let max_stripped = (i64::from(max_exponent) + i64::from(Self::PRECISION)) as u64;
+
+                if self.inner == 0 {
+                    let exponent = 0.min(max_exponent);
+                    return (0, exponent, (exponent + Self::PRECISION) as u32);
+                }
+
+                // `10 ^ k` divides `inner` only if `10 ^ k` fits the layout, because `inner != 0`.
+                let power_of_10 = |k: usize| {
+                    let power = power_table::POWERS_OF_10[k].0;
+                    (power <= $layout::MAX as u128).then(|| power as $layout)
+                };
+                let is_stripped = |k: usize| power_of_10(k).map_or(false, |p| self.inner % p == 0);
+
+                // Divisibility by `10 ^ k` is monotonic in `k`, so find the largest `k`.
+                let mut low = 0;
+                let mut high = max_stripped.min(power_table::POWERS_OF_10.len() as u64 - 1) as usize;
+                while low < high {
+                    let middle = high - (high - low) / 2;
+                    if is_stripped(middle) {
+                        low = middle;
+                    } else {
+                        high = middle - 1;
+                    }
+                }
+
+                let mantissa = power_of_10(low).map_or(self.inner, |p| self.inner / p);
+                (mantissa, low as i32 - Self::PRECISION, low as u32)
+            }
+
+            /// Splits into `(negative, integral, fractional)`, where `fractional` is the numerator
+            /// of the fractional part over `10 ^ PRECISION`. `negative` is always `false`,
+            /// it's returned for compatibility with signed layouts.
+            #[inline]
+            pub fn to_parts(self) -> (bool, u128, u128) {
+                let coef = Self::COEF as u128;
+                (false, self.inner as u128 / coef, self.inner as u128 % coef)
+            }
+
+            /// Creates a number from `(negative, integral, fractional)` parts,
+            /// see [`to_parts`](Self::to_parts). A negative zero is allowed.
+            ///
+            /// Returns `Err` if `fractional` isn't less than `10 ^ PRECISION`
+            /// or the number doesn't fit the layout.
+            pub fn from_parts(negative: bool, integral: u128, fractional: u128) -> Result<Self, ConvertError> {
+                let coef = Self::COEF as u128;
+                if fractional >= coef {
+                    return Err(ConvertError::new(
+                        ConvertErrorKind::OutOfDomain,
+                        "fractional part must be less than 10 ^ PRECISION",
+                    ));
+                }
+
+                let abs = integral
+                    .checked_mul(coef)
+                    .and_then(|abs| abs.checked_add(fractional))
+                    .ok_or_else(Self::integral_overflow)?;
+
+                if negative && abs != 0 {
+                    return Err(Self::integral_overflow());
+                }
+
+                $layout::try_from(abs)
+                    .map(Self::from_bits)
+                    .map_err(|_| Self::integral_overflow())
+            }
+
+            /// Creates a number from an ASCII digit, e.g. `b'7'` becomes `7.0`.
+            /// Returns `Err` for other bytes and digits that don't fit the layout.
+            #[inline]
+            pub fn try_from_digit(digit: u8) -> Result<Self, ConvertError> {
+                if !digit.is_ascii_digit() {
+                    return Err(ConvertError::new(ConvertErrorKind::InvalidSyntax, "not an ASCII digit"));
+                }
+
+                Self::try_from(digit - b'0')
+            }
+
+            /// Converts to another precision, rounding according to `mode` if it's lower.
+            /// Increasing the precision is exact, so it fails only if the result doesn't fit.
+            #[inline]
+            pub fn rescale<Q: Precision>(self, mode: RoundMode) -> Result<FixedPoint<$layout, Q>> {
+                let (from, to) = (Self::COEF, FixedPoint::<$layout, Q>::COEF);
+                let inner = if to >= from {
+                    self.inner.checked_mul(to / from).ok_or(ArithmeticError::Overflow)?
+                } else {
+                    self.inner.rdiv(from / to, mode)?
+                };
+                Ok(FixedPoint::from_bits(inner))
+            }
+
+            /// Converts to another precision exactly, without rounding.
+            /// Returns `Err` if the number has more decimal places than `Q` allows
+            /// ([`is_fractional_precision`]) or the result doesn't fit ([`is_integral_overflow`]).
+            ///
+            /// Unlike [`rescale`](Self::rescale), any loss is an error, so it's both stricter and
+            /// cheaper for values known to have few decimal places, e.g. prices stored with
+            /// a higher precision.
+            ///
+            /// [`is_fractional_precision`]: ConvertError::is_fractional_precision
+            /// [`is_integral_overflow`]: ConvertError::is_integral_overflow
+            #[inline]
+            pub fn try_rescale<Q: Precision>(self) -> Result<FixedPoint<$layout, Q>, ConvertError> {
+                let (from, to) = (Self::COEF, FixedPoint::<$layout, Q>::COEF);
+                let inner = if to >= from {
+                    self.inner
+                        .checked_mul(to / from)
+                        .ok_or_else(FixedPoint::<$layout, Q>::integral_overflow)?
+                } else if self.inner % (from / to) == 0 {
+                    self.inner / (from / to)
+                } else {
+                    let precision = FixedPoint::<$layout, Q>::PRECISION as u32;
+                    return Err(ConvertError::fractional_precision(precision));
+                };
+                Ok(FixedPoint::from_bits(inner))
+            }
+
+            /// Returns the decimal digit at `position` relative to the decimal point:
+            /// `0` is the units digit, `1` is tens, `-1` is tenths and so on.
+            ///
+            /// Positions outside the representable range have zero digits.
+            #[inline]
+            pub fn digit_at(&self, position: i32) -> u8 {
+                let exponent = match position.checked_add(Self::PRECISION) {
+                    Some(exponent) if exponent >= 0 => exponent as u32,
+                    _ => return 0,
+                };
+
+                power_table::power_of_10(exponent)
+                    .map_or(0, |power| (self.inner as u128 / power % 10) as u8)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Debug for FixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = Default::default();
+                self.stringify(&mut buf);
+                f.write_str(buf.as_str())
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> fmt::Display for FixedPoint<$layout, P> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut buf = Default::default();
+                self.stringify(&mut buf);
+                f.write_str(buf.as_str())
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> TryFrom<($layout, i32)> for FixedPoint<$layout, P> {
+            type Error = ConvertError;
+
+            /// Creates a new number from a pair `(mantissa, exponent)`.
+            /// The same as [`from_decimal`](FixedPoint::from_decimal).
+            #[inline]
+            fn try_from((mantissa, exponent): ($layout, i32)) -> Result<Self, Self::Error> {
+                Self::from_decimal(mantissa, exponent)
+            }
+        }
+
+        $(#[$attr])?
+        impl<P: Precision> From<bool> for FixedPoint<$layout, P> {
+            /// Converts `true` to `ONE` and `false` to `ZERO`.
+            #[inline]
+            fn from(value: bool) -> Self {
+                if value {
+                    Self::ONE
+                } else {
+                    Self::ZERO
+                }
+            }
+        }
+
+        impl<P: Precision> FixedPoint<$layout, P> {
+            pub(crate) fn to_f64(self) -> f64 {
+                let integral = (self.inner / Self::COEF) as f64;
+                let fractional = ((self.inner % Self::COEF) as f64) / (Self::COEF as f64);
+                integral + fractional
+            }
+
+            #[cfg(feature = "audit")]
+            fn audit_to_f64(self, float: f64) {
+                // The float doesn't fit the layout only near `MIN` and `MAX`, it's at least `EPSILON` off.
+                let distance = Self::from_f64(float).map_or(1, |back| self.inner.abs_diff(back.inner));
+                let loss = distance as f64 / Self::COEF as f64;
+                crate::audit::record(crate::audit::Direction::ToF64, Self::PRECISION, float, loss);
+            }
+        }
+
+        impl<P: Precision> From<FixedPoint<$layout, P>> for f64 {
+            fn from(value: FixedPoint<$layout, P>) -> Self {
+                let float = value.to_f64();
+
+                #[cfg(feature = "audit")]
+                value.audit_to_f64(float);
+
+                float
+            }
+        }
+
+        $(
+            impl<P: Precision> TryFrom<$try_from> for FixedPoint<$layout, P> {
+                type Error = ConvertError;
+
+                fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+                    $layout::try_from(value)
+                        .map_err(|_| Self::integral_overflow())?
+                        .checked_mul(Self::COEF)
+                        .map(Self::from_bits)
+                        .ok_or_else(Self::integral_overflow)
+                }
+            }
+        )*
+    };};
+}
+
+#[cfg(feature = "u16")]
+impl_unsigned_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u16")))]
+    inner = u16;
+    promoted_to = u32;
+    max_precision = 4;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
+#[cfg(feature = "u32")]
+impl_unsigned_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u32")))]
+    inner = u32;
+    promoted_to = u64;
+    max_precision = 9;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
+#[cfg(feature = "u64")]
+impl_unsigned_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u64")))]
+    inner = u64;
+    promoted_to = u128;
+    max_precision = 19;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
+#[cfg(feature = "u128")]
+impl_unsigned_fixed_point!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "u128")))]
+    inner = u128;
+    promoted_to = u256;
+    max_precision = 38;
+    try_from = [i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, isize, usize];
+);
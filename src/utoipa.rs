@@ -0,0 +1,29 @@
+use std::borrow::Cow;
+
+use utoipa::{
+    openapi::{schema::ObjectBuilder, RefOr, Schema, Type},
+    PartialSchema, ToSchema,
+};
+
+use crate::{FixedPoint, Precision};
+
+impl<I, P: Precision> PartialSchema for FixedPoint<I, P> {
+    fn schema() -> RefOr<Schema> {
+        let (pattern, example) = match P::U32 {
+            0 => (r"^-?[0-9]+$".into(), "42"),
+            precision => (format!(r"^-?[0-9]+(\.[0-9]{{1,{}}})?$", precision), "42.5"),
+        };
+
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .pattern(Some(pattern))
+            .examples([example])
+            .into()
+    }
+}
+
+impl<I, P: Precision> ToSchema for FixedPoint<I, P> {
+    fn name() -> Cow<'static, str> {
+        Cow::Borrowed("FixedPoint")
+    }
+}
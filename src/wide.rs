@@ -0,0 +1,839 @@
+//! The 256-bit layout, `FixedPoint<I256, P>`, for more than 38 significant digits.
+//!
+//! Multiplication and division are promoted to a polyfill for `u512`.
+
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use ::i256::{i256 as I256, u256};
+
+use crate::{
+    const_fn, ops::*, u512_polyfill::u512, ArithmeticError, ConvertError, ConvertErrorKind,
+    FixedPoint, Precision, Result,
+};
+
+/// `10 ^ 77` doesn't fit `I256`.
+const MAX_PRECISION: usize = 76;
+
+const ZERO: I256 = I256::from_u8(0);
+
+/// Splits a non-negative number into little-endian limbs for `const_fn::write_decimal`.
+const fn limbs(value: I256) -> [u64; 4] {
+    let bytes = value.to_le_bytes();
+    let mut limbs = [0; 4];
+
+    let mut i = 0;
+    while i < bytes.len() {
+        limbs[i / 8] |= (bytes[i] as u64) << (i % 8 * 8);
+        i += 1;
+    }
+
+    limbs
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "i256")))]
+impl<P: Precision> FixedPoint<I256, P> {
+    /// The number of digits in the fractional part.
+    pub const PRECISION: i32 = P::I32;
+    /// The difference between `0.0` and the next larger representable number.
+    pub const EPSILON: Self = Self::from_bits(I256::from_u8(1));
+    /// [`MAX`] in the decimal form, the same as `MAX.to_string()`, but available in const
+    /// contexts, e.g. it's used by [`fixnum_type!`](crate::fixnum_type).
+    ///
+    /// [`MAX`]: ./ops/trait.Bounded.html#associatedconstant.MAX
+    pub const MAX_STR: &'static str = const_fn::decimal_str(Self::MAX_DECIMAL);
+    /// [`EPSILON`](Self::EPSILON) in the decimal form, the same as `EPSILON.to_string()`.
+    pub const EPSILON_STR: &'static str = const_fn::decimal_str(Self::EPSILON_DECIMAL);
+
+    const MAX_DECIMAL: &'static const_fn::Decimal =
+        &const_fn::write_decimal(limbs(I256::MAX), P::U32);
+    const EPSILON_DECIMAL: &'static const_fn::Decimal =
+        &const_fn::write_decimal(const_fn::to_limbs(1), P::U32);
+    /// The largest precision of the layout, `10^MAX_PRECISION` is the largest power of
+    /// ten fitting in it. It's equal to [`MaxPrecision`](crate::MaxPrecision).
+    pub const MAX_PRECISION: i32 = MAX_PRECISION as i32;
+
+    const COEF: I256 = I256::from_u8(10).pow(P::U32);
+
+    fn integral_overflow() -> ConvertError {
+        ConvertError::new(ConvertErrorKind::IntegralOverflow, "too big number")
+    }
+
+    /// Builds a number from the magnitude and the sign, checking the range.
+    fn from_magnitude(negative: bool, magnitude: u256) -> Option<Self> {
+        let value = magnitude.cast_signed();
+        if !negative {
+            (!value.is_negative()).then(|| Self::from_bits(value))
+        } else if !value.is_negative() {
+            Some(Self::from_bits(-value))
+        } else {
+            // Only `MIN` has the magnitude `2 ^ 255`.
+            (value == I256::MIN).then(|| Self::from_bits(value))
+        }
+    }
+
+    /// Calculates `a * b / divisor` rounding according to `mode`.
+    fn mul_div(a: I256, b: I256, divisor: I256, mode: RoundMode) -> Result<Self> {
+        let negative = a.is_negative() != b.is_negative() && a != ZERO && b != ZERO;
+        let negative = negative != divisor.is_negative();
+        let divisor = divisor.unsigned_abs();
+
+        let product = u512::mul(a.unsigned_abs(), b.unsigned_abs());
+        let (result, loss) = product.div_rem(divisor).ok_or(ArithmeticError::Overflow)?;
+
+        // Rounding is done on the magnitude.
+        let is_odd = result % u256::from_u8(2) != u256::MIN;
+        let add_one = loss != u256::MIN
+            && mode.is_away_from_zero(negative, loss.cmp(&(divisor - loss)), is_odd);
+
+        let result = if add_one {
+            result.checked_add(u256::from_u8(1))
+        } else {
+            Some(result)
+        };
+
+        result
+            .and_then(|result| Self::from_magnitude(negative, result))
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Returns a number representing sign of self.
+    /// * `0` if the number is zero
+    /// * `1` if the number is positive
+    /// * `-1` if the number is negative
+    #[inline]
+    pub fn signum(self) -> I256 {
+        self.inner.signum()
+    }
+
+    /// Returns `true` if the number is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.inner == ZERO
+    }
+
+    /// Returns `true` if the number is greater than zero.
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        self.inner > ZERO
+    }
+
+    /// Returns `true` if the number is less than zero.
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.inner < ZERO
+    }
+
+    /// Returns `true` if the number has no fractional part.
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.inner % Self::COEF == ZERO
+    }
+
+    /// Returns [`signum`](Self::signum) as a number, i.e. `-1`, `0` or `1`.
+    #[inline]
+    pub fn signum_fp(self) -> Self {
+        Self::from_bits(self.inner.signum() * Self::COEF)
+    }
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`.
+    /// Zero is considered positive. Returns `Err` on overflow.
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Result<Self> {
+        if (self.inner < ZERO) == (sign.inner < ZERO) {
+            Ok(self)
+        } else {
+            self.cneg()
+        }
+    }
+
+    /// Checked negation. Returns `Err` on overflow (you can't negate [`MIN` value][MIN]).
+    ///
+    /// [MIN]: ./ops/trait.Bounded.html#associatedconstant.MIN
+    #[inline]
+    pub fn cneg(self) -> Result<Self> {
+        self.inner
+            .checked_neg()
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Calculates `self + rhs`, returning the wrapped result along with a boolean
+    /// indicating whether an overflow has occurred.
+    #[inline]
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (inner, overflow) = self.inner.overflowing_add(rhs.inner);
+        (Self::from_bits(inner), overflow)
+    }
+
+    /// Calculates `self - rhs`, returning the wrapped result along with a boolean
+    /// indicating whether an overflow has occurred.
+    #[inline]
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (inner, overflow) = self.inner.overflowing_sub(rhs.inner);
+        (Self::from_bits(inner), overflow)
+    }
+
+    /// Calculates `self * rhs`, returning the wrapped result along with a boolean
+    /// indicating whether an overflow has occurred.
+    #[inline]
+    pub const fn overflowing_mul(self, rhs: I256) -> (Self, bool) {
+        let (inner, overflow) = self.inner.overflowing_mul(rhs);
+        (Self::from_bits(inner), overflow)
+    }
+
+    /// Negates the number, returning the wrapped result along with a boolean
+    /// indicating whether an overflow has occurred.
+    #[inline]
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        let (inner, overflow) = self.inner.overflowing_neg();
+        (Self::from_bits(inner), overflow)
+    }
+
+    /// Checked sum of all values. Returns `ZERO` for an empty iterator and `Err`
+    /// on the first overflow, so the rest of values isn't consumed.
+    #[inline]
+    pub fn csum(iter: impl IntoIterator<Item = Self>) -> Result<Self> {
+        iter.into_iter().try_fold(Self::ZERO, Self::cadd)
+    }
+
+    /// Returns the absolute value of a number.
+    #[inline]
+    pub fn abs(self) -> Result<Self> {
+        self.inner
+            .checked_abs()
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    /// Takes [rounded][RoundMode] integral part of the number.
+    #[inline]
+    pub fn integral(self, mode: RoundMode) -> I256 {
+        let sign = self.inner.signum();
+        let (mut int, frac) = (self.inner / Self::COEF, (self.inner % Self::COEF).abs());
+        let is_odd = int % I256::from_u8(2) != ZERO;
+
+        // `|int| < |MIN|` if `frac != 0`, thus it cannot overflow.
+        if frac != ZERO
+            && mode.is_away_from_zero(sign < ZERO, frac.cmp(&(Self::COEF - frac)), is_odd)
+        {
+            int += sign;
+        }
+
+        int
+    }
+
+    fn integral_fp(self, mode: RoundMode) -> Self {
+        let int = self.integral(mode);
+        // `checked_mul` of `I256` misses some overflows of negative products,
+        // so the range of integers is checked explicitly.
+        let fits = (I256::MIN / Self::COEF..=I256::MAX / Self::COEF).contains(&int);
+        // Rounding moves the value by less than `1`, thus the previous integer fits.
+        let int = if fits { int } else { int - int.signum() };
+        Self::from_bits(int * Self::COEF)
+    }
+
+    /// Returns the largest integer less than or equal to a number.
+    /// If the result doesn't fit (only possible near `MIN`), saturates to the nearest
+    /// representable integer, which is greater than the number in this case.
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.integral_fp(RoundMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to a number.
+    /// If the result doesn't fit (only possible near `MAX`), saturates to the nearest
+    /// representable integer, which is less than the number in this case.
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.integral_fp(RoundMode::Ceil)
+    }
+
+    /// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
+    /// If the result doesn't fit (only possible near `MIN` or `MAX`), saturates to the nearest
+    /// representable integer.
+    #[inline]
+    pub fn round(self) -> Self {
+        self.integral_fp(RoundMode::Nearest)
+    }
+
+    /// Returns `1/n`.
+    #[inline]
+    pub fn recip(self, mode: RoundMode) -> Result<Self> {
+        Self::ONE.rdiv(self, mode)
+    }
+
+    /// Constructs the [rounded][RoundMode] quotient of two integers.
+    #[inline]
+    pub fn from_ratio(numerator: I256, denominator: I256, mode: RoundMode) -> Result<Self> {
+        Self::from_bits(numerator).rdiv(Self::from_bits(denominator), mode)
+    }
+
+    /// Calculates `(a + b) / 2`, rounding the same as `(a + b).rdiv(2, mode)` would
+    /// if the sum didn't overflow.
+    ///
+    /// It never fails: the sum of halves is calculated instead of the halved sum,
+    /// and the result lies between `a` and `b`.
+    #[inline]
+    pub fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+        let (a, b) = (a.inner, b.inner);
+        let one = I256::from_u8(1);
+        // Arithmetic shifts round towards negative infinity, the lost halves are summed.
+        let floor = (a >> 1u32) + (b >> 1u32) + (a & b & one);
+        if (a ^ b) & one == ZERO {
+            return Self::from_bits(floor);
+        }
+
+        // The exact result is `floor + 0.5`, it's negative only if `floor` is.
+        let negative = floor.is_negative();
+        let truncated = if negative { floor + one } else { floor };
+        let is_odd = truncated & one != ZERO;
+
+        // Both `floor` and `floor + 1` lie between `a` and `b`.
+        if mode.is_away_from_zero(negative, Ordering::Equal, is_odd) {
+            Self::from_bits(if negative { floor } else { floor + one })
+        } else {
+            Self::from_bits(truncated)
+        }
+    }
+
+    /// Checked [rounding][RoundMode] square root. Returns `Err` for negative argument.
+    ///
+    /// The radicand is calculated in the polyfill for `u512`.
+    pub fn rsqrt(self, mode: RoundMode) -> Result<Self> {
+        if self.inner.is_negative() {
+            return Err(ArithmeticError::DomainViolation);
+        }
+
+        // `sqrt(S) * COEF = sqrt(S_inner * COEF)`, see the primitive layouts.
+        let squared = u512::mul(self.inner.unsigned_abs(), Self::COEF.unsigned_abs());
+        let lo = squared.sqrt();
+
+        // The radicand is less than `2 ^ 508`, thus `2lo + 1` is less than `2 ^ 255`
+        // and the distances to `lo^2` and `(lo+1)^2` can be compared modulo `2 ^ 256`.
+        let lo2 = lo.wrapping_mul(lo);
+        let hi2 = lo2
+            .wrapping_add(lo)
+            .wrapping_add(lo)
+            .wrapping_add(u256::from_u8(1));
+        let squared = squared.low();
+        let half = squared.wrapping_sub(lo2).cmp(&hi2.wrapping_sub(squared));
+
+        let is_odd = lo % u256::from_u8(2) != u256::MIN;
+        let add_one = lo2 != squared && mode.is_away_from_zero(false, half, is_odd);
+
+        let inner = if add_one { lo + u256::from_u8(1) } else { lo };
+
+        Ok(Self::from_bits(inner.cast_signed()))
+    }
+
+    /// Creates a new number from separate mantissa and exponent.
+    pub fn from_decimal(mantissa: I256, exponent: i32) -> Result<Self, ConvertError> {
+        if exponent < -Self::PRECISION || exponent > 10 {
+            return Err(ConvertError::new(
+                ConvertErrorKind::UnsupportedExponent,
+                "unsupported exponent",
+            ));
+        }
+
+        if mantissa == ZERO {
+            return Ok(Self::ZERO);
+        }
+
+        I256::from_u8(10)
+            .checked_pow((exponent + Self::PRECISION) as u32)
+            .and_then(|multiplier| mantissa.checked_mul(multiplier))
+            .map(Self::from_bits)
+            .ok_or_else(|| {
+                ConvertError::new(ConvertErrorKind::IntegralOverflow, "too big mantissa")
+            })
+    }
+
+    /// Returns a pair `(mantissa, exponent)` where `exponent`
+    /// is in `[-PRECISION, max_exponent]`.
+    ///
+    /// # Panics
+    /// If `max_exponent` is less than `-PRECISION`.
+    pub fn to_decimal(&self, max_exponent: i32) -> (I256, i32) {
+        assert!(max_exponent >= -Self::PRECISION);
+
+        if self.inner == ZERO {
+            return (ZERO, 0.min(max_exponent));
+        }
+
+        // A nonzero number has at most `MAX_PRECISION` trailing zeros.
+        let max_stripped = (i64::from(max_exponent) + i64::from(Self::PRECISION)) as u64;
+        let ten = I256::from_u8(10);
+        let (mut mantissa, mut stripped) = (self.inner, 0);
+        while stripped < max_stripped && mantissa % ten == ZERO {
+            mantissa /= ten;
+            stripped += 1;
+        }
+
+        (mantissa, stripped as i32 - Self::PRECISION)
+    }
+
+    /// Parses a string slice into a fixed point.
+    /// If the value cannot be represented then this will return an error.
+    ///
+    /// Use the `FromStr` instance to parse with rounding.
+    pub fn from_str_exact(str: &str) -> Result<Self, ConvertError> {
+        Self::parse_str::<true>(str)
+    }
+
+    fn parse_str<const EXACT: bool>(str: &str) -> Result<Self, ConvertError> {
+        let str = str.trim();
+        let (negative, str) = match str.as_bytes().first() {
+            Some(b'-') => (true, &str[1..]),
+            Some(b'+') => (false, &str[1..]),
+            _ => (false, str),
+        };
+
+        let (integral_str, fractional_str) = match str.split_once('.') {
+            Some((_, "")) => return Err(syntax_error("can't parse fractional part")),
+            Some(parts) => parts,
+            None => (str, ""),
+        };
+
+        let integral = parse_digits(integral_str)
+            .ok_or_else(|| syntax_error("can't parse integral part"))?
+            .ok_or_else(Self::integral_overflow)?;
+
+        if !fractional_str.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(syntax_error(
+                "can't parse fractional part: must contain digits only",
+            ));
+        }
+
+        let prec = Self::PRECISION as usize;
+        if EXACT && fractional_str.len() > prec {
+            return Err(ConvertError::fractional_precision(Self::PRECISION as u32));
+        }
+
+        let (fractional_str, extra) = if fractional_str.len() > prec {
+            let (fractional_str, extra) = fractional_str.split_at(prec);
+            (fractional_str, extra.as_bytes()[0])
+        } else {
+            (fractional_str, b'0')
+        };
+
+        // At most `PRECISION` digits, thus it fits.
+        let fractional = parse_digits(fractional_str)
+            .unwrap_or(Some(u256::MIN))
+            .ok_or_else(Self::integral_overflow)?;
+
+        let exp = u256::from_u8(10).pow((prec - fractional_str.len()) as u32);
+        let round = u256::from_u8((extra >= b'5') as u8);
+
+        integral
+            .checked_mul(Self::COEF.unsigned_abs())
+            .and_then(|integral| integral.checked_add(fractional * exp + round))
+            .and_then(|magnitude| Self::from_magnitude(negative, magnitude))
+            .ok_or_else(Self::integral_overflow)
+    }
+}
+
+/// Returns `None` for invalid syntax and `Some(None)` for too big numbers.
+fn parse_digits(digits: &str) -> Option<Option<u256>> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let ten = u256::from_u8(10);
+    Some(digits.bytes().try_fold(u256::MIN, |acc, digit| {
+        acc.checked_mul(ten)?
+            .checked_add(u256::from_u8(digit - b'0'))
+    }))
+}
+
+fn syntax_error(reason: &'static str) -> ConvertError {
+    ConvertError::new(ConvertErrorKind::InvalidSyntax, reason)
+}
+
+impl<P: Precision> Zero for FixedPoint<I256, P> {
+    const ZERO: Self = Self::from_bits(ZERO);
+}
+
+impl<P: Precision> One for FixedPoint<I256, P> {
+    const ONE: Self = Self::from_bits(Self::COEF);
+}
+
+impl<P: Precision> Bounded for FixedPoint<I256, P> {
+    const MIN: Self = Self::from_bits(I256::MIN);
+    const MAX: Self = Self::from_bits(I256::MAX);
+}
+
+// Required by default methods of traits in `ops`, e.g. `saturating_rdiv`.
+impl Zero for I256 {
+    const ZERO: Self = ZERO;
+}
+
+impl One for I256 {
+    const ONE: Self = I256::from_u8(1);
+}
+
+impl Bounded for I256 {
+    const MIN: Self = I256::MIN;
+    const MAX: Self = I256::MAX;
+}
+
+impl<P: Precision> CheckedAdd for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cadd(self, rhs: Self) -> Result<Self> {
+        self.inner
+            .checked_add(rhs.inner)
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn saturating_add(self, rhs: Self) -> Self::Output {
+        Self::from_bits(self.inner.saturating_add(rhs.inner))
+    }
+}
+
+impl<P: Precision> CheckedSub for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn csub(self, rhs: Self) -> Result<Self> {
+        self.inner
+            .checked_sub(rhs.inner)
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn saturating_sub(self, rhs: Self) -> Self::Output {
+        Self::from_bits(self.inner.saturating_sub(rhs.inner))
+    }
+}
+
+impl<P: Precision> CheckedMul<I256> for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cmul(self, rhs: I256) -> Result<Self> {
+        self.inner
+            .checked_mul(rhs)
+            .map(Self::from_bits)
+            .ok_or(ArithmeticError::Overflow)
+    }
+
+    #[inline]
+    fn saturating_mul(self, rhs: I256) -> Self::Output {
+        Self::from_bits(self.inner.saturating_mul(rhs))
+    }
+}
+
+impl<P: Precision> CheckedMul<FixedPoint<I256, P>> for I256 {
+    type Output = FixedPoint<I256, P>;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn cmul(self, rhs: FixedPoint<I256, P>) -> Result<FixedPoint<I256, P>> {
+        rhs.cmul(self)
+    }
+
+    #[inline]
+    fn saturating_mul(self, rhs: FixedPoint<I256, P>) -> Self::Output {
+        rhs.saturating_mul(self)
+    }
+}
+
+impl<P: Precision> RoundingMul for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rmul(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+        Self::mul_div(self.inner, rhs.inner, Self::COEF, mode)
+    }
+}
+
+impl<P: Precision> RoundingDiv for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: Self, mode: RoundMode) -> Result<Self> {
+        if rhs.inner == ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        Self::mul_div(self.inner, Self::COEF, rhs.inner, mode)
+    }
+}
+
+impl<P: Precision> RoundingDiv<I256> for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: I256, mode: RoundMode) -> Result<Self> {
+        if rhs == ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        Self::mul_div(self.inner, I256::from_u8(1), rhs, mode)
+    }
+}
+
+impl<P: Precision> RoundingDiv<FixedPoint<I256, P>> for I256 {
+    type Output = FixedPoint<I256, P>;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rdiv(self, rhs: FixedPoint<I256, P>, mode: RoundMode) -> Result<FixedPoint<I256, P>> {
+        let lhs = FixedPoint::<I256, P>::try_from(self).map_err(|_| ArithmeticError::Overflow)?;
+        lhs.rdiv(rhs, mode)
+    }
+}
+
+impl<P: Precision> RoundingMulDiv for FixedPoint<I256, P> {
+    type Output = Self;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn rmul_div(self, rhs: Self, divisor: Self, mode: RoundMode) -> Result<Self> {
+        if divisor.inner == ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        Self::mul_div(self.inner, rhs.inner, divisor.inner, mode)
+    }
+}
+
+impl<P: Precision> CheckedRem for FixedPoint<I256, P> {
+    type Output = Self;
+    type Quotient = I256;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn crem(self, rhs: Self) -> Result<Self> {
+        if rhs.inner == ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        // `MIN % -1` is `0`, but `checked_rem` considers it as overflow.
+        Ok(Self::from_bits(self.inner.wrapping_rem(rhs.inner)))
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Result<Self> {
+        if rhs.inner == ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        Ok(Self::from_bits(self.inner.wrapping_rem_euclid(rhs.inner)))
+    }
+
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Result<I256> {
+        if rhs.inner == ZERO {
+            return Err(ArithmeticError::DivisionByZero);
+        }
+
+        // Only `MIN / -1` overflows.
+        self.inner
+            .checked_div_euclid(rhs.inner)
+            .ok_or(ArithmeticError::Overflow)
+    }
+}
+
+impl<P: Precision> CheckedRem<I256> for FixedPoint<I256, P> {
+    type Output = Self;
+    type Quotient = I256;
+    type Error = ArithmeticError;
+
+    #[inline]
+    fn crem(self, rhs: I256) -> Result<Self> {
+        self.crem(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: I256) -> Result<Self> {
+        self.rem_euclid(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+    }
+
+    #[inline]
+    fn div_euclid(self, rhs: I256) -> Result<I256> {
+        self.div_euclid(Self::try_from(rhs).map_err(|_| ArithmeticError::Overflow)?)
+    }
+}
+
+impl<P: Precision> From<bool> for FixedPoint<I256, P> {
+    /// Converts `true` to `ONE` and `false` to `ZERO`.
+    #[inline]
+    fn from(value: bool) -> Self {
+        if value {
+            Self::ONE
+        } else {
+            Self::ZERO
+        }
+    }
+}
+
+fn to_f64(value: I256) -> f64 {
+    // `u128::MAX` is rounded to `2 ^ 128` exactly.
+    const TWO_POW_128: f64 = u128::MAX as f64;
+    let abs = value.unsigned_abs();
+    let abs = abs.high() as f64 * TWO_POW_128 + abs.low() as f64;
+    if value.is_negative() {
+        -abs
+    } else {
+        abs
+    }
+}
+
+impl<P: Precision> From<FixedPoint<I256, P>> for f64 {
+    fn from(value: FixedPoint<I256, P>) -> Self {
+        let coef = FixedPoint::<I256, P>::COEF;
+        let integral = to_f64(value.inner / coef);
+        let fractional = to_f64(value.inner % coef) / to_f64(coef);
+        integral + fractional
+    }
+}
+
+impl<P: Precision> crate::ops::sealed::Sealed for FixedPoint<I256, P> {}
+
+impl<P: Precision> FixedPointOps for FixedPoint<I256, P> {
+    type Layout = I256;
+
+    const PRECISION: i32 = Self::PRECISION;
+    const EPSILON: Self = Self::EPSILON;
+
+    #[inline]
+    fn from_bits(raw: I256) -> Self {
+        Self::from_bits(raw)
+    }
+
+    #[inline]
+    fn into_bits(self) -> I256 {
+        Self::into_bits(self)
+    }
+
+    #[inline]
+    fn rsqrt(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+        Self::rsqrt(self, mode)
+    }
+
+    #[inline]
+    fn recip(self, mode: RoundMode) -> Result<Self, ArithmeticError> {
+        Self::recip(self, mode)
+    }
+
+    #[inline]
+    fn from_ratio(
+        numerator: I256,
+        denominator: I256,
+        mode: RoundMode,
+    ) -> Result<Self, ArithmeticError> {
+        Self::from_ratio(numerator, denominator, mode)
+    }
+
+    #[inline]
+    fn half_sum(a: Self, b: Self, mode: RoundMode) -> Self {
+        Self::half_sum(a, b, mode)
+    }
+
+    #[inline]
+    fn integral(self, mode: RoundMode) -> I256 {
+        Self::integral(self, mode)
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        Self::floor(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Self::ceil(self)
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Self::round(self)
+    }
+
+    #[inline]
+    fn from_decimal(mantissa: I256, exponent: i32) -> Result<Self, ConvertError> {
+        Self::from_decimal(mantissa, exponent)
+    }
+
+    #[inline]
+    fn to_decimal(&self, max_exponent: i32) -> (I256, i32) {
+        Self::to_decimal(self, max_exponent)
+    }
+}
+
+impl<P: Precision> FromStr for FixedPoint<I256, P> {
+    type Err = ConvertError;
+
+    /// Parses a string slice into a fixed point.
+    /// If the value cannot be represented, it will be rounded to the nearest value.
+    ///
+    /// Use `from_str_exact` to parse without rounding.
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Self::parse_str::<false>(str)
+    }
+}
+
+impl<P: Precision> fmt::Display for FixedPoint<I256, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let magnitude = self.inner.unsigned_abs();
+        let coef = Self::COEF.unsigned_abs();
+
+        if self.inner.is_negative() {
+            f.write_str("-")?;
+        }
+
+        write!(f, "{}.", magnitude / coef)?;
+
+        let mut fractional = magnitude % coef;
+        if fractional == u256::MIN {
+            return f.write_str("0");
+        }
+
+        let ten = u256::from_u8(10);
+        let mut buf = [b'0'; MAX_PRECISION];
+        let digits = &mut buf[..Self::PRECISION as usize];
+        for digit in digits.iter_mut().rev() {
+            *digit = b'0' + (fractional % ten).as_u8();
+            fractional /= ten;
+        }
+
+        let digits = core::str::from_utf8(digits).expect("only ASCII digits");
+        f.write_str(digits.trim_end_matches('0'))
+    }
+}
+
+impl<P: Precision> fmt::Debug for FixedPoint<I256, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+macro_rules! impl_try_from {
+    ($($int:ty),*) => {
+        $(
+            impl<P: Precision> TryFrom<$int> for FixedPoint<I256, P> {
+                type Error = ConvertError;
+
+                fn try_from(value: $int) -> Result<Self, Self::Error> {
+                    I256::from(value)
+                        .checked_mul(Self::COEF)
+                        .map(Self::from_bits)
+                        .ok_or_else(Self::integral_overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from!(i8, u8, i16, u16, i32, u32, i64, u64, i128, u128, I256);
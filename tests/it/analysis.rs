@@ -0,0 +1,75 @@
+use anyhow::Result;
+
+use fixnum::{
+    analysis::{self, Reference},
+    ops::{RoundMode::*, *},
+    *,
+};
+
+#[test]
+fn rounding_error_of_rmul_and_rdiv() -> Result<()> {
+    test_fixed_point! {
+        // Bounds are in halves of `EPSILON`.
+        case (mode: RoundMode, min: i32, max: i32) => {
+            let epsilon = analysis::to_reference(FixedPoint::EPSILON).unwrap();
+            let half = Reference::from_bits(epsilon.into_bits() / I256::from(2));
+            let (min, max) = (half.cmul(I256::from(min))?, half.cmul(I256::from(max))?);
+
+            let values = (1..100).map(|i: Layout| FixedPoint::from_bits(i * i * 7_919 - 1_000_003));
+            let inputs = values.clone().flat_map(|a| values.clone().map(move |b| [a, b]));
+
+            let mul = analysis::rounding_error(
+                |[a, b]| a.rmul(b, mode),
+                |[a, b]| a.rmul(b, mode),
+                inputs.clone(),
+            );
+            assert!(mul.measured > 0);
+            assert!(min <= mul.min && mul.max <= max, "{:?}", mul);
+
+            let div = analysis::rounding_error(
+                |[a, b]| a.rdiv(b, mode),
+                |[a, b]| a.rdiv(b, mode),
+                inputs,
+            );
+            assert!(div.measured > 0);
+            assert!(min <= div.min && div.max <= max, "{:?}", div);
+        },
+        all {
+            (Floor, -2, 0);
+            (Ceil, 0, 2);
+            (Nearest, -1, 1);
+            (TowardZero, -2, 2);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn distribution() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let inputs = [[fp!(1)], [fp!(2)], [FixedPoint::MAX], [fp!(3)]];
+            // `x + EPSILON` with the reference `x`, so every error is `EPSILON`.
+            let distribution = analysis::rounding_error(
+                |[x]| x.cadd(FixedPoint::EPSILON),
+                |[x]| Ok::<_, ArithmeticError>(x),
+                inputs,
+            );
+            let epsilon = analysis::to_reference(FixedPoint::EPSILON).unwrap();
+
+            assert_eq!((distribution.measured, distribution.failed), (3, 1));
+            assert_eq!((distribution.min, distribution.max), (epsilon, epsilon));
+            assert_eq!(distribution.max_abs(), epsilon);
+            assert_eq!(distribution.mean(), Some(epsilon));
+
+            let empty = analysis::rounding_error(
+                |[x]: [FixedPoint; 1]| x.cneg(),
+                |[x]: [Reference; 1]| x.cneg(),
+                [],
+            );
+            assert_eq!(empty.measured, 0);
+            assert_eq!(empty.mean(), None);
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::cell::RefCell;
+
+use anyhow::Result;
+use typenum::{U2, U9};
+
+use fixnum::{
+    audit::{self, Conversion, Direction},
+    FixedPoint,
+};
+
+type Fp2 = FixedPoint<i64, U2>;
+type Fp64 = FixedPoint<i64, U9>;
+
+thread_local! {
+    // Other tests convert floats concurrently, so only conversions of this thread are checked.
+    static RECORDED: RefCell<Vec<Conversion>> = const { RefCell::new(Vec::new()) };
+}
+
+fn recorded() -> Vec<(Direction, i32, f64, bool)> {
+    RECORDED.with(|recorded| {
+        recorded
+            .borrow_mut()
+            .drain(..)
+            .map(|c| (c.direction, c.precision, c.float, c.is_lossy()))
+            .collect()
+    })
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn hook_and_summary() -> Result<()> {
+    audit::set_hook(|conversion| RECORDED.with(|r| r.borrow_mut().push(*conversion)));
+
+    assert_eq!(Fp2::try_from(0.25)?, "0.25".parse()?);
+    assert_eq!(Fp2::try_from(0.125)?, "0.13".parse()?);
+    assert!(Fp2::try_from(f64::NAN).is_err());
+    assert_eq!(
+        recorded(),
+        [
+            (Direction::FromF64, 2, 0.25, false),
+            (Direction::FromF64, 2, 0.125, true),
+        ]
+    );
+
+    assert_eq!(f64::from(Fp64::try_from(1.5)?), 1.5);
+    let big: Fp64 = "8003332421.536753168".parse()?;
+    assert_eq!(f64::from(big), 8003332421.536753);
+    assert_eq!(
+        recorded(),
+        [
+            (Direction::FromF64, 9, 1.5, false),
+            (Direction::ToF64, 9, 1.5, false),
+            (Direction::ToF64, 9, 8003332421.536753, true),
+        ]
+    );
+
+    let summary = audit::summary();
+    assert!(summary.from_f64.lossy >= 1);
+    assert!(summary.to_f64.lossy >= 1);
+    assert!(summary.from_f64.max_loss >= 0.005);
+    assert!(summary.to_f64.max_loss >= 0.000000168);
+    assert!(summary.to_string().contains(" lossy, max loss "));
+
+    audit::remove_hook();
+    let _ = f64::from(big);
+    assert_eq!(recorded(), []);
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use typenum::{U0, U32, U62};
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, Binary, FixedPoint,
+};
+
+type Q32 = FixedPoint<i64, Binary<U32>>;
+
+fn q32(value: f64) -> Q32 {
+    Q32::from_bits((value * (1u64 << 32) as f64) as i64)
+}
+
+#[test]
+fn constants() -> Result<()> {
+    assert_eq!(Q32::FRACTIONAL_BITS, 32);
+    assert_eq!(Q32::ONE.into_bits(), 1 << 32);
+    assert_eq!(Q32::EPSILON.into_bits(), 1);
+    assert_eq!(FixedPoint::<i64, Binary<U0>>::ONE.into_bits(), 1);
+    assert_eq!(FixedPoint::<i64, Binary<U62>>::ONE.into_bits(), 1 << 62);
+    assert_eq!(Q32::try_from(3)?, q32(3.));
+    assert!(Q32::try_from(i64::MAX >> 31).is_err());
+    Ok(())
+}
+
+#[test]
+fn arithmetic() -> Result<()> {
+    let a = q32(1.5);
+    let b = q32(-0.25);
+
+    assert_eq!(a.cadd(b)?, q32(1.25));
+    assert_eq!(a.csub(b)?, q32(1.75));
+    assert_eq!(a.cmul(-3)?, q32(-4.5));
+    assert_eq!(a.rmul(b, Floor)?, q32(-0.375));
+    assert_eq!(a.rdiv(b, Floor)?, q32(-6.));
+    assert_eq!(a.rdiv(4, Floor)?, q32(0.375));
+    assert_eq!(b.cneg()?, q32(0.25));
+    assert_eq!(b.abs()?, q32(0.25));
+
+    assert_eq!(Q32::MAX.cadd(Q32::EPSILON), Err(ArithmeticError::Overflow));
+    assert_eq!(Q32::MIN.cneg(), Err(ArithmeticError::Overflow));
+    assert_eq!(
+        Q32::MAX.rmul(q32(2.), Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        a.rdiv(Q32::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(Q32::MAX.saturating_add(Q32::ONE), Q32::MAX);
+    assert_eq!(Q32::MIN.saturating_rmul(q32(2.), Floor), Q32::MIN);
+    Ok(())
+}
+
+#[test]
+fn rounding() -> Result<()> {
+    let half_epsilon = q32(0.5);
+    let x = Q32::from_bits(3);
+
+    // `3 * 2^-32 * 0.5` is exactly between `EPSILON` and `2 * EPSILON`.
+    for (mode, positive, negative) in [
+        (Floor, 1, -2),
+        (Ceil, 2, -1),
+        (TowardZero, 1, -1),
+        (AwayFromZero, 2, -2),
+        (Nearest, 2, -2),
+        (HalfEven, 2, -2),
+    ] {
+        assert_eq!(
+            x.rmul(half_epsilon, mode)?.into_bits(),
+            positive,
+            "{:?}",
+            mode
+        );
+        assert_eq!(
+            x.cneg()?.rmul(half_epsilon, mode)?.into_bits(),
+            negative,
+            "{:?}",
+            mode
+        );
+    }
+
+    assert_eq!(Q32::ONE.rdiv(q32(3.), Floor)?.into_bits(), 0x5555_5555);
+    assert_eq!(Q32::ONE.rdiv(q32(3.), Ceil)?.into_bits(), 0x5555_5556);
+    assert_eq!(Q32::ONE.rdiv(q32(-3.), Floor)?.into_bits(), -0x5555_5556);
+
+    assert_eq!(q32(2.5).integral(Floor), 2);
+    assert_eq!(q32(2.5).integral(Nearest), 3);
+    assert_eq!(q32(2.5).integral(HalfEven), 2);
+    assert_eq!(q32(-2.5).integral(Floor), -3);
+    assert_eq!(q32(-2.5).integral(Ceil), -2);
+    Ok(())
+}
+
+#[test]
+fn display() {
+    for (value, expected) in [
+        (Q32::ZERO, "0.0"),
+        (q32(1.), "1.0"),
+        (q32(-2.75), "-2.75"),
+        (Q32::EPSILON, "0.00000000023283064365386962890625"),
+        (Q32::MAX, "2147483647.99999999976716935634613037109375"),
+        (Q32::MIN, "-2147483648.0"),
+    ] {
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(format!("{:?}", value), expected);
+    }
+
+    #[cfg(feature = "i128")]
+    {
+        type Q126 = FixedPoint<i128, Binary<typenum::U126>>;
+        assert_eq!(Q126::ONE.to_string(), "1.0");
+        assert_eq!(Q126::MIN.to_string(), "-2.0");
+        assert_eq!(Q126::EPSILON.to_string().len(), "0.".len() + 126);
+    }
+}
+
+#[test]
+fn to_f64() {
+    assert_eq!(f64::from(q32(-2.75)), -2.75);
+    assert_eq!(f64::from(Q32::EPSILON), 2f64.powi(-32));
+}
@@ -0,0 +1,50 @@
+use anyhow::Result;
+
+use fixnum::{book::Ladder, ops::*, *};
+
+#[test]
+fn ladder() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(Ladder::<_, _, u32>::new(fp!(0)).unwrap_err(), ArithmeticError::DomainViolation);
+            assert_eq!(Ladder::<_, _, u32>::new(fp!(0.5).cneg()?).unwrap_err(), ArithmeticError::DomainViolation);
+
+            let mut ladder = Ladder::new(fp!(0.05))?;
+            assert_eq!(ladder.tick(), fp!(0.05));
+            assert!(ladder.is_empty());
+            assert_eq!((ladder.best_bid(), ladder.best_ask()), (None, None));
+
+            assert_eq!(ladder.insert(fp!(10.05), 1)?, None);
+            assert_eq!(ladder.insert(fp!(9.95), 2)?, None);
+            assert_eq!(ladder.insert(fp!(10.2), 3)?, None);
+            assert_eq!(ladder.insert(fp!(10.05), 4)?, Some(1));
+            assert_eq!(ladder.insert(fp!(10.01), 5), Err(ArithmeticError::DomainViolation));
+            assert_eq!(ladder.insert(fp!(0.05).cneg()?, 6)?, None);
+            assert!(!ladder.is_aligned(fp!(0.000000001)));
+            assert!(ladder.is_aligned(fp!(0)));
+            assert_eq!(ladder.len(), 4);
+
+            assert_eq!(ladder.best_bid(), Some((fp!(10.2), &3)));
+            assert_eq!(ladder.best_ask(), Some((fp!(0.05).cneg()?, &6)));
+            assert_eq!(ladder.get(fp!(10.05)), Some(&4));
+            *ladder.get_mut(fp!(10.05)).unwrap() += 1;
+            assert_eq!(ladder.remove(fp!(10.05)), Some(5));
+            assert_eq!(ladder.remove(fp!(10.05)), None);
+
+            let around = |mid, width| ladder.range_around(mid, width).map(|(price, _)| price).collect::<Vec<_>>();
+            assert_eq!(around(fp!(10), fp!(0.2)), [fp!(9.95), fp!(10.2)]);
+            assert_eq!(around(fp!(10), fp!(0.19)), [fp!(9.95)]);
+            assert_eq!(around(fp!(9.95), fp!(0)), [fp!(9.95)]);
+            assert_eq!(around(fp!(10), fp!(0.2).cneg()?), []);
+            assert_eq!(around(FixedPoint::MAX, FixedPoint::MAX).len(), 2);
+            assert_eq!(around(fp!(0), FixedPoint::MAX).len(), 3);
+            assert_eq!(ladder.range_around(fp!(10), fp!(1)).next_back(), Some((fp!(10.2), &3)));
+
+            let prices: Vec<_> = ladder.iter().map(|(price, _)| price).collect();
+            assert_eq!(prices, [fp!(0.05).cneg()?, fp!(9.95), fp!(10.2)]);
+            assert_eq!(ladder.as_map().len(), 3);
+            assert_eq!(ladder.into_map().into_iter().next(), Some((fp!(0.05).cneg()?, 6)));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use clap::{error::ErrorKind, Arg, Command};
+use fixnum::{clap::value_parser, typenum::U2, FixedPoint};
+
+type Price = FixedPoint<i64, U2>;
+
+fn p(s: &str) -> Price {
+    s.parse().unwrap()
+}
+
+fn command() -> Command {
+    Command::new("quote").arg(
+        Arg::new("price")
+            .long("price")
+            .allow_negative_numbers(true)
+            .value_parser(value_parser().min(p("-10")).max(p("1000")).tick(p("0.25"))),
+    )
+}
+
+fn parse(value: &str) -> Result<Price, String> {
+    command()
+        .try_get_matches_from(["quote", "--price", value])
+        .map(|matches| *matches.get_one::<Price>("price").unwrap())
+        .map_err(|err| {
+            assert_eq!(err.kind(), ErrorKind::ValueValidation);
+            err.to_string()
+        })
+}
+
+#[test]
+fn accepted() {
+    assert_eq!(parse("100.25"), Ok(p("100.25")));
+    assert_eq!(parse("-10"), Ok(p("-10")));
+    assert_eq!(parse("1000.00"), Ok(p("1000")));
+    assert_eq!(parse("0"), Ok(p("0")));
+}
+
+#[test]
+fn rejected() {
+    let error =
+        |value, reason| format!("error: invalid value '{value}' for '--price <price>': {reason}\n");
+
+    assert_eq!(
+        parse("1.125"),
+        Err(error("1.125", "expected at most 2 decimal places, got 3"))
+    );
+    assert_eq!(
+        parse("-10.25"),
+        Err(error("-10.25", "expected at least -10.0, got -10.25"))
+    );
+    assert_eq!(
+        parse("1000.25"),
+        Err(error("1000.25", "expected at most 1000.0, got 1000.25"))
+    );
+    assert_eq!(
+        parse("100.3"),
+        Err(error("100.3", "expected a multiple of 0.25, got 100.3"))
+    );
+    assert_eq!(
+        parse("1.2.3"),
+        Err(error(
+            "1.2.3",
+            "can't parse fractional part: must contain digits only"
+        ))
+    );
+}
+
+#[test]
+fn unrestricted() {
+    let command = Command::new("quote").arg(
+        Arg::new("price")
+            .allow_negative_numbers(true)
+            .value_parser(value_parser::<i64, U2>()),
+    );
+    let matches = command.try_get_matches_from(["quote", "-0.01"]).unwrap();
+    assert_eq!(matches.get_one::<Price>("price"), Some(&p("-0.01")));
+}
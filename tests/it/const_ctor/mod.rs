@@ -1,3 +1,8 @@
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::String, vec::Vec};
+
 #[cfg(feature = "i64")]
 #[test]
 fn valid() {
@@ -14,8 +19,183 @@ fn valid() {
     assert_eq!(SAMPLE2, F64p9::from_decimal(42, 0).unwrap());
 }
 
+/// Checks that `fixnum!` and `from_str_exact` parse literals to the same bits.
+macro_rules! check_literals {
+    ($precision:literal = $typenum:ident: $($literal:literal),* $(,)?) => {{
+        type F = fixnum::FixedPoint<Layout, typenum::$typenum>;
+        $(
+            let expected = F::from_str_exact(stringify!($literal)).unwrap();
+            let actual: F = fixnum::fixnum!($literal, $precision);
+            assert_eq!(actual, expected, "fixnum!({})", stringify!($literal));
+        )*
+    }};
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn literals_i64() {
+    type Layout = i64;
+    check_literals!(9 = U9:
+        0, 0.0, -0.0, 1, -1, 0.1, -0.1, 0.000000001, -0.000000001, 0.5, 0.05, 0.000000005,
+        1.000000001, 10, 100.100, 42.42, 1000000000, 123456789.123456789,
+        -123456789.123456789, 999999999.999999999, 9223372036, -9223372036,
+        9223372036.854775807, -9223372036.854775808,
+    );
+    check_literals!(0 = U0: 0, 1, -1, 9223372036854775807, -9223372036854775808);
+    check_literals!(18 = U18: 0.000000000000000001, 9.223372036854775807, -9.223372036854775808);
+}
+
+#[cfg(feature = "i128")]
+#[test]
+fn literals_i128() {
+    type Layout = i128;
+    check_literals!(18 = U18:
+        0, 0.0, -0.0, 1, -1, 0.000000000000000001, -0.000000000000000001, 0.5, 42.42,
+        123456789.123456789123456789, 9223372036.854775808, -9223372036.854775809,
+        170141183460469231731.687303715884105727, -170141183460469231731.687303715884105728,
+    );
+    check_literals!(0 = U0: 0, 1, -1, 170141183460469231731687303715884105727);
+    check_literals!(38 = U38: 1.70141183460469231731687303715884105727);
+}
+
+#[test]
+fn differential() -> anyhow::Result<()> {
+    test_fixed_point! {
+        case () => {
+            // Boundaries and digits of every length, including many zeros.
+            let mut integrals: Vec<String> = [
+                "42", "123456789", "9223372036", "170141183460469231731", "007",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect();
+            let mut fractionals: Vec<String> = [
+                "", "05", "50", "123456789", "854775807", "854775808", "687303715884105727",
+                "687303715884105728",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+            for len in 1..=21 {
+                integrals.push("0".repeat(len));
+                integrals.push(format!("1{}", "0".repeat(len - 1)));
+                integrals.push(format!("5{}", "0".repeat(len - 1)));
+                integrals.push("9".repeat(len));
+            }
+            for len in 1..=18 {
+                fractionals.push("0".repeat(len));
+                fractionals.push(format!("{}1", "0".repeat(len - 1)));
+                fractionals.push(format!("{}5", "0".repeat(len - 1)));
+                fractionals.push(format!("1{}", "0".repeat(len - 1)));
+                fractionals.push("9".repeat(len));
+            }
+
+            let coef = fixnum::_priv::pow10(FixedPoint::PRECISION);
+            let mut checked = 0;
+
+            for sign in ["", "-", "+"] {
+                for integral in &integrals {
+                    for fractional in &fractionals {
+                        for point in ["", "."] {
+                            if point.is_empty() && !fractional.is_empty() {
+                                continue;
+                            }
+
+                            let input = format!("{}{}{}{}", sign, integral, point, fractional);
+                            let expected = match FixedPoint::from_str_exact(&input) {
+                                Ok(expected) => expected,
+                                Err(_) => continue,
+                            };
+
+                            let actual = fixnum::_priv::parse_fixed(&input, coef) as Layout;
+                            assert_eq!(actual, expected.into_bits(), "{}", input);
+                            checked += 1;
+                        }
+                    }
+                }
+            }
+
+            assert!(checked > 1000, "too few literals: {}", checked);
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn round() {
+    use fixnum::{fixnum_round, FixedPoint};
+    type F64p2 = FixedPoint<i64, typenum::U2>;
+
+    let value = |s: &str| s.parse::<F64p2>().unwrap();
+
+    let floor: F64p2 = fixnum_round!(1.125, 2, Floor);
+    let ceil: F64p2 = fixnum_round!(1.125, 2, Ceil);
+    let nearest: F64p2 = fixnum_round!(1.125, 2, Nearest);
+    let half_even: F64p2 = fixnum_round!(1.125, 2, HalfEven);
+    assert_eq!(
+        [floor, ceil, nearest, half_even],
+        [value("1.12"), value("1.13"), value("1.13"), value("1.12")]
+    );
+
+    let floor: F64p2 = fixnum_round!(-1.125, 2, Floor);
+    let toward_zero: F64p2 = fixnum_round!(-1.125, 2, TowardZero);
+    let away_from_zero: F64p2 = fixnum_round!(-1.121, 2, AwayFromZero);
+    assert_eq!(
+        [floor, toward_zero, away_from_zero],
+        [value("-1.13"), value("-1.12"), value("-1.13")]
+    );
+
+    // Representable literals are left as is.
+    let exact: F64p2 = fixnum_round!(42.4, 2, Ceil);
+    assert_eq!(exact, value("42.4"));
+    let zeros: F64p2 = fixnum_round!(42.4000, 2, Ceil);
+    assert_eq!(zeros, value("42.4"));
+}
+
 #[test]
 fn too_long_fractional() {
     let test_cases = trybuild::TestCases::new();
     test_cases.compile_fail("tests/it/const_ctor/too_long_fractional.rs");
 }
+
+#[cfg(feature = "i64")]
+#[test]
+fn type_alias() {
+    use fixnum::{fixnum_type, FixedPoint};
+
+    fixnum_type! {
+        /// Some amount.
+        type Amount = FixedPoint<i64, U9>;
+    }
+    fixnum_type!(type Integer = FixedPoint<i64, U0>);
+
+    const MAX: &str = Amount::MAX_STR;
+    assert_eq!(MAX, "9223372036.854775807");
+    assert_eq!(Amount::EPSILON_STR, "0.000000001");
+    assert_eq!(Integer::MAX_STR, "9223372036854775807.0");
+    assert_eq!(Integer::EPSILON_STR, "1.0");
+
+    let _: FixedPoint<i64, typenum::U9> = Amount::EPSILON;
+    let _: FixedPoint<i64, typenum::U0> = Integer::EPSILON;
+    assert_eq!(Amount::PRECISION, 9);
+    assert_eq!(Integer::PRECISION, 0);
+}
+
+#[cfg(all(feature = "u64", feature = "i256"))]
+#[test]
+fn type_alias_of_any_layout() {
+    use fixnum::{fixnum_type, I256};
+
+    fixnum_type!(type WideUnsigned = FixedPoint<u64, U19>);
+    fixnum_type!(type Wide = FixedPoint<I256, U76>);
+
+    assert_eq!(WideUnsigned::MAX_STR, "1.8446744073709551615");
+    assert_eq!(WideUnsigned::EPSILON_STR, "0.0000000000000000001");
+    assert_eq!(
+        Wide::MAX_STR,
+        "5.7896044618658097711785492504343953926634992332820282019728792003956564819967"
+    );
+    assert_eq!(Wide::EPSILON_STR, Wide::EPSILON.to_string());
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 #[cfg(feature = "i128")]
 use proptest::prelude::*;
 
-use fixnum::ops::Bounded;
+use fixnum::ops::{Bounded, Zero};
 
 #[test]
 fn from_decimal() -> Result<()> {
@@ -10,6 +10,7 @@ fn from_decimal() -> Result<()> {
         case (input: (Layout, i32), expected: FixedPoint) => {
             assert_eq!(FixedPoint::from_decimal(input.0, input.1)?, expected);
             assert_eq!(FixedPoint::from_decimal(-input.0, input.1)?, expected.cneg().unwrap());
+            assert_eq!(FixedPoint::try_from(input)?, expected);
         },
         all {
             ((5_000_000_000, -9), fp!(5));
@@ -34,6 +35,10 @@ fn to_decimal() -> Result<()> {
             let (mantissa, exponent) = fp.cneg().unwrap().to_decimal(max_exponent);
             assert!(exponent <= max_exponent);
             assert_eq!((mantissa, exponent), (-expected.0, expected.1));
+
+            let (mantissa, exponent, stripped) = fp.to_decimal_stripped(max_exponent);
+            assert_eq!((mantissa, exponent), expected);
+            assert_eq!(stripped as i32, exponent + FixedPoint::PRECISION);
         },
         all {
             (fp!(0), 0, (0, 0));
@@ -49,8 +54,425 @@ fn to_decimal() -> Result<()> {
             (fp!(5.5), 5, (55, -1));
             (fp!(5.5), -1, (55, -1));
             (fp!(5.5), -2, (550, -2));
+            (fp!(1000000000), i32::MAX, (1, 9));
+            (fp!(1000000000), 5, (10000, 5));
             (FixedPoint::MAX, 0, (FixedPoint::MAX.into_bits(), -FixedPoint::PRECISION))
         },
+        fp128 {
+            (fp!(100000000000000000000), i32::MAX, (1, 20));
+            (fp!(100000000000000000000), 5, (1000000000000000, 5));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn slice_bits() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], bits: &[Layout]) => {
+            assert_eq!(FixedPoint::slice_as_bits(values), bits);
+            assert_eq!(FixedPoint::slice_from_bits(bits), values);
+        },
+        all {
+            (&[], &[]);
+            (&[FixedPoint::MIN, fp!(0), FixedPoint::MAX], &[Layout::MIN, 0, Layout::MAX]);
+        },
+        fp64 {
+            (&[fp!(1), fp!(-0.5)], &[1_000_000_000, -500_000_000]);
+        },
+        fp128 {
+            (&[fp!(1), fp!(-0.5)], &[1_000_000_000_000_000_000, -500_000_000_000_000_000]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn wrapping_and_saturating() -> Result<()> {
+    use core::num::{Saturating, Wrapping};
+
+    test_fixed_point! {
+        case (value: FixedPoint, bits: Layout) => {
+            assert_eq!(Wrapping::from(value), Wrapping(bits));
+            assert_eq!(FixedPoint::from(Wrapping(bits)), value);
+            assert_eq!(Saturating::from(value), Saturating(bits));
+            assert_eq!(FixedPoint::from(Saturating(bits)), value);
+        },
+        all {
+            (fp!(0), 0);
+            (FixedPoint::EPSILON, 1);
+            (FixedPoint::MIN, Layout::MIN);
+            (FixedPoint::MAX, Layout::MAX);
+        },
+        fp64 {
+            (fp!(-1.5), -1_500_000_000);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "const-generics")]
+fn const_precision() -> Result<()> {
+    use fixnum::ConstFixedPoint;
+
+    test_fixed_point! {
+        case (value: FixedPoint) => {
+            type Const = ConstFixedPoint<Layout, { FixedPoint::PRECISION as usize }>;
+            let same: Const = value;
+            assert_eq!(Const::PRECISION, FixedPoint::PRECISION);
+            assert_eq!(same.to_string().parse::<Const>()?, value);
+        },
+        all {
+            (fp!(0));
+            (fp!(-1.5));
+            (FixedPoint::EPSILON);
+            (FixedPoint::MIN);
+            (FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rescale() -> Result<()> {
+    use fixnum::{
+        ops::{
+            One,
+            RoundMode::{self, *},
+        },
+        typenum::U2,
+        ArithmeticError,
+    };
+
+    test_fixed_point! {
+        case (value: FixedPoint, mode: RoundMode, expected: &str) => {
+            let cents: fixnum::FixedPoint<Layout, U2> = value.rescale(mode)?;
+            assert_eq!(cents.to_string(), expected);
+            // Increasing the precision is exact.
+            let back: FixedPoint = cents.rescale(Floor)?;
+            assert_eq!(back.to_string(), expected);
+        },
+        all {
+            (fp!(1.234), Floor, "1.23");
+            (fp!(1.235), Nearest, "1.24");
+            (fp!(1.231), Ceil, "1.24");
+            (fp!(-1.235), Nearest, "-1.24");
+            (fp!(-1.239), Ceil, "-1.23");
+            (fp!(5), Floor, "5.0");
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let divisor = FixedPoint::ONE.into_bits() / 100;
+            let cents = FixedPoint::MAX.rescale::<U2>(Floor)?;
+            assert_eq!(cents.into_bits(), Layout::MAX / divisor);
+            assert_eq!(
+                fixnum::FixedPoint::<Layout, U2>::MAX.rescale::<typenum::U9>(Floor),
+                Err(ArithmeticError::Overflow)
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn parts() -> Result<()> {
+    use fixnum::{ops::One, ConvertErrorKind};
+
+    test_fixed_point! {
+        case (value: FixedPoint, expected: (bool, u128, u128)) => {
+            assert_eq!(value.to_parts(), expected);
+            assert_eq!(FixedPoint::from_parts(expected.0, expected.1, expected.2)?, value);
+        },
+        all {
+            (fp!(0), (false, 0, 0));
+            (fp!(5), (false, 5, 0));
+            (fp!(-5), (true, 5, 0));
+            (FixedPoint::EPSILON, (false, 0, 1));
+        },
+        fp64 {
+            (fp!(5.25), (false, 5, 250_000_000));
+            (fp!(-0.5), (true, 0, 500_000_000));
+            (FixedPoint::MAX, (false, 9_223_372_036, 854_775_807));
+            (FixedPoint::MIN, (true, 9_223_372_036, 854_775_808));
+        },
+        fp128 {
+            (fp!(5.25), (false, 5, 250_000_000_000_000_000));
+            (fp!(-0.5), (true, 0, 500_000_000_000_000_000));
+            (FixedPoint::MAX, (false, 170_141_183_460_469_231_731, 687_303_715_884_105_727));
+            (FixedPoint::MIN, (true, 170_141_183_460_469_231_731, 687_303_715_884_105_728));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let coef = FixedPoint::ONE.into_bits() as u128;
+            let (_, integral, fractional) = FixedPoint::MAX.to_parts();
+            let kind = |parts: (bool, u128, u128)| {
+                FixedPoint::from_parts(parts.0, parts.1, parts.2).unwrap_err().kind()
+            };
+
+            assert_eq!(FixedPoint::from_parts(true, 0, 0)?, FixedPoint::ZERO);
+            assert_eq!(kind((false, 0, coef)), ConvertErrorKind::OutOfDomain);
+            assert_eq!(kind((true, 0, u128::MAX)), ConvertErrorKind::OutOfDomain);
+            assert_eq!(kind((false, integral, fractional + 1)), ConvertErrorKind::IntegralOverflow);
+            assert_eq!(kind((true, integral, fractional + 2)), ConvertErrorKind::IntegralOverflow);
+            assert_eq!(kind((false, u128::MAX, 0)), ConvertErrorKind::IntegralOverflow);
+            assert_eq!(kind((true, u128::MAX / coef, coef - 1)), ConvertErrorKind::IntegralOverflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn try_rescale() -> Result<()> {
+    use fixnum::typenum::U2;
+
+    test_fixed_point! {
+        case (value: FixedPoint, expected: Option<&str>) => {
+            let result = value.try_rescale::<U2>();
+            match expected {
+                Some(expected) => {
+                    let cents = result?;
+                    assert_eq!(cents.to_string(), expected);
+                    assert_eq!(cents.try_rescale()?, value);
+                    assert_eq!(cents.rescale(fixnum::ops::RoundMode::Floor)?, value);
+                }
+                None => assert!(result.unwrap_err().is_fractional_precision()),
+            }
+        },
+        all {
+            (fp!(0), Some("0.0"));
+            (fp!(1.23), Some("1.23"));
+            (fp!(-1.2), Some("-1.2"));
+            (fp!(5), Some("5.0"));
+            (fp!(1.231), None);
+            (fp!(-1.239), None);
+            (FixedPoint::EPSILON, None);
+            (FixedPoint::MAX, None);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let cents = fixnum::FixedPoint::<Layout, U2>::MAX;
+            assert!(cents.try_rescale::<typenum::U9>().unwrap_err().is_integral_overflow());
+            assert_eq!(FixedPoint::MAX.try_rescale::<typenum::U9>().is_ok(), FixedPoint::PRECISION <= 9);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "i128", feature = "std"))]
+fn widen() -> Result<()> {
+    use fixnum::{typenum::U9, FixedPoint};
+
+    for value in [
+        "0",
+        "-1.5",
+        "0.000000001",
+        "9223372036.854775807",
+        "-9223372036.854775808",
+    ] {
+        let narrow: FixedPoint<i64, U9> = value.parse()?;
+        let wide: FixedPoint<i128, U9> = narrow.widen();
+        assert_eq!(wide, value.parse()?);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "i128", feature = "std"))]
+fn narrow() -> Result<()> {
+    use fixnum::{typenum::U9, ConvertErrorKind, FixedPoint};
+
+    for value in [
+        "0",
+        "-1.5",
+        "0.000000001",
+        "9223372036.854775807",
+        "-9223372036.854775808",
+    ] {
+        let wide: FixedPoint<i128, U9> = value.parse()?;
+        let narrow = FixedPoint::<i64, U9>::try_from(wide)?;
+        assert_eq!(narrow, value.parse()?);
+    }
+
+    for value in [
+        "9223372036.854775808",
+        "-9223372036.854775809",
+        "100000000000000000000",
+    ] {
+        let wide: FixedPoint<i128, U9> = value.parse()?;
+        let error = FixedPoint::<i64, U9>::try_from(wide).unwrap_err();
+        assert_eq!(error.kind(), ConvertErrorKind::IntegralOverflow);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "i128", feature = "std"))]
+fn rescale_slice_with_max_loss() -> Result<()> {
+    use fixnum::{
+        ops::RoundMode::{self, *},
+        typenum::{U18, U2, U9},
+        ArithmeticError, FixedPoint,
+    };
+
+    type Fp128 = FixedPoint<i128, U18>;
+    type Fp64 = FixedPoint<i64, U9>;
+
+    let parse = |values: &[&str]| -> Result<Vec<Fp128>> {
+        Ok(values.iter().map(|v| v.parse()).collect::<Result<_, _>>()?)
+    };
+    let values = parse(&[
+        "1.5",
+        "-0.0000000015",
+        "0.9999999999",
+        "-7.123456789123456789",
+    ])?;
+
+    for (mode, expected, max_loss) in [
+        (
+            Floor,
+            ["1.5", "-0.000000002", "0.999999999", "-7.123456790"],
+            "0.0000000009",
+        ),
+        (
+            Ceil,
+            ["1.5", "-0.000000001", "1", "-7.123456789"],
+            "0.0000000005",
+        ),
+        (
+            Nearest,
+            ["1.5", "-0.000000002", "1", "-7.123456789"],
+            "0.0000000005",
+        ),
+        (
+            HalfEven,
+            ["1.5", "-0.000000002", "1", "-7.123456789"],
+            "0.0000000005",
+        ),
+    ] {
+        let mut out = [Fp64::ZERO; 4];
+        let loss = Fp128::rescale_slice_with_max_loss(&values, &mut out, mode)?;
+        let expected = expected
+            .iter()
+            .map(|v| v.parse())
+            .collect::<Result<Vec<Fp64>, _>>()?;
+        assert_eq!(out.to_vec(), expected, "{:?}", mode);
+        assert_eq!(loss, max_loss.parse()?, "{:?}", mode);
+    }
+
+    // Lossless and empty batches.
+    let mut out = [Fp64::ZERO; 2];
+    let loss = Fp128::rescale_slice_with_max_loss(&parse(&["1.5", "-2"])?, &mut out, Floor)?;
+    assert_eq!(loss, Fp128::ZERO);
+    let loss = Fp128::rescale_slice_with_max_loss::<U9>(&[], &mut [], Floor)?;
+    assert_eq!(loss, Fp128::ZERO);
+
+    // Upscaling is lossless, but can overflow.
+    let mut out = [FixedPoint::<i64, U18>::ZERO; 1];
+    let loss =
+        FixedPoint::<i128, U9>::rescale_slice_with_max_loss(&["9.2".parse()?], &mut out, Floor)?;
+    assert_eq!((out[0], loss), ("9.2".parse()?, Zero::ZERO));
+    let result =
+        FixedPoint::<i128, U9>::rescale_slice_with_max_loss(&["9.3".parse()?], &mut out, Floor);
+    assert_eq!(result, Err(ArithmeticError::Overflow));
+
+    let mut out = [FixedPoint::<i64, U2>::ZERO; 1];
+    let result = Fp128::rescale_slice_with_max_loss(&[Fp128::MAX], &mut out, Nearest);
+    assert_eq!(result, Err(ArithmeticError::Overflow));
+
+    let result = std::panic::catch_unwind(|| {
+        let mut out = [Fp64::ZERO; 1];
+        Fp128::rescale_slice_with_max_loss(&[Fp128::ZERO; 2], &mut out, RoundMode::Floor)
+    });
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn from_bool_and_digit() -> Result<()> {
+    use fixnum::ConvertErrorKind;
+
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::from(true), fp!(1));
+            assert_eq!(FixedPoint::from(false), fp!(0));
+
+            for (i, digit) in (b'0'..=b'9').enumerate() {
+                assert_eq!(FixedPoint::try_from_digit(digit)?, FixedPoint::try_from(i)?);
+            }
+            for byte in [b'a', b' ', b'-', 0, 10] {
+                let error = FixedPoint::try_from_digit(byte).unwrap_err();
+                assert_eq!(error.kind(), ConvertErrorKind::InvalidSyntax);
+            }
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sortable_key() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint]) => {
+            for pair in values.windows(2) {
+                assert!(pair[0] < pair[1]);
+                assert!(pair[0].sortable_key() < pair[1].sortable_key());
+            }
+
+            for &value in values {
+                assert_eq!(FixedPoint::from_sortable_key(value.sortable_key()), value);
+            }
+        },
+        all {
+            (&[FixedPoint::MIN, fp!(-1), fp!(-0.000000001), fp!(0), FixedPoint::EPSILON, fp!(1), FixedPoint::MAX]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.sortable_key(), 0);
+            assert_eq!(FixedPoint::ZERO.sortable_key(), 1 << (Layout::BITS - 1));
+            assert_eq!(FixedPoint::MAX.sortable_key().count_zeros(), 0);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn digit_at() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, position: i32, expected: u8) => {
+            assert_eq!(x.digit_at(position), expected);
+        },
+        all {
+            (fp!(123.45), 3, 0);
+            (fp!(123.45), 2, 1);
+            (fp!(123.45), 1, 2);
+            (fp!(123.45), 0, 3);
+            (fp!(123.45), -1, 4);
+            (fp!(123.45), -2, 5);
+            (fp!(123.45), -3, 0);
+            (fp!(-123.45), 2, 1);
+            (fp!(-123.45), -2, 5);
+            (fp!(0), 0, 0);
+            (fp!(1), i32::MAX, 0);
+            (fp!(1), i32::MIN, 0);
+            (FixedPoint::EPSILON, -19, 0);
+        },
+        fp64 {
+            (FixedPoint::MAX, 9, 9);
+            (FixedPoint::MAX, -9, 7);
+            (FixedPoint::MIN, -9, 8);
+            (FixedPoint::EPSILON, -9, 1);
+        },
+        fp128 {
+            (FixedPoint::MAX, 20, 1);
+            (FixedPoint::MAX, -18, 7);
+            (FixedPoint::MIN, -18, 8);
+            (FixedPoint::EPSILON, -18, 1);
+        },
     };
     Ok(())
 }
@@ -75,4 +497,13 @@ proptest! {
             }
         }
     }
+
+    #[test]
+    fn sortable_key_order(a in any::<i128>(), b in any::<i128>()) {
+        type FixedPoint128 = fixnum::FixedPoint<i128, typenum::U18>;
+
+        let (a, b) = (FixedPoint128::from_bits(a), FixedPoint128::from_bits(b));
+        prop_assert_eq!(a.cmp(&b), a.sortable_key().cmp(&b.sortable_key()));
+        prop_assert_eq!(FixedPoint128::from_sortable_key(a.sortable_key()), a);
+    }
 }
@@ -87,6 +87,23 @@ fn from_f64() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_negative_zero_f64() -> Result<()> {
+    test_fixed_point! {
+        case (x: f64) => {
+            let zero = FixedPoint::try_from(x)?;
+            assert_eq!(zero.into_bits(), 0);
+            assert!(f64::from(zero).is_sign_positive());
+        },
+        all {
+            (-0.0);
+            (-1e-20);
+            (-4e-19);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn from_f64_exact() -> Result<()> {
     test_fixed_point! {
@@ -122,16 +139,16 @@ fn from_f64_exact() -> Result<()> {
 #[test]
 fn from_f64_limits() -> Result<()> {
     test_fixed_point! {
-        case (x: f64, expected: &str) => {
-            let actual = FixedPoint::try_from(x).map_err(|err| err.to_string());
-            assert_eq!(actual, Err(expected.to_string()));
+        case (x: f64, kind: ConvertErrorKind, expected: &str) => {
+            let actual = FixedPoint::try_from(x).map_err(|err| (err.kind(), err.as_str()));
+            assert_eq!(actual, Err((kind, expected)));
         },
         all {
-            (f64::NAN, "not finite");
-            (f64::INFINITY, "not finite");
-            (f64::NEG_INFINITY, "not finite");
-            (f64::MAX, "too big number");
-            (f64::MIN, "too big number");
+            (f64::NAN, ConvertErrorKind::NotFinite, "not finite");
+            (f64::INFINITY, ConvertErrorKind::NotFinite, "not finite");
+            (f64::NEG_INFINITY, ConvertErrorKind::NotFinite, "not finite");
+            (f64::MAX, ConvertErrorKind::IntegralOverflow, "too big number");
+            (f64::MIN, ConvertErrorKind::IntegralOverflow, "too big number");
         },
     };
     Ok(())
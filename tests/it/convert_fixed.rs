@@ -0,0 +1,98 @@
+use anyhow::Result;
+use fixed::traits::Fixed;
+#[cfg(feature = "i64")]
+use proptest::prelude::*;
+use typenum::{U0, U32, U63};
+
+use fixnum::ops::{Bounded, RoundMode::*, *};
+
+fn with_bits<F: Fixed>(_: F, bits: F::Bits) -> F {
+    F::from_bits(bits)
+}
+
+#[test]
+fn to_fixed() -> Result<()> {
+    test_fixed_point! {
+        case (input: FixedPoint, mode: RoundMode, expected: Layout) => {
+            assert_eq!(input.to_fixed::<U32>(mode)?.to_bits(), expected);
+        },
+        all {
+            (fp!(0), Nearest, 0);
+            (fp!(2.5), Floor, 5 << 31);
+            (fp!(-2.5), Ceil, -5 << 31);
+            (fp!(0.000000001), Floor, 4);
+            (fp!(0.000000001), Nearest, 4);
+            (fp!(0.000000001), Ceil, 5);
+            (fp!(-0.000000001), Floor, -5);
+            (fp!(-0.000000001), Nearest, -4);
+            (fp!(-0.000000001), Ceil, -4);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_fixed() -> Result<()> {
+    test_fixed_point! {
+        case (bits: Layout, mode: RoundMode, expected: FixedPoint) => {
+            let binary = with_bits(FixedPoint::ZERO.to_fixed::<U32>(Floor)?, bits);
+            assert_eq!(FixedPoint::from_fixed(binary, mode)?, expected);
+        },
+        all {
+            (0, Nearest, fp!(0));
+            (5 << 31, Floor, fp!(2.5));
+            (-5 << 31, Ceil, fp!(-2.5));
+        },
+        fp64 {
+            (1, Floor, fp!(0));
+            (1, Nearest, fp!(0));
+            (1, Ceil, fp!(0.000000001));
+            (-1, Floor, fp!(-0.000000001));
+            (-1, Nearest, fp!(0));
+            (-1, Ceil, fp!(0));
+            // Ties are rounded away from zero.
+            (1 << 22, Floor, fp!(0.000976562));
+            (1 << 22, Nearest, fp!(0.000976563));
+            (-1 << 22, Nearest, fp!(-0.000976563));
+            (-1 << 22, Ceil, fp!(-0.000976562));
+        },
+        fp128 {
+            (1, Floor, fp!(0.000000000232830643));
+            (1, Nearest, fp!(0.000000000232830644));
+            (-1, Ceil, fp!(-0.000000000232830643));
+            (1 << 22, Nearest, fp!(0.0009765625));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn overflow() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert!(FixedPoint::MAX.to_fixed::<U63>(Floor).is_err());
+            assert!(FixedPoint::MIN.to_fixed::<U63>(Ceil).is_err());
+
+            let binary = with_bits(FixedPoint::ZERO.to_fixed::<U0>(Floor)?, Layout::MAX);
+            assert!(FixedPoint::from_fixed(binary, Floor).is_err());
+            let binary = with_bits(FixedPoint::ZERO.to_fixed::<U0>(Floor)?, Layout::MIN);
+            assert!(FixedPoint::from_fixed(binary, Ceil).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[cfg(feature = "i64")]
+proptest! {
+    #[test]
+    fn roundtrip(bits in (i64::MIN >> 3)..(i64::MAX >> 3)) {
+        type FixedPoint64 = fixnum::FixedPoint<i64, typenum::U9>;
+
+        // `2^-32 < 0.5e-9`, so the binary representation keeps all decimal digits.
+        let expected = FixedPoint64::from_bits(bits);
+        for mode in [Floor, Nearest, Ceil] {
+            let binary = expected.to_fixed::<U32>(mode).unwrap();
+            prop_assert_eq!(FixedPoint64::from_fixed(binary, Nearest).unwrap(), expected);
+        }
+    }
+}
@@ -4,6 +4,7 @@ extern crate alloc;
 use alloc::format;
 
 use anyhow::Result;
+use fixnum::ConvertErrorKind::{self, *};
 #[cfg(feature = "i128")]
 use proptest::prelude::*;
 
@@ -27,8 +28,18 @@ fn from_good_str_exact() -> Result<()> {
             #[cfg(feature = "serde")]
             assert_eq!(serde_json::from_str::<FixedPoint>(&format!("\"{}\"", exact))?, expected);
         },
+        all {
+            ("0", 0);
+            ("-0", 0);
+            ("+0", 0);
+        },
         fp64 {
             ("1", 1000000000);
+            ("-1", -1000000000);
+            ("+1", 1000000000);
+            (" 42 ", 42000000000);
+            ("9223372036", 9223372036000000000);
+            ("-9223372036", -9223372036000000000);
             ("1.1", 1100000000);
             ("1.02", 1020000000);
             ("-1.02", -1020000000);
@@ -41,6 +52,11 @@ fn from_good_str_exact() -> Result<()> {
         },
         fp128 {
             ("1", 1000000000000000000);
+            ("-1", -1000000000000000000);
+            ("+1", 1000000000000000000);
+            (" 42 ", 42000000000000000000);
+            ("170141183460469231731", 170141183460469231731000000000000000000);
+            ("-170141183460469231731", -170141183460469231731000000000000000000);
             ("1.1", 1100000000000000000);
             ("1.02", 1020000000000000000);
             ("-1.02", -1020000000000000000);
@@ -110,6 +126,41 @@ fn from_good_str_inexact() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn negative_zero() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str) => {
+            let zero: FixedPoint = input.parse()?;
+            assert_eq!(zero.into_bits(), 0);
+            assert_eq!(format!("{}", zero), "0.0");
+
+            #[cfg(feature = "std")]
+            {
+                use std::collections::hash_map::DefaultHasher;
+                use std::hash::{Hash, Hasher};
+
+                let hash = |x: FixedPoint| {
+                    let mut hasher = DefaultHasher::new();
+                    x.hash(&mut hasher);
+                    hasher.finish()
+                };
+                assert_eq!(hash(zero), hash(FixedPoint::from_bits(0)));
+            }
+
+            #[cfg(feature = "serde")]
+            assert_eq!(serde_json::to_string(&zero)?, "\"0.0\"");
+        },
+        all {
+            ("-0");
+            ("-0.0");
+            ("-00.000");
+            ("-0.0000000000000000001");
+            ("-0.0000000000000000004");
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn from_bad_str() -> Result<()> {
     test_fixed_point! {
@@ -124,6 +175,11 @@ fn from_bad_str() -> Result<()> {
         },
         all {
             ("");
+            ("-");
+            ("+");
+            ("--1");
+            ("1-");
+            ("12a");
             ("7.02e5");
             ("a.12");
             ("12.a");
@@ -135,6 +191,8 @@ fn from_bad_str() -> Result<()> {
             ("-170141183460469231731.6873037158841057285");
         },
         fp64 {
+            ("9223372037");
+            ("-9223372037");
             ("9223372036.854775808");
             ("9223372036.8547758075");
             ("-9223372036.854775809");
@@ -144,6 +202,34 @@ fn from_bad_str() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn parse_errors() -> Result<()> {
+    test_fixed_point! {
+        case (input: &str, kind: ConvertErrorKind, expected: &str) => {
+            let err = FixedPoint::from_str_exact(input).unwrap_err();
+            assert_eq!(err.kind(), kind);
+            assert_eq!(err.is_integral_overflow(), kind == ConvertErrorKind::IntegralOverflow);
+            assert_eq!(err.is_fractional_precision(), kind == ConvertErrorKind::FractionalPrecision);
+            assert_eq!(err.to_string(), expected);
+        },
+        all {
+            ("12a", InvalidSyntax, "can't parse integer");
+            ("1.2.3", InvalidSyntax, "can't parse fractional part: must contain digits only");
+        },
+        fp64 {
+            ("100000000000000000000000", IntegralOverflow, "too big number: must be in [-9223372036.854775808, 9223372036.854775807]");
+            ("-9223372037", IntegralOverflow, "too big number: must be in [-9223372036.854775808, 9223372036.854775807]");
+            ("9223372036.854775808", IntegralOverflow, "too big number: must be in [-9223372036.854775808, 9223372036.854775807]");
+            ("13.0000000001", FractionalPrecision, "requested precision is too high: at most 9 fractional digits");
+        },
+        fp128 {
+            ("100000000000000000000000", IntegralOverflow, "too big number: must be in [-170141183460469231731.687303715884105728, 170141183460469231731.687303715884105727]");
+            ("13.0000000000000000001", FractionalPrecision, "requested precision is too high: at most 18 fractional digits");
+        },
+    };
+    Ok(())
+}
+
 #[cfg(feature = "i128")]
 proptest! {
     #[test]
@@ -0,0 +1,104 @@
+//! Test vectors shared by all configurations: enabling `std` must not change computed values.
+
+#[allow(unused_imports)]
+use fixnum::{ops::RoundMode::*, typenum::*, FixedPoint};
+
+#[test]
+#[cfg(feature = "i64")]
+fn rsqrt_fp64() {
+    type Fp64 = FixedPoint<i64, U9>;
+
+    for (input, floor, nearest, ceil) in [
+        ("2", "1.414213562", "1.414213562", "1.414213563"),
+        ("0.5", "0.707106781", "0.707106781", "0.707106782"),
+        ("0.000000002", "0.000044721", "0.000044721", "0.000044722"),
+        (
+            "123456789.123456789",
+            "11111.111066111",
+            "11111.111066111",
+            "11111.111066112",
+        ),
+        (
+            "9223372036.854775807",
+            "96038.388349944",
+            "96038.388349945",
+            "96038.388349945",
+        ),
+    ] {
+        let value: Fp64 = input.parse().unwrap();
+        assert_eq!(value.rsqrt(Floor).unwrap().to_string(), floor, "{}", input);
+        assert_eq!(
+            value.rsqrt(Nearest).unwrap().to_string(),
+            nearest,
+            "{}",
+            input
+        );
+        assert_eq!(value.rsqrt(Ceil).unwrap().to_string(), ceil, "{}", input);
+    }
+}
+
+#[test]
+#[cfg(feature = "i128")]
+fn rsqrt_fp128() {
+    type Fp128 = FixedPoint<i128, U18>;
+
+    for (input, floor, nearest, ceil) in [
+        (
+            "2",
+            "1.414213562373095048",
+            "1.414213562373095049",
+            "1.414213562373095049",
+        ),
+        (
+            "0.5",
+            "0.707106781186547524",
+            "0.707106781186547524",
+            "0.707106781186547525",
+        ),
+        (
+            "0.000000000000000002",
+            "0.000000001414213562",
+            "0.000000001414213562",
+            "0.000000001414213563",
+        ),
+        (
+            "123456789.123456789123456789",
+            "11111.111066111110974986",
+            "11111.111066111110974986",
+            "11111.111066111110974987",
+        ),
+        (
+            "170141183460469231731.687303715884105727",
+            "13043817825.332782212349571806",
+            "13043817825.332782212349571806",
+            "13043817825.332782212349571807",
+        ),
+    ] {
+        let value: Fp128 = input.parse().unwrap();
+        assert_eq!(value.rsqrt(Floor).unwrap().to_string(), floor, "{}", input);
+        assert_eq!(
+            value.rsqrt(Nearest).unwrap().to_string(),
+            nearest,
+            "{}",
+            input
+        );
+        assert_eq!(value.rsqrt(Ceil).unwrap().to_string(), ceil, "{}", input);
+    }
+}
+
+#[test]
+#[cfg(feature = "i64")]
+fn transcendental_fp64() {
+    type Fp64 = FixedPoint<i64, U9>;
+
+    for (input, log1p, expm1) in [
+        ("2", "1.098612288", "6.389056098"),
+        ("0.5", "0.405465108", "0.64872127"),
+        ("0.000000002", "0.000000001", "0.000000002"),
+        ("-0.5", "-0.693147181", "-0.393469341"),
+    ] {
+        let value: Fp64 = input.parse().unwrap();
+        assert_eq!(value.rlog1p(Floor).unwrap().to_string(), log1p, "{}", input);
+        assert_eq!(value.rexpm1(Floor).unwrap().to_string(), expm1, "{}", input);
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::Result;
+use typenum::U9;
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, ConvertErrorKind, DynFixedPoint, FixedPoint,
+};
+
+type Dyn = DynFixedPoint<i64>;
+type Fp64 = FixedPoint<i64, U9>;
+
+fn d(s: &str) -> Dyn {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parse_and_display() -> Result<()> {
+    for (input, mantissa, scale, expected) in [
+        ("0", 0, 0, "0"),
+        ("-0.00", 0, 2, "0.00"),
+        ("+1.50", 150, 2, "1.50"),
+        ("-0.001", -1, 3, "-0.001"),
+        ("-9223372036854775808", i64::MIN, 0, "-9223372036854775808"),
+        ("9.223372036854775807", i64::MAX, 18, "9.223372036854775807"),
+    ] {
+        let value: Dyn = input.parse()?;
+        assert_eq!(
+            (value.mantissa(), value.scale()),
+            (mantissa, scale),
+            "{}",
+            input
+        );
+        assert_eq!(value.to_string(), expected);
+    }
+
+    let kind = |s: &str| s.parse::<Dyn>().unwrap_err().kind();
+    assert_eq!(
+        kind("9223372036854775808"),
+        ConvertErrorKind::IntegralOverflow
+    );
+    assert_eq!(
+        kind("0.0000000000000000001"),
+        ConvertErrorKind::FractionalPrecision
+    );
+    assert_eq!(kind("1."), ConvertErrorKind::InvalidSyntax);
+    assert_eq!(kind(".1"), ConvertErrorKind::InvalidSyntax);
+    assert_eq!(kind("1.-1"), ConvertErrorKind::InvalidSyntax);
+
+    assert_eq!(
+        Dyn::new(1, 19).unwrap_err().kind(),
+        ConvertErrorKind::UnsupportedExponent
+    );
+    Ok(())
+}
+
+#[test]
+fn rescale() -> Result<()> {
+    assert_eq!(d("1.25").rescale(4, Floor)?, d("1.2500"));
+    assert_eq!(d("1.25").rescale(1, Floor)?, d("1.2"));
+    assert_eq!(d("1.25").rescale(1, Nearest)?, d("1.3"));
+    assert_eq!(d("-1.25").rescale(1, Nearest)?, d("-1.3"));
+    assert_eq!(d("-1.25").rescale(1, Ceil)?, d("-1.2"));
+    assert_eq!(d("-1.25").rescale(0, Floor)?, d("-2"));
+    assert_eq!(d("10").rescale(18, Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(d("1").rescale(19, Floor), Err(ArithmeticError::Overflow));
+    Ok(())
+}
+
+#[test]
+fn arithmetic() -> Result<()> {
+    assert_eq!(d("1.5").cadd(d("0.25"))?, d("1.75"));
+    assert_eq!(d("1.5").csub(d("0.25"))?, d("1.25"));
+    assert_eq!(d("0.1").csub(d("0.100"))?, d("0.000"));
+    assert_eq!(
+        d("10").cadd(d("0.000000000000000001")),
+        Err(ArithmeticError::Overflow)
+    );
+
+    assert_eq!(d("1.5").rmul(d("0.25"), Floor)?, d("0.37"));
+    assert_eq!(d("1.5").rmul(d("0.25"), Ceil)?, d("0.38"));
+    assert_eq!(d("-1.5").rmul(d("0.25"), Nearest)?, d("-0.38"));
+    assert_eq!(
+        d("3037000500").rmul(d("3037000500"), Floor),
+        Err(ArithmeticError::Overflow)
+    );
+
+    assert_eq!(d("1").rdiv(d("3.0"), Floor)?, d("0.3"));
+    assert_eq!(d("1").rdiv(d("3.00"), Ceil)?, d("0.34"));
+    assert_eq!(d("-2.00").rdiv(d("0.3"), Nearest)?, d("-6.67"));
+    assert_eq!(
+        d("1").rdiv(d("0.00"), Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    Ok(())
+}
+
+#[test]
+fn fixed_point() -> Result<()> {
+    let fixed: Fp64 = "1.5".parse()?;
+    let value = Dyn::from(fixed);
+    assert_eq!((value.mantissa(), value.scale()), (1_500_000_000, 9));
+    assert_eq!(value.to_fixed_point::<U9>(Floor)?, fixed);
+
+    assert_eq!(
+        d("0.0000000015").to_fixed_point::<U9>(Floor)?,
+        Fp64::EPSILON
+    );
+    assert_eq!(
+        d("0.0000000015").to_fixed_point::<U9>(Nearest)?,
+        Fp64::EPSILON.cmul(2)?
+    );
+    assert_eq!(d("10").to_fixed_point::<U9>(Floor)?, "10".parse()?);
+    assert_eq!(
+        d("10000000000").to_fixed_point::<U9>(Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
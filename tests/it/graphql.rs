@@ -0,0 +1,72 @@
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use anyhow::Result;
+use async_graphql::{
+    EmptyMutation, EmptySubscription, Number, Object, Request, ScalarType, Schema, Value,
+};
+use fixnum::{ops::CheckedAdd, typenum::U9, FixedPoint};
+
+type Amount = FixedPoint<i64, U9>;
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn double(&self, amount: Amount) -> Amount {
+        amount.cadd(amount).unwrap()
+    }
+}
+
+/// Resolvers above never wait, so polling once is enough.
+fn now_or_never<F: Future>(future: F) -> F::Output {
+    match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is pending"),
+    }
+}
+
+#[test]
+fn scalar() -> Result<()> {
+    let parse = |value: Value| {
+        <Amount as ScalarType>::parse(value)
+            .map_err(|e| e.into_server_error(Default::default()).message)
+    };
+
+    assert_eq!(parse(Value::String("1.5".into())), Ok("1.5".parse()?));
+    assert_eq!(parse(Value::Number(Number::from(-42))), Ok("-42".parse()?));
+    assert_eq!(parse(Value::Number(Number::from(7u64))), Ok("7".parse()?));
+    assert_eq!(
+        parse(Value::Number(Number::from_f64(0.25).unwrap())),
+        Ok("0.25".parse()?)
+    );
+    assert!(parse(Value::String("1.2.3".into())).is_err());
+    assert!(parse(Value::Number(Number::from(u64::MAX))).is_err());
+    assert!(parse(Value::Boolean(true)).is_err());
+    assert!(parse(Value::Null).is_err());
+
+    let amount: Amount = "-0.000000001".parse()?;
+    assert_eq!(amount.to_value(), Value::String("-0.000000001".into()));
+    Ok(())
+}
+
+#[test]
+fn schema() {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    assert!(schema.sdl().contains("scalar FixedPoint"));
+
+    let response = now_or_never(schema.execute(Request::new(
+        r#"{ a: double(amount: "1.25") b: double(amount: 3) }"#,
+    )));
+    assert_eq!(response.errors, []);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        serde_json::json!({ "a": "2.5", "b": "6.0" })
+    );
+
+    let response = now_or_never(schema.execute(r#"{ double(amount: "abc") }"#));
+    assert_eq!(response.errors.len(), 1);
+}
@@ -18,10 +18,15 @@ macro_rules! test_fixed_point {
         #[allow(unused)]
         macro_rules! fp {
             ($val:literal) => {{
-                // TODO: check that `fixnum!` returns the same value.
-
                 // We don't use `fixnum!` here to check `suite_fails` cases.
-                FixedPoint::from_str_exact(stringify!($val))?
+                let value = FixedPoint::from_str_exact(stringify!($val))?;
+
+                // The parser behind `fixnum!` must return the same value.
+                let coef = fixnum::_priv::pow10(FixedPoint::PRECISION);
+                let bits = fixnum::_priv::parse_fixed(stringify!($val), coef);
+                assert_eq!(bits as Layout, value.into_bits(), "fixnum!({})", stringify!($val));
+
+                value
             }};
         }
 
@@ -179,9 +184,50 @@ mod r#impl {
 }
 
 // Tests
+#[cfg(feature = "analysis")]
+mod analysis;
+#[cfg(all(feature = "audit", feature = "i64"))]
+mod audit;
+#[cfg(all(feature = "i64", feature = "std"))]
+mod binary;
+#[cfg(feature = "std")]
+mod book;
+#[cfg(all(feature = "clap", feature = "i64"))]
+mod clap;
 mod const_ctor;
 mod convert;
 mod convert_f64;
+#[cfg(feature = "fixed-interop")]
+mod convert_fixed;
 mod convert_str;
+mod deterministic;
+#[cfg(all(feature = "i64", feature = "std"))]
+mod dynamic;
+#[cfg(all(feature = "async-graphql", feature = "i64"))]
+mod graphql;
+#[cfg(all(feature = "i64", feature = "std"))]
+mod non_zero;
+// `#[no_panic]` relies on optimizations to remove unreachable panics.
+#[cfg(all(feature = "i64", feature = "std", not(debug_assertions)))]
+mod no_panic;
+#[cfg(all(feature = "ops", feature = "std"))]
+mod operators;
 mod ops;
+mod precision;
+#[cfg(feature = "prost")]
+mod prost;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(all(feature = "inventory", feature = "i16"))]
+mod registry;
 mod serde;
+mod slice;
+mod stats;
+#[cfg(all(feature = "testgen", feature = "std"))]
+mod testgen;
+#[cfg(all(any(feature = "u64", feature = "u128"), feature = "std"))]
+mod unsigned;
+#[cfg(all(feature = "utoipa", feature = "i64"))]
+mod utoipa;
+#[cfg(all(feature = "i256", feature = "std"))]
+mod wide;
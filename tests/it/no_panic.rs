@@ -0,0 +1,91 @@
+//! Hot operations must have no panic paths in release builds: `#[no_panic]` fails linking
+//! otherwise. `Display` is checked by unit tests in `src/string.rs`.
+//!
+//! Run by `CARGO_PROFILE_RELEASE_LTO=fat cargo test --release --features i64,i128 no_panic`,
+//! LTO is required to see through functions of dependencies (e.g. `itoa`).
+
+use no_panic::no_panic;
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    typenum::U9,
+    ArithmeticError, FixedPoint,
+};
+
+type Amount = FixedPoint<i64, U9>;
+
+const MODES: [RoundMode; 6] = [Floor, Nearest, Ceil, HalfEven, TowardZero, AwayFromZero];
+
+#[no_panic]
+fn cadd(a: Amount, b: Amount) -> Result<Amount, ArithmeticError> {
+    a.cadd(b)
+}
+
+#[no_panic]
+fn rmul(a: Amount, b: Amount, mode: RoundMode) -> Result<Amount, ArithmeticError> {
+    a.rmul(b, mode)
+}
+
+#[no_panic]
+fn rdiv(a: Amount, b: Amount, mode: RoundMode) -> Result<Amount, ArithmeticError> {
+    a.rdiv(b, mode)
+}
+
+#[no_panic]
+fn integral(a: Amount, mode: RoundMode) -> i64 {
+    a.integral(mode)
+}
+
+#[test]
+fn hot_ops() {
+    let values = [
+        Amount::MIN,
+        Amount::ZERO,
+        Amount::EPSILON,
+        Amount::ONE,
+        Amount::MAX,
+    ];
+    for a in values {
+        for mode in MODES {
+            assert_eq!(integral(a, mode), a.integral(mode));
+            for b in values {
+                assert_eq!(cadd(a, b), a.cadd(b));
+                assert_eq!(rmul(a, b, mode), a.rmul(b, mode));
+                assert_eq!(rdiv(a, b, mode), a.rdiv(b, mode));
+            }
+        }
+    }
+}
+
+// `rmul` and `rdiv` of `i128` aren't checked: they divide in `i256` from the `i256` crate,
+// whose long division has bounds checks the optimizer can't prove unreachable.
+#[cfg(feature = "i128")]
+mod wide {
+    use super::*;
+    use fixnum::typenum::U18;
+
+    type Wide = FixedPoint<i128, U18>;
+
+    #[no_panic]
+    fn cadd(a: Wide, b: Wide) -> Result<Wide, ArithmeticError> {
+        a.cadd(b)
+    }
+
+    #[no_panic]
+    fn integral(a: Wide, mode: RoundMode) -> i128 {
+        a.integral(mode)
+    }
+
+    #[test]
+    fn hot_ops() {
+        let values = [Wide::MIN, Wide::ZERO, Wide::EPSILON, Wide::ONE, Wide::MAX];
+        for a in values {
+            for mode in MODES {
+                assert_eq!(integral(a, mode), a.integral(mode));
+                for b in values {
+                    assert_eq!(cadd(a, b), a.cadd(b));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use core::{mem::size_of, num::NonZeroI64};
+
+use anyhow::Result;
+use typenum::U9;
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, ConvertErrorKind, FixedPoint,
+};
+
+type Plain = FixedPoint<i64, U9>;
+type NonZero = FixedPoint<NonZeroI64, U9>;
+
+#[test]
+fn niche() {
+    assert_eq!(size_of::<Option<NonZero>>(), size_of::<Plain>());
+}
+
+#[test]
+fn new_and_get() -> Result<()> {
+    let plain: Plain = "1.5".parse()?;
+    let non_zero = NonZero::new(plain).unwrap();
+    assert_eq!(non_zero.get(), plain);
+    assert_eq!(NonZero::new(Plain::ZERO), None);
+
+    assert_eq!(NonZero::ONE.get(), Plain::ONE);
+    assert_eq!(NonZero::MIN.get(), Plain::MIN);
+    assert_eq!(NonZero::MAX.get(), Plain::MAX);
+    Ok(())
+}
+
+#[test]
+fn parse_and_display() -> Result<()> {
+    let x: NonZero = "-0.25".parse()?;
+    assert_eq!(x.to_string(), "-0.25");
+    assert_eq!(format!("{:?}", x), "-0.25");
+    assert_eq!(
+        "0.0".parse::<NonZero>().unwrap_err().kind(),
+        ConvertErrorKind::OutOfDomain
+    );
+    Ok(())
+}
+
+#[test]
+fn arithmetic() -> Result<()> {
+    let a: NonZero = "1.5".parse()?;
+    let b: NonZero = "0.5".parse()?;
+
+    assert_eq!(a.cadd(b)?, "2".parse()?);
+    assert_eq!(a.csub(b)?, NonZero::ONE);
+    assert_eq!(a.rmul(b, Floor)?, "0.75".parse()?);
+    assert_eq!(a.rdiv(b, Floor)?, "3".parse()?);
+
+    // Zero cannot be represented.
+    assert_eq!(a.csub(a), Err(ArithmeticError::ZeroResult));
+    let tiny: NonZero = "0.000000001".parse()?;
+    assert_eq!(tiny.rmul(tiny, Floor), Err(ArithmeticError::ZeroResult));
+    assert_eq!(tiny.rmul(tiny, Ceil)?, tiny);
+    assert_eq!(NonZero::MAX.cadd(a), Err(ArithmeticError::Overflow));
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::panic::catch_unwind;
+
+use anyhow::Result;
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    *,
+};
+
+#[test]
+fn operators() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, n: Layout) => {
+            assert_eq!(a + b, a.cadd(b)?);
+            assert_eq!(a - b, a.csub(b)?);
+            assert_eq!(a * n, a.cmul(n)?);
+            assert_eq!(a / n, a.rdiv(n, TowardZero)?);
+            assert_eq!(-a, a.cneg()?);
+            assert_eq!(-(a - b), b - a);
+            assert_eq!([a, b, a].iter().sum::<FixedPoint>(), FixedPoint::csum([a, b, a])?);
+            assert_eq!([a, b].into_iter().sum::<FixedPoint>(), a + b);
+            assert_eq!(
+                [a, b].iter().product::<FixedPoint>(),
+                FixedPoint::checked_product([a, b], TowardZero)?
+            );
+
+            let mut x = a;
+            x += b;
+            assert_eq!(x, a + b);
+            x -= b;
+            assert_eq!(x, a);
+            x *= n;
+            assert_eq!(x, a * n);
+            x /= n;
+            assert_eq!(x, a * n / n);
+        },
+        all {
+            (fp!(0), fp!(0), 1);
+            (fp!(1.5), fp!(-2.25), 3);
+            (fp!(-1000.0000002), fp!(3000.0000006), -7);
+            (fp!(0.000000001), fp!(0.000000001), 2);
+            (fp!(-0.000000001), fp!(0.1), 2);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn operators_panic() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert!(catch_unwind(|| FixedPoint::MAX + FixedPoint::EPSILON).is_err());
+            assert!(catch_unwind(|| FixedPoint::MIN - FixedPoint::EPSILON).is_err());
+            assert!(catch_unwind(|| FixedPoint::MAX * 2).is_err());
+            assert!(catch_unwind(|| FixedPoint::ONE / 0).is_err());
+            assert!(catch_unwind(|| FixedPoint::MIN / -1).is_err());
+            assert!(catch_unwind(|| -FixedPoint::MIN).is_err());
+            assert!(catch_unwind(|| [FixedPoint::MAX, FixedPoint::EPSILON].iter().sum::<FixedPoint>()).is_err());
+            assert!(catch_unwind(|| [FixedPoint::MAX, FixedPoint::MAX].iter().product::<FixedPoint>()).is_err());
+            assert_eq!(core::iter::empty::<FixedPoint>().sum::<FixedPoint>(), FixedPoint::ZERO);
+            assert_eq!(core::iter::empty::<FixedPoint>().product::<FixedPoint>(), FixedPoint::ONE);
+            assert_eq!(-FixedPoint::MAX, FixedPoint::MIN + FixedPoint::EPSILON);
+            assert!(catch_unwind(|| {
+                let mut x = FixedPoint::MAX;
+                x += FixedPoint::EPSILON;
+            })
+            .is_err());
+        },
+    };
+    Ok(())
+}
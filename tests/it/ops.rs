@@ -303,6 +303,104 @@ fn rdiv_layout() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rdiv_rem() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: Layout, mode: RoundMode, expected: FixedPoint, remainder: FixedPoint) => {
+            assert_eq!(a.rdiv_rem(b, mode)?, (expected, remainder));
+            assert_eq!(expected.cmul(b)?.cadd(remainder)?, a);
+        },
+        all {
+            (fp!(7), 2, Floor, fp!(3.5), fp!(0));
+            (fp!(-7), 2, Ceil, fp!(-3.5), fp!(0));
+            (FixedPoint::EPSILON, 2, Floor, fp!(0), FixedPoint::EPSILON);
+            (FixedPoint::EPSILON, 2, Ceil, FixedPoint::EPSILON, FixedPoint::EPSILON.cneg()?);
+            (FixedPoint::EPSILON.cneg()?, 2, Floor, FixedPoint::EPSILON.cneg()?, FixedPoint::EPSILON);
+            (FixedPoint::EPSILON.cneg()?, -2, Nearest, FixedPoint::EPSILON, FixedPoint::EPSILON);
+        },
+        fp64 {
+            (fp!(100), 3, Nearest, fp!(33.333333333), fp!(0.000000001));
+            (fp!(100), 3, Ceil, fp!(33.333333334), fp!(-0.000000002));
+            (fp!(-100), 3, Floor, fp!(-33.333333334), fp!(0.000000002));
+        },
+        fp128 {
+            (fp!(100), 3, Nearest, fp!(33.333333333333333333), fp!(0.000000000000000001));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // `quotient * rhs` overflows, but the remainder fits.
+            let (quotient, remainder) = FixedPoint::MAX.rdiv_rem(2, Ceil)?;
+            assert_eq!(quotient.into_bits(), Layout::MAX / 2 + 1);
+            assert_eq!(remainder, FixedPoint::EPSILON.cneg()?);
+
+            assert_eq!(fp!(1).rdiv_rem(0, Floor), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(FixedPoint::MIN.rdiv_rem(-1, Floor), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_ratio() -> Result<()> {
+    test_fixed_point! {
+        case (a: Layout, b: Layout, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_ratio(a, b, mode)?, expected);
+        },
+        all {
+            (5, 2, Floor, fp!(2.5));
+            (-1, 4, Ceil, fp!(-0.25));
+            (0, 7, Nearest, fp!(0));
+            (9_223_372_036, 1, Floor, fp!(9223372036));
+        },
+        fp64 {
+            (1, 3, Floor, fp!(0.333333333));
+            (2, 3, Nearest, fp!(0.666666667));
+            (-2, 3, Floor, fp!(-0.666666667));
+        },
+        fp128 {
+            (1, 3, Ceil, fp!(0.333333333333333334));
+            (-2, 3, Nearest, fp!(-0.666666666666666667));
+        },
+    };
+    test_fixed_point! {
+        case (a: Layout, b: Layout, expected: ArithmeticError) => {
+            assert_eq!(FixedPoint::from_ratio(a, b, Nearest), Err(expected));
+        },
+        all {
+            (1, 0, ArithmeticError::DivisionByZero);
+            (Layout::MAX, 1, ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rdiv_or() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, default: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.rdiv_or(b, default, Floor)?, expected);
+            if a == FixedPoint::ONE {
+                assert_eq!(b.recip_or(default, Floor)?, expected);
+            }
+        },
+        all {
+            (fp!(5), fp!(2), fp!(0), fp!(2.5));
+            (fp!(5), fp!(0), fp!(0), fp!(0));
+            (fp!(5), fp!(0), fp!(-1), fp!(-1));
+            (fp!(1), fp!(4), fp!(7), fp!(0.25));
+            (fp!(1), fp!(0), fp!(7), fp!(7));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let result = FixedPoint::MAX.rdiv_or(FixedPoint::EPSILON, FixedPoint::ZERO, Floor);
+            assert_eq!(result, Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn rdiv_division_by_zero() -> Result<()> {
     test_fixed_point! {
@@ -341,6 +439,98 @@ fn rdiv_overflow() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn rmul_div() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(a.rmul_div(b, c, mode)?, expected);
+            assert_eq!(b.rmul_div(a, c, mode)?, expected);
+            assert_eq!(a.cneg()?.rmul_div(b, c.cneg()?, mode)?, expected);
+        },
+        all {
+            (fp!(0), fp!(5), fp!(3), Floor, fp!(0));
+            (fp!(2), fp!(3), fp!(4), Floor, fp!(1.5));
+            (fp!(0.000000001), fp!(0.5), fp!(0.25), Floor, fp!(0.000000002));
+            (FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX, Floor, FixedPoint::MAX);
+            (FixedPoint::MAX, fp!(3), fp!(4), Floor, FixedPoint::MAX.rmul(fp!(0.75), Floor)?);
+            (fp!(1000), fp!(1000), fp!(0.001), Floor, fp!(1000000000));
+        },
+        fp64 {
+            (fp!(1), fp!(1), fp!(3), Floor, fp!(0.333333333));
+            (fp!(1), fp!(1), fp!(3), Ceil, fp!(0.333333334));
+            (fp!(-1), fp!(2), fp!(3), Nearest, fp!(-0.666666667));
+            (fp!(-1), fp!(2), fp!(3), TowardZero, fp!(-0.666666666));
+        },
+        fp128 {
+            (fp!(1), fp!(1), fp!(3), Floor, fp!(0.333333333333333333));
+            (fp!(-1), fp!(2), fp!(3), AwayFromZero, fp!(-0.666666666666666667));
+            (fp!(0.000000000000000001), fp!(0.5), fp!(0.25), Ceil, fp!(0.000000000000000002));
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, c: FixedPoint, expected: ArithmeticError) => {
+            assert_eq!(a.rmul_div(b, c, Floor), Err(expected));
+        },
+        all {
+            (fp!(1), fp!(1), fp!(0), ArithmeticError::DivisionByZero);
+            (FixedPoint::MAX, fp!(2), fp!(1), ArithmeticError::Overflow);
+            (FixedPoint::MIN, fp!(1), FixedPoint::EPSILON.cneg()?, ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rem_and_div_euclid() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, rem: FixedPoint, rem_euclid: FixedPoint, div_euclid: Layout) => {
+            assert_eq!(a.crem(b)?, rem);
+            assert_eq!(a.rem_euclid(b)?, rem_euclid);
+            assert_eq!(a.div_euclid(b)?, div_euclid);
+            assert_eq!(b.cmul(div_euclid)?.cadd(rem_euclid)?, a);
+        },
+        all {
+            (fp!(7.5), fp!(2), fp!(1.5), fp!(1.5), 3);
+            (fp!(-7.5), fp!(2), fp!(-1.5), fp!(0.5), -4);
+            (fp!(7.5), fp!(-2), fp!(1.5), fp!(1.5), -3);
+            (fp!(-7.5), fp!(-2), fp!(-1.5), fp!(0.5), 4);
+            (fp!(6), fp!(1.5), fp!(0), fp!(0), 4);
+            (fp!(0.3), fp!(0.1), fp!(0), fp!(0), 3);
+            (fp!(-0.000000001), fp!(0.25), fp!(-0.000000001), fp!(0.249999999), -1);
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: Layout, rem: FixedPoint, rem_euclid: FixedPoint, div_euclid: Layout) => {
+            assert_eq!(a.crem(b)?, rem);
+            assert_eq!(a.rem_euclid(b)?, rem_euclid);
+            assert_eq!(a.div_euclid(b)?, div_euclid);
+        },
+        all {
+            (fp!(7.5), 2, fp!(1.5), fp!(1.5), 3);
+            (fp!(-7.5), 2, fp!(-1.5), fp!(0.5), -4);
+            (fp!(-7.5), -2, fp!(-1.5), fp!(0.5), 4);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let zero = Err(ArithmeticError::DivisionByZero);
+            assert_eq!(fp!(1).crem(FixedPoint::ZERO), zero);
+            assert_eq!(fp!(1).rem_euclid(0), zero);
+            assert_eq!(fp!(1).div_euclid(FixedPoint::ZERO), Err(ArithmeticError::DivisionByZero));
+
+            let minus_epsilon = FixedPoint::EPSILON.cneg()?;
+            assert_eq!(FixedPoint::MIN.crem(minus_epsilon)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::MIN.rem_euclid(minus_epsilon)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::MIN.div_euclid(minus_epsilon), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.crem(FixedPoint::MAX)?, minus_epsilon);
+            assert_eq!(FixedPoint::MIN.rem_euclid(FixedPoint::MAX)?, FixedPoint::MAX.csub(FixedPoint::EPSILON)?);
+            assert_eq!(FixedPoint::MIN.div_euclid(FixedPoint::MAX)?, -2);
+            assert_eq!(fp!(1).crem(Layout::MAX), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn float_mul() -> Result<()> {
     test_fixed_point! {
@@ -422,6 +612,58 @@ fn half_sum_exact() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn half_sum_as_rdiv() -> Result<()> {
+    test_fixed_point! {
+        case (a: Layout, b: Layout) => {
+            let (a, b) = (FixedPoint::EPSILON.cmul(a)?, FixedPoint::EPSILON.cmul(b)?);
+            for mode in [Floor, Ceil, Nearest, HalfEven, TowardZero, AwayFromZero] {
+                let expected = a.cadd(b)?.rdiv(2, mode)?;
+                assert_eq!(FixedPoint::half_sum(a, b, mode), expected, "{:?} {:?}", a, mode);
+                assert_eq!(FixedPoint::half_sum(b, a, mode), expected, "{:?} {:?}", b, mode);
+            }
+        },
+        all {
+            (1, 4);
+            (2, 3);
+            (3, 3);
+            (-2, -3);
+            (-1, -4);
+            (-5, -6);
+            (0, 5);
+            (0, -5);
+            (3, -8);
+            (-3, 8);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn half_sum_ties() -> Result<()> {
+    test_fixed_point! {
+        case (a: Layout, b: Layout, expected: [Layout; 6]) => {
+            let (a, b) = (FixedPoint::EPSILON.cmul(a)?, FixedPoint::EPSILON.cmul(b)?);
+            let modes = [Floor, Ceil, Nearest, HalfEven, TowardZero, AwayFromZero];
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                let expected = FixedPoint::EPSILON.cmul(expected)?;
+                assert_eq!(FixedPoint::half_sum(a, b, mode), expected, "{:?}", mode);
+                assert_eq!(FixedPoint::half_sum(b, a, mode), expected, "{:?}", mode);
+            }
+        },
+        all {
+            (-3, -2, [-3, -2, -3, -2, -2, -3]);
+            (-1, -4, [-3, -2, -3, -2, -2, -3]);
+            (-5, -6, [-6, -5, -6, -6, -5, -6]);
+            (-1, 0, [-1, 0, -1, 0, 0, -1]);
+            (-8, 3, [-3, -2, -3, -2, -2, -3]);
+            (2, 3, [2, 3, 3, 2, 2, 3]);
+            (5, 6, [5, 6, 6, 6, 5, 6]);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn half_sum_rounded() -> Result<()> {
     test_fixed_point! {
@@ -451,6 +693,186 @@ fn half_sum_rounded() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn lerp() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, t: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::lerp(a, b, t, mode)?, expected);
+            let mirrored = match mode {
+                Floor => Ceil,
+                Ceil => Floor,
+                mode => mode,
+            };
+            assert_eq!(FixedPoint::lerp(a.cneg()?, b.cneg()?, t, mirrored)?, expected.cneg()?);
+        },
+        all {
+            (fp!(1), fp!(3), fp!(0), Floor, fp!(1));
+            (fp!(1), fp!(3), fp!(1), Ceil, fp!(3));
+            (fp!(1), fp!(3), fp!(0.25), Floor, fp!(1.5));
+            (fp!(-2), fp!(2), fp!(0.75), Nearest, fp!(1));
+            (fp!(1), fp!(3), fp!(2), Floor, fp!(5));
+            (fp!(1), fp!(3), fp!(-1), Ceil, fp!(-1));
+            (fp!(0), FixedPoint::EPSILON, fp!(0.5), Floor, fp!(0));
+            (fp!(0), FixedPoint::EPSILON, fp!(0.5), Ceil, FixedPoint::EPSILON);
+            (fp!(0), FixedPoint::EPSILON, fp!(0.5), Nearest, FixedPoint::EPSILON);
+            (fp!(0), FixedPoint::EPSILON, fp!(0.5), HalfEven, fp!(0));
+            (fp!(0), FixedPoint::EPSILON, fp!(0.5), TowardZero, fp!(0));
+            (FixedPoint::MAX, fp!(0), fp!(1), Floor, fp!(0));
+            (fp!(-1), fp!(2), fp!(0.333333333), Nearest, fp!(-0.000000001));
+        },
+        fp64 {
+            (fp!(0), fp!(0.000000003), fp!(0.5), HalfEven, fp!(0.000000002));
+        },
+        fp128 {
+            (fp!(-1), fp!(2), fp!(0.333333333333333333), Nearest, fp!(-0.000000000000000001));
+            (fp!(0), fp!(0.000000000000000003), fp!(0.5), HalfEven, fp!(0.000000000000000002));
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, t: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::lerp(a, b, t, mode)?, expected);
+        },
+        all {
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(0), Ceil, FixedPoint::MIN);
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(1), Floor, FixedPoint::MAX);
+            (FixedPoint::MAX, FixedPoint::MIN, fp!(1), Ceil, FixedPoint::MIN);
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(0.5), Ceil, fp!(0));
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(0.5), Floor, fp!(0).csub(FixedPoint::EPSILON)?);
+            (FixedPoint::MAX, FixedPoint::MIN, fp!(0.5), Nearest, fp!(0).csub(FixedPoint::EPSILON)?);
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, t: FixedPoint) => {
+            assert_eq!(FixedPoint::lerp(a, b, t, Floor), Err(ArithmeticError::Overflow));
+        },
+        all {
+            (FixedPoint::MIN, FixedPoint::MAX, fp!(2));
+            (fp!(0), FixedPoint::MAX, fp!(1.5));
+            (fp!(0), FixedPoint::MIN, fp!(-1));
+            (FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MAX);
+            (FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn trunc_and_fract() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, trunc: FixedPoint, fract: FixedPoint) => {
+            assert_eq!(x.trunc(), trunc);
+            assert_eq!(x.fract(), fract);
+            assert_eq!(x.trunc().cadd(x.fract())?, x);
+            assert_eq!(x.trunc(), FixedPoint::from_bits(x.integral(TowardZero) * FixedPoint::ONE.into_bits()));
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(42), fp!(42), fp!(0));
+            (fp!(-42), fp!(-42), fp!(0));
+            (fp!(2.75), fp!(2), fp!(0.75));
+            (fp!(-2.75), fp!(-2), fp!(-0.75));
+            (fp!(0.5), fp!(0), fp!(0.5));
+            (fp!(-0.5), fp!(0), fp!(-0.5));
+        },
+        fp64 {
+            (FixedPoint::MAX, fp!(9223372036), fp!(0.854775807));
+            (FixedPoint::MIN, fp!(-9223372036), fp!(-0.854775808));
+        },
+        fp128 {
+            (FixedPoint::MAX, fp!(170141183460469231731), fp!(0.687303715884105727));
+            (FixedPoint::MIN, fp!(-170141183460469231731), fp!(-0.687303715884105728));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn round_to() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, decimal_places: u32, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(x.round_to(decimal_places, mode)?, expected);
+            let mirrored = match mode {
+                Floor => Ceil,
+                Ceil => Floor,
+                mode => mode,
+            };
+            assert_eq!(x.cneg()?.round_to(decimal_places, mirrored)?, expected.cneg()?);
+        },
+        all {
+            (fp!(2.345), 2, Floor, fp!(2.34));
+            (fp!(2.345), 2, Ceil, fp!(2.35));
+            (fp!(2.345), 2, TowardZero, fp!(2.34));
+            (fp!(2.345), 2, AwayFromZero, fp!(2.35));
+            (fp!(2.345), 2, Nearest, fp!(2.35));
+            (fp!(2.345), 2, HalfEven, fp!(2.34));
+            (fp!(2.355), 2, HalfEven, fp!(2.36));
+            (fp!(2.346), 2, HalfEven, fp!(2.35));
+            (fp!(2.5), 0, Nearest, fp!(3));
+            (fp!(2.5), 0, HalfEven, fp!(2));
+            (fp!(0.001), 1, Ceil, fp!(0.1));
+            (fp!(0.001), 1, Floor, fp!(0));
+            (fp!(42), 0, Ceil, fp!(42));
+            (fp!(2.345), 3, Floor, fp!(2.345));
+            (fp!(2.345), 100, Floor, fp!(2.345));
+        },
+        fp64 {
+            (FixedPoint::MAX, 8, Floor, fp!(9223372036.85477580));
+        },
+        fp128 {
+            (fp!(0.123456789123456789), 17, Floor, fp!(0.12345678912345678));
+            (fp!(0.123456789123456789), 18, Floor, fp!(0.123456789123456789));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.round_to(0, Ceil), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.round_to(0, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MAX.round_to(0, Floor)?, FixedPoint::MAX.floor());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn apply_bps() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, bps: i32, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(x.apply_bps(bps, mode)?, expected);
+        },
+        all {
+            (fp!(100), 50, Floor, fp!(100.5));
+            (fp!(100), -50, Ceil, fp!(99.5));
+            (fp!(100), 0, Floor, fp!(100));
+            (fp!(100), -10000, Floor, fp!(0));
+            (fp!(100), 10000, Floor, fp!(200));
+            (fp!(-100), 50, Floor, fp!(-100.5));
+            (fp!(0), 50, Ceil, fp!(0));
+        },
+        fp64 {
+            // Buys round up, sells round down, regardless of the sign.
+            (fp!(0.123456789), 50, Ceil, fp!(0.124074073));
+            (fp!(0.123456789), 50, Floor, fp!(0.124074072));
+            (fp!(0.123456789), -50, Floor, fp!(0.122839505));
+            (fp!(0.123456789), -50, Ceil, fp!(0.122839506));
+            (fp!(0.123456789).cneg()?, 50, Floor, fp!(0.124074073).cneg()?);
+            (fp!(0.123456789).cneg()?, -50, Ceil, fp!(0.122839505).cneg()?);
+            (fp!(0.000000001), 1, Ceil, fp!(0.000000002));
+            (fp!(0.000000001), 1, Nearest, fp!(0.000000001));
+        },
+        fp128 {
+            (fp!(0.123456789012345678), 50, Ceil, fp!(0.124074072957407407));
+            (fp!(0.123456789012345678), 50, Floor, fp!(0.124074072957407406));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MAX.apply_bps(1, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MAX.apply_bps(-1, Ceil)?, FixedPoint::MAX.rmul(fp!(0.9999), Ceil)?);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn integral() -> Result<()> {
     test_fixed_point! {
@@ -478,6 +900,243 @@ fn integral() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn half_even() -> Result<()> {
+    test_fixed_point! {
+        case (ulps: Layout, expected_even: Layout, expected_nearest: Layout) => {
+            // `ulps / 2` is always a tie.
+            let value = FixedPoint::EPSILON.cmul(ulps)?;
+            let half = fp!(0.5);
+            for (mode, expected) in [(HalfEven, expected_even), (Nearest, expected_nearest)] {
+                let expected = FixedPoint::EPSILON.cmul(expected)?;
+                assert_eq!(value.rmul(half, mode)?, expected, "rmul {:?}", mode);
+                assert_eq!(value.rdiv(fp!(2), mode)?, expected, "rdiv {:?}", mode);
+                assert_eq!(value.rdiv(2, mode)?, expected, "rdiv by layout {:?}", mode);
+                assert_eq!(ulps.rdiv(2, mode)?, expected.into_bits(), "rdiv of layout {:?}", mode);
+                assert_eq!(FixedPoint::half_sum(FixedPoint::ZERO, value, mode), expected, "half_sum {:?}", mode);
+            }
+        },
+        all {
+            (1, 0, 1);
+            (3, 2, 2);
+            (5, 2, 3);
+            (7, 4, 4);
+            (-1, 0, -1);
+            (-3, -2, -2);
+            (-5, -2, -3);
+            (-7, -4, -4);
+        },
+    };
+    test_fixed_point! {
+        case (a: Layout, b: Layout, expected: Layout) => {
+            // Operands of the same sign, the parity of the result matters.
+            let (a, b) = (FixedPoint::EPSILON.cmul(a)?, FixedPoint::EPSILON.cmul(b)?);
+            let expected = FixedPoint::EPSILON.cmul(expected)?;
+            assert_eq!(FixedPoint::half_sum(a, b, HalfEven), expected);
+            assert_eq!(FixedPoint::half_sum(b, a, HalfEven), expected);
+        },
+        all {
+            (1, 2, 2);
+            (1, 4, 2);
+            (2, 5, 4);
+            (4, 5, 4);
+            (5, 6, 6);
+            (-1, -2, -2);
+            (-1, -4, -2);
+            (-2, -5, -4);
+            (-4, -5, -4);
+            (-5, -6, -6);
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint, expected: Layout) => {
+            assert_eq!(value.integral(HalfEven), expected);
+            assert_eq!(value.cneg()?.integral(HalfEven), -expected);
+            // Square roots have no ties.
+            assert_eq!(value.abs()?.rsqrt(HalfEven)?, value.abs()?.rsqrt(Nearest)?);
+        },
+        all {
+            (fp!(0.5), 0);
+            (fp!(1.5), 2);
+            (fp!(2.5), 2);
+            (fp!(2.4), 2);
+            (fp!(2.6), 3);
+            (fp!(3.5), 4);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn nearest_ties_away_from_zero() -> Result<()> {
+    test_fixed_point! {
+        case (ulps: Layout, expected: Layout) => {
+            // `ulps / 2` is always a tie, all operations must round it away from zero.
+            let value = FixedPoint::EPSILON.cmul(ulps)?;
+            let expected = FixedPoint::EPSILON.cmul(expected)?;
+            let two = fp!(2);
+            let minus_two = two.cneg()?;
+
+            assert_eq!(value.rmul(fp!(0.5), Nearest)?, expected, "rmul");
+            assert_eq!(value.rmul(fp!(0.5).cneg()?, Nearest)?, expected.cneg()?, "rmul by negative");
+            assert_eq!(value.rdiv(two, Nearest)?, expected, "rdiv");
+            assert_eq!(value.rdiv(minus_two, Nearest)?, expected.cneg()?, "rdiv by negative");
+            assert_eq!(value.cneg()?.rdiv(minus_two, Nearest)?, expected, "rdiv of negative by negative");
+            assert_eq!(value.rdiv(-2, Nearest)?, expected.cneg()?, "rdiv by negative layout");
+            assert_eq!(value.rmul_div(fp!(1), two, Nearest)?, expected, "rmul_div");
+            assert_eq!(value.rmul_div(fp!(1), minus_two, Nearest)?, expected.cneg()?, "rmul_div by negative");
+        },
+        all {
+            (1, 1);
+            (3, 2);
+            (5, 3);
+            (-1, -1);
+            (-3, -2);
+            (-5, -3);
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint, expected: Layout) => {
+            assert_eq!(value.integral(Nearest), expected, "integral");
+            assert_eq!(value.round(), FixedPoint::try_from(expected)?, "round");
+            assert_eq!(value.rescale::<typenum::U0>(Nearest)?.into_bits(), expected, "rescale");
+            assert_eq!(value.to_string().parse::<FixedPoint>()?, value, "FromStr");
+        },
+        all {
+            (fp!(0.5), 1);
+            (fp!(-0.5), -1);
+            (fp!(2.5), 3);
+            (fp!(-2.5), -3);
+            (fp!(-3.5), -4);
+        },
+    };
+    test_fixed_point! {
+        case (input: &str, expected: FixedPoint) => {
+            assert_eq!(input.parse::<FixedPoint>()?, expected);
+        },
+        fp64 {
+            ("0.0000000005", fp!(0.000000001));
+            ("-0.0000000005", fp!(0.000000001).cneg()?);
+            ("-0.0000000015", fp!(0.000000002).cneg()?);
+            ("-2.0000000025", fp!(2.000000003).cneg()?);
+        },
+        fp128 {
+            ("0.0000000000000000005", fp!(0.000000000000000001));
+            ("-0.0000000000000000005", fp!(0.000000000000000001).cneg()?);
+            ("-0.0000000000000000015", fp!(0.000000000000000002).cneg()?);
+            ("-2.0000000000000000025", fp!(2.000000000000000003).cneg()?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i64", feature = "std"))]
+fn nearest_ties_of_literals_and_floats() -> Result<()> {
+    use fixnum::fixnum_round;
+    type F = FixedPoint<i64, typenum::U1>;
+
+    for (input, literal, float, expected) in [
+        (
+            "0.05",
+            fixnum_round!(0.05, 1, Nearest),
+            0.25,
+            ("0.1", "0.3"),
+        ),
+        (
+            "-0.05",
+            fixnum_round!(-0.05, 1, Nearest),
+            -0.25,
+            ("-0.1", "-0.3"),
+        ),
+        (
+            "-0.15",
+            fixnum_round!(-0.15, 1, Nearest),
+            -0.75,
+            ("-0.2", "-0.8"),
+        ),
+    ] {
+        let literal: F = literal;
+        assert_eq!(
+            input.parse::<F>()?,
+            expected.0.parse()?,
+            "FromStr {}",
+            input
+        );
+        assert_eq!(literal, expected.0.parse()?, "fixnum_round! {}", input);
+        assert_eq!(F::try_from(float)?, expected.1.parse()?, "f64 {}", float);
+    }
+    Ok(())
+}
+
+#[test]
+fn toward_and_away_from_zero() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, toward: FixedPoint, away: FixedPoint) => {
+            for (a, toward, away) in [(a, toward, away), (a.cneg()?, toward.cneg()?, away.cneg()?)] {
+                assert_eq!(a.rmul(b, TowardZero)?, toward);
+                assert_eq!(a.rmul(b, AwayFromZero)?, away);
+                assert_eq!(b.rmul(a, TowardZero)?, toward);
+                assert_eq!(b.rmul(a, AwayFromZero)?, away);
+            }
+        },
+        fp64 {
+            (fp!(0.000000001), fp!(0.1), fp!(0), fp!(0.000000001));
+            (fp!(1.000000001), fp!(1.5), fp!(1.500000001), fp!(1.500000002));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), fp!(0.1), fp!(0), fp!(0.000000000000000001));
+            (fp!(1.000000000000000001), fp!(1.5), fp!(1.500000000000000001), fp!(1.500000000000000002));
+        },
+        all {
+            (fp!(3), fp!(0.5), fp!(1.5), fp!(1.5));
+        },
+    };
+    test_fixed_point! {
+        case (value: FixedPoint, toward: Layout, away: Layout) => {
+            assert_eq!(value.integral(TowardZero), toward);
+            assert_eq!(value.integral(AwayFromZero), away);
+            assert_eq!(value.cneg()?.integral(TowardZero), -toward);
+            assert_eq!(value.cneg()?.integral(AwayFromZero), -away);
+            assert_eq!(value.rdiv(fp!(3), TowardZero)?.cneg()?, value.cneg()?.rdiv(fp!(3), TowardZero)?);
+            assert_eq!(value.rdiv(fp!(3), AwayFromZero)?.cneg()?, value.cneg()?.rdiv(fp!(3), AwayFromZero)?);
+            assert_eq!(value.rsqrt(TowardZero)?, value.rsqrt(Floor)?);
+            assert_eq!(value.rsqrt(AwayFromZero)?, value.rsqrt(Ceil)?);
+        },
+        all {
+            (fp!(0), 0, 0);
+            (fp!(1.5), 1, 2);
+            (fp!(2), 2, 2);
+            (fp!(2.1), 2, 3);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rounding_near_bounds() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let coef = FixedPoint::ONE.into_bits();
+            let min_int = FixedPoint::from_bits(Layout::MIN / coef * coef);
+            let max_int = FixedPoint::from_bits(Layout::MAX / coef * coef);
+
+            assert_eq!(FixedPoint::MIN.integral(Floor), Layout::MIN / coef - 1);
+            assert_eq!(FixedPoint::MIN.integral(Ceil), Layout::MIN / coef);
+            assert_eq!(FixedPoint::MIN.floor(), min_int);
+            assert_eq!(FixedPoint::MIN.round(), min_int);
+            assert_eq!(FixedPoint::MAX.ceil(), max_int);
+            assert_eq!(FixedPoint::MAX.round(), max_int);
+
+            assert_eq!(FixedPoint::ONE.rdiv(FixedPoint::MIN, Nearest)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::ONE.rdiv(FixedPoint::MIN, Floor)?, FixedPoint::EPSILON.cneg()?);
+            assert_eq!(Layout::MIN.rdiv(-1, Floor), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.to_string().parse::<FixedPoint>()?, FixedPoint::MIN);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn round_towards_zero_by() -> Result<()> {
     test_fixed_point! {
@@ -494,12 +1153,22 @@ fn round_towards_zero_by() -> Result<()> {
             (fp!(1234.56789), fp!(0.001), fp!(1234.567));
             (fp!(1234.56789), fp!(0.0001), fp!(1234.5678));
             (fp!(1234.56789), fp!(0.00001), fp!(1234.56789));
+            (fp!(1234.56789), fp!(-10), fp!(1230));
+            (fp!(1234.56789), fp!(0), fp!(1234.56789));
         },
         fp128 {
             (fp!(1234.56789123456789), fp!(0.0000000000001), fp!(1234.5678912345678));
             (fp!(1234.56789123456789), fp!(0.00000000000001), fp!(1234.56789123456789));
         },
     };
+    test_fixed_point! {
+        case () => {
+            let min = FixedPoint::MIN;
+            assert_eq!(min.round_towards_zero_by(FixedPoint::EPSILON.cneg()?), min);
+            assert_eq!(min.round_towards_zero_by(FixedPoint::MIN), min);
+            assert_eq!(FixedPoint::MAX.round_towards_zero_by(FixedPoint::MIN), FixedPoint::ZERO);
+        },
+    };
     Ok(())
 }
 
@@ -728,9 +1397,120 @@ fn saturating_rmul() -> Result<()> {
 }
 
 #[test]
-fn saturating_sub() -> Result<()> {
+fn saturating_rdiv() -> Result<()> {
     test_fixed_point! {
-        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+        case (a: FixedPoint, b: FixedPoint, mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(a.saturating_rdiv(b, mode), expected);
+            assert_eq!(a.saturating_rdiv(b, mode), a.rdiv(b, mode).unwrap_or(expected));
+        },
+        all {
+            (fp!(0), fp!(-7), Ceil, fp!(0));
+            (fp!(1), fp!(4), Floor, fp!(0.25));
+            (fp!(-3), fp!(2), Floor, fp!(-1.5));
+            (FixedPoint::MAX, fp!(0.5), Floor, FixedPoint::MAX);
+            (FixedPoint::MAX, fp!(-0.5), Floor, FixedPoint::MIN);
+            (FixedPoint::MIN, fp!(0.5), Ceil, FixedPoint::MIN);
+            (FixedPoint::MIN, fp!(-0.5), Ceil, FixedPoint::MAX);
+            (FixedPoint::MIN, fp!(-1), Floor, FixedPoint::MAX);
+        },
+        fp64 {
+            (fp!(1), fp!(3), Floor, fp!(0.333333333));
+            (fp!(1), fp!(3), Ceil, fp!(0.333333334));
+            (fp!(4611686019), fp!(0.5), Floor, FixedPoint::MAX);
+        },
+        fp128 {
+            (fp!(1), fp!(3), Floor, fp!(0.333333333333333333));
+            (fp!(1), fp!(3), Ceil, fp!(0.333333333333333334));
+            (fp!(85070591730234615866), fp!(0.5), Floor, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case (a: FixedPoint, b: Layout, expected: FixedPoint) => {
+            assert_eq!(a.saturating_rdiv(b, Floor), expected);
+        },
+        all {
+            (fp!(1.5), 2, fp!(0.75));
+            (fp!(-7.5), 2, fp!(-3.75));
+            (FixedPoint::MIN, 1, FixedPoint::MIN);
+            (FixedPoint::MIN, -1, FixedPoint::MAX);
+        },
+    };
+    test_fixed_point! {
+        case (a: Layout, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.saturating_rdiv(b, Floor), expected);
+        },
+        all {
+            (3, fp!(2), fp!(1.5));
+            (-3, fp!(0.5), fp!(-6));
+            (Layout::MAX, fp!(0.5), FixedPoint::MAX);
+            (Layout::MAX, fp!(-2), FixedPoint::MIN);
+            (Layout::MIN, fp!(0.5), FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            use std::panic::catch_unwind;
+            assert!(catch_unwind(|| FixedPoint::ONE.saturating_rdiv(FixedPoint::ZERO, Floor)).is_err());
+            assert!(catch_unwind(|| FixedPoint::ONE.saturating_rdiv(0, Floor)).is_err());
+            assert!(catch_unwind(|| Layout::MAX.saturating_rdiv(FixedPoint::ZERO, Floor)).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "i64")]
+fn saturating_ops_of_wrappers() {
+    use derive_more::From;
+
+    type Fp64 = FixedPoint<i64, typenum::U9>;
+
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, From)]
+    struct Size(i32);
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, From)]
+    struct Price(Fp64);
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, From)]
+    struct Amount(Fp64);
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, From)]
+    struct Ratio(Fp64);
+
+    impl_op!(Size [cmul] Price = Amount);
+    impl_op!(Size [rdiv] Size = Ratio);
+    impl_op!(Price [rmul] Ratio = Price);
+    impl_op!(Price [rdiv] Price = Ratio);
+    impl_op!(Amount [rdiv] Size = Price);
+    impl_op!([bounded] Size);
+    impl_op!([bounded] Price);
+    impl_op!([bounded] Amount);
+    impl_op!([bounded] Ratio);
+
+    let half = Fp64::ONE.rdiv(2, Floor).unwrap();
+    assert_eq!(Size(2).saturating_mul(Price(half)), Amount(Fp64::ONE));
+    assert_eq!(Size(2).saturating_mul(Price(Fp64::MAX)), Amount(Fp64::MAX));
+    assert_eq!(Size(-2).saturating_mul(Price(Fp64::MAX)), Amount(Fp64::MIN));
+    assert_eq!(Size(1).saturating_rdiv(Size(2), Floor), Ratio(half));
+    assert_eq!(
+        Size(i32::MIN).saturating_rdiv(Size(-1), Floor),
+        Ratio(Fp64::try_from(1i64 << 31).unwrap())
+    );
+    assert_eq!(
+        Price(Fp64::MAX).saturating_rmul(Ratio(Fp64::MIN), Floor),
+        Price(Fp64::MIN)
+    );
+    assert_eq!(
+        Price(Fp64::MIN).saturating_rdiv(Price(half), Ceil),
+        Ratio(Fp64::MIN)
+    );
+    assert_eq!(
+        Amount(Fp64::MIN).saturating_rdiv(Size(-1), Floor),
+        Price(Fp64::MAX)
+    );
+}
+
+#[test]
+fn saturating_sub() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
             assert_eq!(a.saturating_sub(b), expected);
             assert_eq!(b.saturating_sub(a), expected.cneg()?);
             assert_eq!(a.cneg()?.saturating_sub(b.cneg()?), expected.cneg()?);
@@ -771,6 +1551,269 @@ fn saturating_sub() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn overflowing() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint) => {
+            let (sum, overflow) = a.overflowing_add(b);
+            assert_eq!(overflow, a.cadd(b).is_err());
+            assert_eq!(sum.into_bits(), a.into_bits().wrapping_add(b.into_bits()));
+
+            let (diff, overflow) = a.overflowing_sub(b);
+            assert_eq!(overflow, a.csub(b).is_err());
+            assert_eq!(diff.into_bits(), a.into_bits().wrapping_sub(b.into_bits()));
+
+            let (neg, overflow) = a.overflowing_neg();
+            assert_eq!(overflow, a.cneg().is_err());
+            assert_eq!(neg.into_bits(), a.into_bits().wrapping_neg());
+
+            let (product, overflow) = a.overflowing_mul(3);
+            assert_eq!(overflow, a.cmul(3).is_err());
+            assert_eq!(product.into_bits(), a.into_bits().wrapping_mul(3));
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(1.5), fp!(-2.25));
+            (fp!(-1000.0000002), fp!(3000.0000006));
+            (FixedPoint::MAX, FixedPoint::EPSILON);
+            (FixedPoint::MIN, FixedPoint::EPSILON);
+            (FixedPoint::MAX, FixedPoint::MIN);
+            (FixedPoint::MIN, FixedPoint::MIN);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(fp!(0.1).overflowing_add(fp!(0.2)), (fp!(0.3), false));
+            assert_eq!(FixedPoint::MAX.overflowing_add(FixedPoint::EPSILON), (FixedPoint::MIN, true));
+            assert_eq!(FixedPoint::MIN.overflowing_sub(FixedPoint::EPSILON), (FixedPoint::MAX, true));
+            assert_eq!(FixedPoint::MIN.overflowing_neg(), (FixedPoint::MIN, true));
+            assert_eq!(fp!(1.5).overflowing_mul(-2), (fp!(3).cneg()?, false));
+            assert_eq!(FixedPoint::MIN.overflowing_mul(-1), (FixedPoint::MIN, true));
+            assert_eq!(FixedPoint::MAX.overflowing_neg(), (FixedPoint::MIN.cadd(FixedPoint::EPSILON)?, false));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn sign_predicates() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, zero: bool, positive: bool, negative: bool) => {
+            assert_eq!(x.is_zero(), zero);
+            assert_eq!(x.is_positive(), positive);
+            assert_eq!(x.is_negative(), negative);
+            assert_eq!(x.is_positive(), x > FixedPoint::ZERO);
+            assert_eq!(x.is_negative(), x < FixedPoint::ZERO);
+        },
+        all {
+            (fp!(0), true, false, false);
+            (fp!(1.5), false, true, false);
+            (fp!(-1.5), false, false, true);
+            (FixedPoint::EPSILON, false, true, false);
+            (FixedPoint::EPSILON.cneg()?, false, false, true);
+            (FixedPoint::MAX, false, true, false);
+            (FixedPoint::MIN, false, false, true);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            const IS_ZERO: bool = FixedPoint::ZERO.is_zero();
+            assert!(IS_ZERO);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn is_integer() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: bool) => {
+            assert_eq!(x.is_integer(), expected);
+            assert_eq!(x.is_integer(), x.fract() == FixedPoint::ZERO);
+        },
+        all {
+            (fp!(0), true);
+            (fp!(42), true);
+            (fp!(-42), true);
+            (fp!(0.5), false);
+            (fp!(-1.5), false);
+            (FixedPoint::EPSILON, false);
+            (FixedPoint::MAX, false);
+            (FixedPoint::MIN, false);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            const IS_INTEGER: bool = FixedPoint::ONE.is_integer();
+            assert!(IS_INTEGER);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn signum_fp() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.signum_fp(), expected);
+            assert_eq!(x.signum_fp().into_bits(), x.signum() * FixedPoint::ONE.into_bits());
+            assert_eq!(x.signum_fp().rmul(x, RoundMode::Floor)?, x.abs()?);
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(42.5), fp!(1));
+            (fp!(-42.5), fp!(-1));
+            (FixedPoint::EPSILON, fp!(1));
+            (FixedPoint::MAX, fp!(1));
+            (FixedPoint::EPSILON.cneg()?, fp!(-1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn copysign() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, sign: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.copysign(sign)?, expected);
+            assert_eq!(x.copysign(sign)?.abs()?, x.abs()?);
+        },
+        all {
+            (fp!(1.5), fp!(2), fp!(1.5));
+            (fp!(1.5), fp!(-2), fp!(-1.5));
+            (fp!(-1.5), fp!(2), fp!(1.5));
+            (fp!(-1.5), fp!(-0.1), fp!(-1.5));
+            (fp!(-1.5), fp!(0), fp!(1.5));
+            (fp!(0), fp!(-1), fp!(0));
+            (FixedPoint::MAX, fp!(-1), FixedPoint::MIN.cadd(FixedPoint::EPSILON)?);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::MIN.copysign(fp!(1).cneg()?)?, FixedPoint::MIN);
+            assert_eq!(FixedPoint::MIN.copysign(fp!(1)), Err(ArithmeticError::Overflow));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn abs_diff() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(a.abs_diff(b).into_bits() as u128, expected.into_bits() as u128);
+            assert_eq!(b.abs_diff(a).into_bits() as u128, expected.into_bits() as u128);
+            assert_eq!(a.csub(b)?.abs()?, expected);
+        },
+        all {
+            (fp!(0), fp!(0), fp!(0));
+            (fp!(1.5), fp!(-2), fp!(3.5));
+            (fp!(-1.5), fp!(-2), fp!(0.5));
+            (FixedPoint::MAX, fp!(0), FixedPoint::MAX);
+            (FixedPoint::MIN, fp!(-1), FixedPoint::MIN.cadd(fp!(1))?.cneg()?);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // `csub` overflows for distances spanning more than half the range.
+            assert!(FixedPoint::MAX.csub(FixedPoint::MIN).is_err());
+            assert!(FixedPoint::MIN.abs().is_err());
+            assert_eq!(FixedPoint::MAX.abs_diff(FixedPoint::MIN).into_bits().count_ones(), Layout::BITS);
+            assert_eq!(FixedPoint::MIN.abs_diff(fp!(0)).into_bits(), 1 << (Layout::BITS - 1));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn convenience_constants() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            assert_eq!(FixedPoint::ONE_HALF, fp!(0.5));
+            assert_eq!(FixedPoint::TEN, fp!(10));
+            assert_eq!(FixedPoint::HUNDRED, fp!(100));
+            assert_eq!(FixedPoint::ONE_HALF.cmul(2)?, FixedPoint::ONE);
+            assert_eq!(FixedPoint::HUNDRED.rdiv(FixedPoint::TEN, Floor)?, FixedPoint::TEN);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn const_min_max_clamp() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, min: FixedPoint, max: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(x.clamp(min, max), expected);
+            assert_eq!(x.clamp(min, max), Ord::clamp(x, min, max));
+            assert_eq!(x.min(min), Ord::min(x, min));
+            assert_eq!(x.max(max), Ord::max(x, max));
+        },
+        all {
+            (fp!(0), fp!(-1), fp!(1), fp!(0));
+            (fp!(-1.5), fp!(-1), fp!(1), fp!(-1));
+            (fp!(1.5), fp!(-1), fp!(1), fp!(1));
+            (fp!(1), fp!(1), fp!(1), fp!(1));
+            (FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MAX, FixedPoint::MIN);
+            (FixedPoint::MAX, FixedPoint::EPSILON, FixedPoint::ONE, FixedPoint::ONE);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            const CLAMPED: FixedPoint = FixedPoint::MAX.clamp(FixedPoint::ZERO, FixedPoint::ONE);
+            const MIN: FixedPoint = FixedPoint::EPSILON.min(FixedPoint::ZERO);
+            const MAX: FixedPoint = FixedPoint::EPSILON.max(FixedPoint::ZERO);
+            assert_eq!(CLAMPED, FixedPoint::ONE);
+            assert_eq!(MIN, FixedPoint::ZERO);
+            assert_eq!(MAX, FixedPoint::EPSILON);
+
+            let result = std::panic::catch_unwind(|| FixedPoint::ZERO.clamp(FixedPoint::ONE, FixedPoint::ZERO));
+            assert!(result.is_err());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn assign() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, n: Layout) => {
+            // `self` is left unchanged on overflow.
+            let mut x = a;
+            assert_eq!(x.cadd_assign(b).ok(), a.cadd(b).ok().map(|_| ()));
+            assert_eq!(x, a.cadd(b).unwrap_or(a));
+
+            let mut x = a;
+            assert_eq!(x.csub_assign(b).ok(), a.csub(b).ok().map(|_| ()));
+            assert_eq!(x, a.csub(b).unwrap_or(a));
+
+            let mut x = a;
+            assert_eq!(x.cmul_assign(n).ok(), a.cmul(n).ok().map(|_| ()));
+            assert_eq!(x, a.cmul(n).unwrap_or(a));
+
+            let mut x = a;
+            x.saturating_add_assign(b);
+            assert_eq!(x, a.saturating_add(b));
+
+            let mut x = a;
+            x.saturating_sub_assign(b);
+            assert_eq!(x, a.saturating_sub(b));
+
+            let mut x = a;
+            x.saturating_mul_assign(n);
+            assert_eq!(x, a.saturating_mul(n));
+        },
+        all {
+            (fp!(0), fp!(0), 0);
+            (fp!(1.5), fp!(-2.25), 3);
+            (fp!(-1000.0000002), fp!(3000.0000006), -7);
+            (FixedPoint::MAX, FixedPoint::EPSILON, 2);
+            (FixedPoint::MIN, FixedPoint::EPSILON, -1);
+            (FixedPoint::MIN, FixedPoint::MAX, Layout::MAX);
+        },
+    };
+    Ok(())
+}
+
 #[test]
 fn sqrt_exact() -> Result<()> {
     test_fixed_point! {
@@ -839,3 +1882,994 @@ fn sqrt_negative() -> Result<()> {
     };
     Ok(())
 }
+
+#[test]
+fn cbrt_exact() -> Result<()> {
+    test_fixed_point! {
+        case (expected: FixedPoint) => {
+            let cube = expected.rmul(expected, Floor)?.rmul(expected, Floor)?;
+            assert_eq!(cube.rcbrt(Floor)?, expected, "Floor");
+            assert_eq!(cube.rcbrt(Nearest)?, expected, "Nearest");
+            assert_eq!(cube.rcbrt(Ceil)?, expected, "Ceil");
+            assert_eq!(cube.cneg()?.rcbrt(Floor)?, expected.cneg()?, "Floor of negative");
+        },
+        all {
+            (fp!(0));
+            (fp!(0.001));
+            (fp!(1));
+            (fp!(2));
+            (fp!(3));
+            (fp!(1000));
+            (fp!(2097));
+        },
+        fp128 {
+            (fp!(5541191));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn cbrt_approx() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected_floor: FixedPoint, expected_nearest: FixedPoint) => {
+            assert_eq!(x.rcbrt(Floor)?, expected_floor, "Floor");
+            assert_eq!(x.rcbrt(Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(x.rcbrt(Ceil)?, expected_floor.cadd(FixedPoint::EPSILON)?, "Ceil");
+        },
+        fp64 {
+            (fp!(2), fp!(1.259921049), fp!(1.25992105));
+            (fp!(-2), fp!(-1.25992105), fp!(-1.25992105));
+            (fp!(10), fp!(2.154434690), fp!(2.15443469));
+            (FixedPoint::MAX, fp!(2097.151999999), fp!(2097.152));
+        },
+        fp128 {
+            (fp!(2), fp!(1.259921049894873164), fp!(1.259921049894873165));
+            (fp!(-2), fp!(-1.259921049894873165), fp!(-1.259921049894873165));
+            (fp!(10), fp!(2.154434690031883721), fp!(2.154434690031883722));
+            (FixedPoint::MAX, fp!(5541191.377756635628281025), fp!(5541191.377756635628281026));
+            (FixedPoint::MIN, fp!(-5541191.377756635628281026), fp!(-5541191.377756635628281026));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn cbrt_rounding_of_negative() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint) => {
+            assert_eq!(x.cneg()?.rcbrt(Floor)?, x.rcbrt(Ceil)?.cneg()?);
+            assert_eq!(x.cneg()?.rcbrt(TowardZero)?, x.rcbrt(Floor)?.cneg()?);
+            assert_eq!(x.cneg()?.rcbrt(AwayFromZero)?, x.rcbrt(Ceil)?.cneg()?);
+            assert_eq!(x.cneg()?.rcbrt(Nearest)?, x.rcbrt(Nearest)?.cneg()?);
+        },
+        all {
+            (fp!(0.5));
+            (fp!(2));
+            (fp!(7));
+            (FixedPoint::EPSILON);
+            (FixedPoint::MAX);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn log1p_and_expm1_approx() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, log1p: (FixedPoint, FixedPoint), expm1: (FixedPoint, FixedPoint)) => {
+            assert_eq!(x.rlog1p(Floor)?, log1p.0, "log1p Floor");
+            assert_eq!(x.rlog1p(Nearest)?, log1p.1, "log1p Nearest");
+            assert_eq!(x.rlog1p(Ceil)?, log1p.0.cadd(FixedPoint::EPSILON)?, "log1p Ceil");
+            assert_eq!(x.rexpm1(Floor)?, expm1.0, "expm1 Floor");
+            assert_eq!(x.rexpm1(Nearest)?, expm1.1, "expm1 Nearest");
+            assert_eq!(x.rexpm1(Ceil)?, expm1.0.cadd(FixedPoint::EPSILON)?, "expm1 Ceil");
+        },
+        fp64 {
+            (fp!(1), (fp!(0.69314718), fp!(0.693147181)), (fp!(1.718281828), fp!(1.718281828)));
+            (fp!(-0.5), (fp!(-0.693147181), fp!(-0.693147181)), (fp!(-0.393469341), fp!(-0.39346934)));
+            (fp!(2), (fp!(1.098612288), fp!(1.098612289)), (fp!(6.389056098), fp!(6.389056099)));
+            (fp!(10), (fp!(2.397895272), fp!(2.397895273)), (fp!(22025.465794806), fp!(22025.465794807)));
+        },
+        fp128 {
+            (
+                fp!(1),
+                (fp!(0.693147180559945309), fp!(0.693147180559945309)),
+                (fp!(1.718281828459045235), fp!(1.718281828459045235)),
+            );
+            (
+                fp!(-0.5),
+                (fp!(-0.69314718055994531), fp!(-0.693147180559945309)),
+                (fp!(-0.393469340287366577), fp!(-0.393469340287366576)),
+            );
+            (
+                fp!(10),
+                (fp!(2.397895272798370544), fp!(2.397895272798370544)),
+                (fp!(22025.465794806716516957), fp!(22025.465794806716516958)),
+            );
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn exp() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected: (FixedPoint, FixedPoint)) => {
+            assert_eq!(x.rexp(Floor)?, expected.0, "Floor");
+            assert_eq!(x.rexp(Nearest)?, expected.1, "Nearest");
+            assert_eq!(x.rexp(Ceil)?, expected.0.cadd(FixedPoint::EPSILON)?, "Ceil");
+        },
+        fp64 {
+            (fp!(1), (fp!(2.718281828), fp!(2.718281828)));
+            (fp!(-0.5), (fp!(0.606530659), fp!(0.60653066)));
+            (fp!(-0.05), (fp!(0.951229424), fp!(0.951229425)));
+            (fp!(2), (fp!(7.389056098), fp!(7.389056099)));
+            (fp!(10), (fp!(22026.465794806), fp!(22026.465794807)));
+            (fp!(-10), (fp!(0.000045399), fp!(0.0000454)));
+            (fp!(-20), (fp!(0.000000002), fp!(0.000000002)));
+            (fp!(0.000000001), (fp!(1.000000001), fp!(1.000000001)));
+        },
+        fp128 {
+            (fp!(1), (fp!(2.718281828459045235), fp!(2.718281828459045235)));
+            (fp!(-0.5), (fp!(0.606530659712633423), fp!(0.606530659712633424)));
+            (fp!(2), (fp!(7.389056098930650227), fp!(7.389056098930650227)));
+            (fp!(10), (fp!(22026.465794806716516957), fp!(22026.465794806716516958)));
+            (fp!(-10), (fp!(0.000045399929762484), fp!(0.000045399929762485)));
+            (fp!(-20), (fp!(0.000000002061153622), fp!(0.000000002061153622)));
+            (fp!(0.000000001), (fp!(1.000000001), fp!(1.000000001000000001)));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(FixedPoint::ZERO.rexp(mode)?, FixedPoint::ONE);
+                assert_eq!(FixedPoint::MAX.rexp(mode), Err(ArithmeticError::Overflow));
+                assert_eq!(fp!(100).rexp(mode), Err(ArithmeticError::Overflow));
+            }
+
+            // Results less than `EPSILON` are rounded to `ZERO` or `EPSILON`.
+            assert_eq!(FixedPoint::MIN.rexp(Floor)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::MIN.rexp(Nearest)?, FixedPoint::ZERO);
+            assert_eq!(FixedPoint::MIN.rexp(Ceil)?, FixedPoint::EPSILON);
+            assert_eq!(fp!(100).cneg()?.rexp(Ceil)?, FixedPoint::EPSILON);
+
+            // Consistent with `rexpm1`.
+            let x = fp!(0.5);
+            assert_eq!(x.rexp(Nearest)?, x.rexpm1(Nearest)?.cadd(FixedPoint::ONE)?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "i128", feature = "std"))]
+fn transcendental_internal_precision() -> Result<()> {
+    // The internal precision of `i128`, larger ones fail to compile.
+    type Amount = FixedPoint<i128, typenum::U37>;
+
+    let half: Amount = "0.5".parse()?;
+    let ln_half: Amount = "-0.6931471805599453094172321214581765681".parse()?;
+    assert_eq!(half.rln(Nearest)?, ln_half);
+    assert_eq!(half.rlog2(Nearest)?, Amount::ONE.cneg()?);
+    assert_eq!(Amount::ZERO.rexp(Nearest)?, Amount::ONE);
+    assert!(
+        (ln_half.rexp(Nearest)?.csub(half)?.abs()?)
+            < "0.00000000000000000000000000000000001".parse()?
+    );
+    Ok(())
+}
+
+#[test]
+fn sigmoid_and_tanh() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, sigmoid: FixedPoint, tanh: FixedPoint) => {
+            assert_eq!(x.rsigmoid(Nearest)?, sigmoid, "sigmoid");
+            assert_eq!(x.rtanh(Nearest)?, tanh, "tanh");
+            assert!(x.rsigmoid(Floor)? <= sigmoid && sigmoid <= x.rsigmoid(Ceil)?);
+            assert!(x.rtanh(Floor)? <= tanh && tanh <= x.rtanh(Ceil)?);
+        },
+        all {
+            (fp!(0), fp!(0.5), fp!(0));
+            (FixedPoint::MAX, fp!(1), fp!(1));
+            (FixedPoint::MIN, fp!(0), fp!(-1));
+        },
+        fp64 {
+            (fp!(0.5), fp!(0.622459331), fp!(0.462117157));
+            (fp!(1), fp!(0.731058579), fp!(0.761594156));
+            (fp!(-1), fp!(0.268941421), fp!(-0.761594156));
+            (fp!(3), fp!(0.952574127), fp!(0.995054754));
+            (fp!(-7.5), fp!(0.000552779), fp!(-0.999999388));
+            (fp!(20), fp!(0.999999998), fp!(1));
+        },
+        fp128 {
+            (fp!(0.5), fp!(0.622459331201854565), fp!(0.462117157260009759));
+            (fp!(1), fp!(0.731058578630004879), fp!(0.761594155955764888));
+            (fp!(-1), fp!(0.268941421369995121), fp!(-0.761594155955764888));
+            (fp!(3), fp!(0.952574126822433219), fp!(0.995054753686730451));
+            (fp!(-7.5), fp!(0.0005527786369236), fp!(-0.999999388195546149));
+            (fp!(20), fp!(0.999999997938846382), fp!(0.999999999999999992));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn logarithms() -> Result<()> {
+    test_fixed_point! {
+        case (
+            x: FixedPoint,
+            ln: (FixedPoint, FixedPoint),
+            log2: (FixedPoint, FixedPoint),
+            log10: (FixedPoint, FixedPoint),
+        ) => {
+            let eps = FixedPoint::EPSILON;
+            assert_eq!(x.rln(Floor)?, ln.0, "ln Floor");
+            assert_eq!(x.rln(Nearest)?, ln.1, "ln Nearest");
+            assert_eq!(x.rln(Ceil)?, ln.0.cadd(eps)?, "ln Ceil");
+            assert_eq!(x.rlog2(Floor)?, log2.0, "log2 Floor");
+            assert_eq!(x.rlog2(Nearest)?, log2.1, "log2 Nearest");
+            assert_eq!(x.rlog2(Ceil)?, log2.0.cadd(eps)?, "log2 Ceil");
+            assert_eq!(x.rlog10(Floor)?, log10.0, "log10 Floor");
+            assert_eq!(x.rlog10(Nearest)?, log10.1, "log10 Nearest");
+            assert_eq!(x.rlog10(Ceil)?, log10.0.cadd(eps)?, "log10 Ceil");
+        },
+        fp64 {
+            (fp!(3), (fp!(1.098612288), fp!(1.098612289)), (fp!(1.5849625), fp!(1.584962501)), (fp!(0.477121254), fp!(0.477121255)));
+            (fp!(1.05), (fp!(0.048790164), fp!(0.048790164)), (fp!(0.070389327), fp!(0.070389328)), (fp!(0.021189299), fp!(0.021189299)));
+            (fp!(0.3), (fp!(-1.203972805), fp!(-1.203972804)), (fp!(-1.736965595), fp!(-1.736965594)), (fp!(-0.522878746), fp!(-0.522878745)));
+            (fp!(12345.678), (fp!(9.421061321), fp!(9.421061321)), (fp!(13.591718448), fp!(13.591718448)), (fp!(4.091514945), fp!(4.091514946)));
+        },
+        fp128 {
+            (fp!(3), (fp!(1.098612288668109691), fp!(1.098612288668109691)), (fp!(1.584962500721156181), fp!(1.584962500721156181)), (fp!(0.477121254719662437), fp!(0.477121254719662437)));
+            (fp!(1.05), (fp!(0.048790164169432003), fp!(0.048790164169432003)), (fp!(0.070389327891397941), fp!(0.070389327891397941)), (fp!(0.021189299069938072), fp!(0.021189299069938073)));
+            (fp!(0.3), (fp!(-1.203972804325935993), fp!(-1.203972804325935993)), (fp!(-1.736965594166206167), fp!(-1.736965594166206166)), (fp!(-0.522878745280337563), fp!(-0.522878745280337563)));
+            (fp!(12345.678), (fp!(9.421061321291831976), fp!(9.421061321291831977)), (fp!(13.591718448138551158), fp!(13.591718448138551158)), (fp!(4.091514945509201274), fp!(4.091514945509201275)));
+        },
+    };
+    test_fixed_point! {
+        case (x: FixedPoint, log2: Option<FixedPoint>, log10: Option<FixedPoint>) => {
+            // Exact results don't depend on the mode.
+            for mode in [Floor, Nearest, Ceil] {
+                if let Some(log2) = log2 {
+                    assert_eq!(x.rlog2(mode)?, log2, "log2 {:?}", mode);
+                }
+                if let Some(log10) = log10 {
+                    assert_eq!(x.rlog10(mode)?, log10, "log10 {:?}", mode);
+                }
+            }
+        },
+        all {
+            (fp!(1), Some(fp!(0)), Some(fp!(0)));
+            (fp!(2), Some(fp!(1)), None);
+            (fp!(1024), Some(fp!(10)), None);
+            (fp!(0.5), Some(fp!(-1)), None);
+            (fp!(0.125), Some(fp!(-3)), None);
+            (fp!(10), None, Some(fp!(1)));
+            (fp!(1000000), None, Some(fp!(6)));
+            (fp!(0.01), None, Some(fp!(-2)));
+            (fp!(0.000000001), None, Some(fp!(-9)));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), None, Some(fp!(-18)));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(FixedPoint::ONE.rln(mode)?, FixedPoint::ZERO);
+                for x in [FixedPoint::ZERO, FixedPoint::EPSILON.cneg()?, FixedPoint::MIN] {
+                    assert_eq!(x.rln(mode), Err(ArithmeticError::DomainViolation));
+                    assert_eq!(x.rlog2(mode), Err(ArithmeticError::DomainViolation));
+                    assert_eq!(x.rlog10(mode), Err(ArithmeticError::DomainViolation));
+                }
+            }
+
+            // Consistent with `rlog1p` and `rexp`.
+            let x = fp!(2.5);
+            assert_eq!(x.rln(Nearest)?, fp!(1.5).rlog1p(Nearest)?);
+            assert_eq!(x.rln(Nearest)?.rexp(Nearest)?, x);
+
+            let eps = FixedPoint::EPSILON;
+            let precision = FixedPoint::PRECISION as Layout;
+            assert_eq!(eps.rlog10(Nearest)?, FixedPoint::from_decimal(-precision, 0)?);
+            assert!(FixedPoint::MAX.rln(Nearest)? > fp!(20));
+            assert!(FixedPoint::MAX.rlog2(Nearest)? > FixedPoint::MAX.rln(Nearest)?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "trig")]
+fn trigonometry() -> Result<()> {
+    test_fixed_point! {
+        case (
+            x: FixedPoint,
+            sin: (FixedPoint, FixedPoint),
+            cos: (FixedPoint, FixedPoint),
+            tan: (FixedPoint, FixedPoint),
+        ) => {
+            let eps = FixedPoint::EPSILON;
+            assert_eq!(x.rsin(Floor)?, sin.0, "sin Floor");
+            assert_eq!(x.rsin(Nearest)?, sin.1, "sin Nearest");
+            assert_eq!(x.rsin(Ceil)?, sin.0.cadd(eps)?, "sin Ceil");
+            assert_eq!(x.rcos(Floor)?, cos.0, "cos Floor");
+            assert_eq!(x.rcos(Nearest)?, cos.1, "cos Nearest");
+            assert_eq!(x.rcos(Ceil)?, cos.0.cadd(eps)?, "cos Ceil");
+            assert_eq!(x.rtan(Floor)?, tan.0, "tan Floor");
+            assert_eq!(x.rtan(Nearest)?, tan.1, "tan Nearest");
+            assert_eq!(x.rtan(Ceil)?, tan.0.cadd(eps)?, "tan Ceil");
+        },
+        fp64 {
+            (fp!(0.5), (fp!(0.479425538), fp!(0.479425539)), (fp!(0.877582561), fp!(0.877582562)), (fp!(0.546302489), fp!(0.54630249)));
+            (fp!(1), (fp!(0.841470984), fp!(0.841470985)), (fp!(0.540302305), fp!(0.540302306)), (fp!(1.557407724), fp!(1.557407725)));
+            (fp!(-1), (fp!(-0.841470985), fp!(-0.841470985)), (fp!(0.540302305), fp!(0.540302306)), (fp!(-1.557407725), fp!(-1.557407725)));
+            (fp!(2), (fp!(0.909297426), fp!(0.909297427)), (fp!(-0.416146837), fp!(-0.416146837)), (fp!(-2.185039864), fp!(-2.185039863)));
+            (fp!(10), (fp!(-0.544021111), fp!(-0.544021111)), (fp!(-0.83907153), fp!(-0.839071529)), (fp!(0.648360827), fp!(0.648360827)));
+            (fp!(-100.25), (fp!(0.277282856), fp!(0.277282856)), (fp!(0.960788331), fp!(0.960788331)), (fp!(0.288599317), fp!(0.288599317)));
+            (fp!(12345.678), (fp!(-0.704081314), fp!(-0.704081314)), (fp!(0.710119358), fp!(0.710119359)), (fp!(-0.991497141), fp!(-0.991497141)));
+        },
+        fp128 {
+            (fp!(0.5), (fp!(0.479425538604203), fp!(0.479425538604203)), (fp!(0.877582561890372716), fp!(0.877582561890372716)), (fp!(0.546302489843790513), fp!(0.546302489843790513)));
+            (fp!(1), (fp!(0.841470984807896506), fp!(0.841470984807896507)), (fp!(0.540302305868139717), fp!(0.540302305868139717)), (fp!(1.55740772465490223), fp!(1.557407724654902231)));
+            (fp!(-1), (fp!(-0.841470984807896507), fp!(-0.841470984807896507)), (fp!(0.540302305868139717), fp!(0.540302305868139717)), (fp!(-1.557407724654902231), fp!(-1.557407724654902231)));
+            (fp!(2), (fp!(0.909297426825681695), fp!(0.909297426825681695)), (fp!(-0.416146836547142387), fp!(-0.416146836547142387)), (fp!(-2.185039863261518992), fp!(-2.185039863261518992)));
+            (fp!(10), (fp!(-0.544021110889369814), fp!(-0.544021110889369813)), (fp!(-0.839071529076452453), fp!(-0.839071529076452452)), (fp!(0.648360827459086671), fp!(0.648360827459086671)));
+            (fp!(-100.25), (fp!(0.277282856454851303), fp!(0.277282856454851303)), (fp!(0.960788331276061203), fp!(0.960788331276061203)), (fp!(0.288599317277907519), fp!(0.288599317277907519)));
+            (fp!(12345.678), (fp!(-0.704081313753298917), fp!(-0.704081313753298917)), (fp!(0.710119358716144739), fp!(0.71011935871614474)), (fp!(-0.99149714074298402), fp!(-0.99149714074298402)));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // Exact results don't depend on the mode.
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(fp!(0).rsin(mode)?, fp!(0));
+                assert_eq!(fp!(0).rcos(mode)?, fp!(1));
+                assert_eq!(fp!(0).rtan(mode)?, fp!(0));
+            }
+
+            // The nearest number to `pi / 2` is close to the pole of `tan`.
+            let x = fp!(1.570796327);
+            assert_eq!(x.rsin(Nearest)?, fp!(1));
+            assert!(x.rtan(Nearest)? < fp!(3000000).cneg()?);
+
+            // Arguments are reduced modulo `pi / 2` without overflow.
+            for x in [FixedPoint::MAX, FixedPoint::MIN] {
+                assert!(x.rsin(Nearest)?.abs()? <= fp!(1));
+                assert!(x.rcos(Nearest)?.abs()? <= fp!(1));
+            }
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "trig")]
+fn arctangent() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, atan: (FixedPoint, FixedPoint)) => {
+            assert_eq!(x.ratan(Floor)?, atan.0, "Floor");
+            assert_eq!(x.ratan(Nearest)?, atan.1, "Nearest");
+            assert_eq!(x.ratan(Ceil)?, atan.0.cadd(FixedPoint::EPSILON)?, "Ceil");
+            assert_eq!(x.ratan2(fp!(1), Nearest)?, atan.1, "atan2");
+        },
+        fp64 {
+            (fp!(0.5), (fp!(0.463647609), fp!(0.463647609)));
+            (fp!(1), (fp!(0.785398163), fp!(0.785398163)));
+            (fp!(-2), (fp!(-1.107148718), fp!(-1.107148718)));
+            (fp!(1000), (fp!(1.569796327), fp!(1.569796327)));
+        },
+        fp128 {
+            (fp!(0.5), (fp!(0.463647609000806116), fp!(0.463647609000806116)));
+            (fp!(1), (fp!(0.785398163397448309), fp!(0.78539816339744831)));
+            (fp!(-2), (fp!(-1.107148717794090504), fp!(-1.107148717794090503)));
+            (fp!(1000), (fp!(1.569796327128229752), fp!(1.569796327128229753)));
+        },
+    };
+    test_fixed_point! {
+        case (y: FixedPoint, x: FixedPoint, atan2: (FixedPoint, FixedPoint)) => {
+            assert_eq!(y.ratan2(x, Floor)?, atan2.0, "Floor");
+            assert_eq!(y.ratan2(x, Nearest)?, atan2.1, "Nearest");
+            assert_eq!(y.ratan2(x, Ceil)?, atan2.0.cadd(FixedPoint::EPSILON)?, "Ceil");
+        },
+        fp64 {
+            (fp!(1), fp!(1), (fp!(0.785398163), fp!(0.785398163)));
+            (fp!(3), fp!(-4), (fp!(2.498091544), fp!(2.498091545)));
+            (fp!(-0.5), fp!(-0.5), (fp!(-2.356194491), fp!(-2.35619449)));
+            (fp!(-7), fp!(0), (fp!(-1.570796327), fp!(-1.570796327)));
+            (fp!(0), fp!(-1), (fp!(3.141592653), fp!(3.141592654)));
+            (fp!(1), fp!(1000), (fp!(0.000999999), fp!(0.001)));
+        },
+        fp128 {
+            (fp!(1), fp!(1), (fp!(0.785398163397448309), fp!(0.78539816339744831)));
+            (fp!(3), fp!(-4), (fp!(2.498091544796508851), fp!(2.498091544796508852)));
+            (fp!(-0.5), fp!(-0.5), (fp!(-2.356194490192344929), fp!(-2.356194490192344929)));
+            (fp!(-7), fp!(0), (fp!(-1.57079632679489662), fp!(-1.570796326794896619)));
+            (fp!(0), fp!(-1), (fp!(3.141592653589793238), fp!(3.141592653589793238)));
+            (fp!(1), fp!(1000), (fp!(0.000999999666666866), fp!(0.000999999666666867)));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            // Exact results don't depend on the mode.
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(fp!(0).ratan(mode)?, fp!(0));
+                assert_eq!(fp!(0).ratan2(fp!(5), mode)?, fp!(0));
+            }
+            assert_eq!(fp!(0).ratan2(fp!(0), Nearest), Err(ArithmeticError::DomainViolation));
+
+            // Only the ratio matters.
+            let angle = fp!(3).ratan2(fp!(4), Nearest)?;
+            assert_eq!(fp!(0.000000003).ratan2(fp!(0.000000004), Nearest)?, angle);
+            assert_eq!(fp!(3000000).ratan2(fp!(4000000), Nearest)?, angle);
+            assert_eq!(FixedPoint::MAX.ratan2(FixedPoint::MAX, Nearest)?, fp!(1).ratan(Nearest)?);
+            assert_eq!(FixedPoint::MIN.ratan2(FixedPoint::MIN, Nearest)?, fp!(0.5).cneg()?.ratan2(fp!(0.5).cneg()?, Nearest)?);
+            assert_eq!(FixedPoint::MAX.ratan(Nearest)?, FixedPoint::MAX.ratan2(fp!(1), Nearest)?);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn log1p_and_expm1_edges() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let minus_one = FixedPoint::ONE.cneg()?;
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(FixedPoint::ZERO.rlog1p(mode)?, FixedPoint::ZERO);
+                assert_eq!(FixedPoint::ZERO.rexpm1(mode)?, FixedPoint::ZERO);
+                assert_eq!(minus_one.rlog1p(mode), Err(ArithmeticError::DomainViolation));
+                assert_eq!(FixedPoint::MIN.rlog1p(mode), Err(ArithmeticError::DomainViolation));
+                assert_eq!(FixedPoint::MAX.rexpm1(mode), Err(ArithmeticError::Overflow));
+                assert_eq!(fp!(100).rexpm1(mode), Err(ArithmeticError::Overflow));
+                assert!(FixedPoint::MAX.rlog1p(mode)? > fp!(20));
+            }
+
+            // Tiny values don't lose precision.
+            let eps = FixedPoint::EPSILON;
+            assert_eq!(eps.rlog1p(Floor)?, FixedPoint::ZERO);
+            assert_eq!(eps.rlog1p(Nearest)?, eps);
+            assert_eq!(eps.rexpm1(Nearest)?, eps);
+            assert_eq!(eps.rexpm1(Ceil)?, eps.cmul(2)?);
+            assert_eq!(eps.cneg()?.rexpm1(Floor)?, eps.cneg()?);
+
+            // `e^x - 1` is close to `-1` for big negative `x`.
+            assert_eq!(FixedPoint::MIN.rexpm1(Floor)?, minus_one);
+            assert_eq!(FixedPoint::MIN.rexpm1(Ceil)?, minus_one.cadd(eps)?);
+            assert_eq!(fp!(50).cneg()?.rexpm1(Nearest)?, minus_one);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rpow() -> Result<()> {
+    test_fixed_point! {
+        case (base: FixedPoint, exponent: FixedPoint, expected: FixedPoint) => {
+            assert_eq!(base.rpow(exponent, Nearest)?, expected);
+        },
+        all {
+            (fp!(2), fp!(10), fp!(1024));
+            (fp!(0.5), fp!(2).cneg()?, fp!(4));
+            (fp!(0.04), fp!(0.5), fp!(0.2));
+            (fp!(0.04), fp!(1.5), fp!(0.008));
+            (fp!(2).cneg()?, fp!(3), fp!(8).cneg()?);
+            (fp!(2).cneg()?, fp!(2), fp!(4));
+            (fp!(2).cneg()?, fp!(1).cneg()?, fp!(0.5).cneg()?);
+            (fp!(0), fp!(0), fp!(1));
+            (fp!(0), fp!(2.5), fp!(0));
+            (fp!(7.5), fp!(0), fp!(1));
+            (fp!(1), FixedPoint::MAX, fp!(1));
+            (fp!(0.1), fp!(100), fp!(0));
+        },
+        fp64 {
+            (fp!(2), fp!(0.5), fp!(1.414213562));
+            (fp!(1.5), fp!(1.5), fp!(1.837117307));
+            (fp!(1.05), fp!(12.5), fp!(1.840205136));
+            (fp!(3), fp!(0.25).cneg()?, fp!(0.759835686));
+            (fp!(10), fp!(9).cneg()?, FixedPoint::EPSILON);
+        },
+        fp128 {
+            (fp!(2), fp!(0.5), fp!(1.414213562373095049));
+            (fp!(1.5), fp!(1.5), fp!(1.837117307087383574));
+            (fp!(1.05), fp!(12.5), fp!(1.840205135548584653));
+            (fp!(3), fp!(0.25).cneg()?, fp!(0.759835685651592547));
+            (fp!(10), fp!(18).cneg()?, FixedPoint::EPSILON);
+        },
+    };
+    test_fixed_point! {
+        case (base: FixedPoint, exponent: FixedPoint, expected: ArithmeticError) => {
+            assert_eq!(base.rpow(exponent, Nearest), Err(expected));
+        },
+        all {
+            (fp!(2).cneg()?, fp!(0.5), ArithmeticError::DomainViolation);
+            (FixedPoint::MIN, fp!(1.5), ArithmeticError::DomainViolation);
+            (fp!(0), fp!(1).cneg()?, ArithmeticError::DivisionByZero);
+            (fp!(10), fp!(100), ArithmeticError::Overflow);
+            (fp!(0.1), fp!(100).cneg()?, ArithmeticError::Overflow);
+            (FixedPoint::MAX, FixedPoint::MAX, ArithmeticError::Overflow);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let tiny = fp!(0.1).rpow(fp!(100), Ceil)?;
+            assert_eq!(tiny, FixedPoint::EPSILON);
+            let tiny = fp!(0.1).cneg()?.rpow(fp!(101), Floor)?;
+            assert_eq!(tiny, FixedPoint::EPSILON.cneg()?);
+            assert_eq!(FixedPoint::MAX.rpow(FixedPoint::MIN, Floor)?, FixedPoint::ZERO);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn fixed_point_ops() -> Result<()> {
+    fn mean_sqrt<F: FixedPointOps>(values: &[F]) -> Result<F, ArithmeticError> {
+        let (sum, count) = values
+            .iter()
+            .try_fold((F::ZERO, F::ZERO), |(sum, count), &value| {
+                Ok::<_, ArithmeticError>((sum.cadd(value)?, count.cadd(F::ONE)?))
+            })?;
+        sum.rdiv(count, Floor)?.rsqrt(Floor)
+    }
+
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            assert_eq!(mean_sqrt(values)?, expected);
+            assert_eq!(<FixedPoint as FixedPointOps>::PRECISION, FixedPoint::PRECISION);
+            assert_eq!(FixedPointOps::integral(expected, Ceil), expected.integral(Ceil));
+            assert_eq!(<FixedPoint as FixedPointOps>::from_decimal(25, -1)?, fp!(2.5));
+            assert_eq!(<FixedPoint as FixedPointOps>::EPSILON, FixedPoint::EPSILON);
+            assert_eq!(FixedPointOps::into_bits(expected), expected.into_bits());
+            assert_eq!(FixedPointOps::to_decimal(&expected, 0), expected.to_decimal(0));
+        },
+        all {
+            (&[fp!(4)], fp!(2));
+            (&[fp!(1), fp!(2), fp!(3.75)], fp!(1.5));
+        },
+        fp64 {
+            (&[fp!(1), fp!(3), fp!(5)], fp!(1.732050807));
+        },
+        fp128 {
+            (&[fp!(1), fp!(3), fp!(5)], fp!(1.732050807568877293));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn fixed_point_ops_vwap() -> Result<()> {
+    fn vwap<F: FixedPointOps>(trades: &[(F, F)], mode: RoundMode) -> Result<F, ArithmeticError> {
+        let mut volume = F::ZERO;
+        let mut amount = F::ZERO;
+        for &(price, size) in trades {
+            volume = volume.cadd(size)?;
+            amount = amount.cadd(price.rmul(size, mode)?)?;
+        }
+        amount.rdiv(volume, mode)
+    }
+
+    test_fixed_point! {
+        case (trades: &[(FixedPoint, FixedPoint)], mode: RoundMode, expected: FixedPoint) => {
+            assert_eq!(vwap(trades, mode)?, expected);
+        },
+        all {
+            (&[(fp!(10), fp!(1)), (fp!(20), fp!(3))], Floor, fp!(17.5));
+            (&[(fp!(1.5), fp!(2)), (fp!(2.5), fp!(2))], Nearest, fp!(2));
+        },
+        fp64 {
+            (&[(fp!(1), fp!(1)), (fp!(2), fp!(2))], Floor, fp!(1.666666666));
+            (&[(fp!(1), fp!(1)), (fp!(2), fp!(2))], Ceil, fp!(1.666666667));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn checked_product() -> Result<()> {
+    test_fixed_point! {
+        case (
+            values: &[FixedPoint],
+            expected_floor: FixedPoint,
+            expected_nearest: FixedPoint,
+            expected_ceil: FixedPoint,
+        ) => {
+            let product = |mode| FixedPoint::checked_product(values.iter().copied(), mode);
+            assert_eq!(product(Floor)?, expected_floor, "Floor");
+            assert_eq!(product(Nearest)?, expected_nearest, "Nearest");
+            assert_eq!(product(Ceil)?, expected_ceil, "Ceil");
+
+            let reversed = |mode| FixedPoint::checked_product(values.iter().rev().copied(), mode);
+            assert_eq!(reversed(Floor)?, expected_floor, "Floor, reversed");
+            assert_eq!(reversed(Nearest)?, expected_nearest, "Nearest, reversed");
+            assert_eq!(reversed(Ceil)?, expected_ceil, "Ceil, reversed");
+        },
+        all {
+            (&[], fp!(1), fp!(1), fp!(1));
+            (&[fp!(-2.5)], fp!(-2.5), fp!(-2.5), fp!(-2.5));
+            (&[fp!(0.1), fp!(0.1), fp!(0.1)], fp!(0.001), fp!(0.001), fp!(0.001));
+            (&[fp!(1.5), fp!(-2), fp!(3), fp!(0)], fp!(0), fp!(0), fp!(0));
+            (&[fp!(1.5), fp!(-2), fp!(-3), fp!(-0.5)], fp!(-4.5), fp!(-4.5), fp!(-4.5));
+            (&[FixedPoint::MAX, fp!(0.5), fp!(2)], FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX);
+            // The intermediate product exceeds the layout.
+            (&[fp!(1000000000), fp!(1000000000), fp!(0.000000001)], fp!(1000000000), fp!(1000000000), fp!(1000000000));
+            (
+                &[fp!(10000000), fp!(10000000), fp!(10000000), fp!(0.000000001), fp!(0.000000001), fp!(0.000000001)],
+                fp!(0.000001),
+                fp!(0.000001),
+                fp!(0.000001),
+            );
+        },
+        fp64 {
+            (&[fp!(0.5), fp!(0.000000001), fp!(3)], fp!(0.000000001), fp!(0.000000002), fp!(0.000000002));
+            (&[fp!(-0.5), fp!(0.000000001), fp!(3)], fp!(-0.000000002), fp!(-0.000000002), fp!(-0.000000001));
+            (&[fp!(0.4), fp!(0.000000001), fp!(1.25)], fp!(0), fp!(0.000000001), fp!(0.000000001));
+            (
+                &[fp!(1.000000001), fp!(1.000000001), fp!(1.000000001)],
+                fp!(1.000000003),
+                fp!(1.000000003),
+                fp!(1.000000004),
+            );
+            (&[fp!(0.000000001); 5], fp!(0), fp!(0), fp!(0.000000001));
+        },
+        fp128 {
+            (
+                &[fp!(0.5), fp!(0.000000000000000001), fp!(3)],
+                fp!(0.000000000000000001),
+                fp!(0.000000000000000002),
+                fp!(0.000000000000000002),
+            );
+            (
+                &[fp!(-0.5), fp!(0.000000000000000001), fp!(3)],
+                fp!(-0.000000000000000002),
+                fp!(-0.000000000000000002),
+                fp!(-0.000000000000000001),
+            );
+            (
+                &[
+                    fp!(100000000000000),
+                    fp!(100000000000000),
+                    fp!(100000000000000),
+                    fp!(0.000000000000000001),
+                    fp!(0.000000000000000001),
+                    fp!(0.000000000000000001),
+                ],
+                fp!(0.000000000001),
+                fp!(0.000000000001),
+                fp!(0.000000000001),
+            );
+        },
+    };
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: FixedPoint) => {
+            // Rounding after every step overflows.
+            let folded = values.iter().try_fold(fp!(1), |acc, value| acc.rmul(*value, Floor));
+            assert_eq!(folded, Err(ArithmeticError::Overflow));
+
+            assert_eq!(FixedPoint::checked_product(values.iter().copied(), Floor)?, expected);
+        },
+        all {
+            (&[fp!(10000000), fp!(10000000), fp!(10000000), fp!(0.000000001), fp!(0.000000001), fp!(0.000000001)], fp!(0.000001));
+        },
+        fp128 {
+            (
+                &[
+                    fp!(100000000000000),
+                    fp!(100000000000000),
+                    fp!(100000000000000),
+                    fp!(0.000000000000000001),
+                    fp!(0.000000000000000001),
+                    fp!(0.000000000000000001),
+                ],
+                fp!(0.000000000001),
+            );
+        },
+    };
+    test_fixed_point! {
+        case (values: &[FixedPoint]) => {
+            let result = FixedPoint::checked_product(values.iter().copied(), Floor);
+            assert_eq!(result, Err(ArithmeticError::Overflow));
+        },
+        all {
+            (&[FixedPoint::MAX, fp!(2)]);
+            (&[FixedPoint::MIN, FixedPoint::MIN, FixedPoint::MIN]);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn csum_and_try_sum_of() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], expected: Option<FixedPoint>) => {
+            assert_eq!(FixedPoint::csum(values.iter().copied()).ok(), expected);
+
+            let folded = values.iter().try_fold(FixedPoint::ZERO, |acc, value| acc.cadd(*value));
+            assert_eq!(folded.ok(), expected);
+        },
+        all {
+            (&[], Some(fp!(0)));
+            (&[fp!(1.5)], Some(fp!(1.5)));
+            (&[fp!(0.1), fp!(0.2), fp!(-0.3)], Some(fp!(0)));
+            (&[FixedPoint::MAX, FixedPoint::EPSILON], None);
+            (&[FixedPoint::MAX, FixedPoint::EPSILON, FixedPoint::MIN], None);
+            (&[FixedPoint::MAX, FixedPoint::MIN, FixedPoint::EPSILON], Some(fp!(0)));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let (one, minus_one) = (fp!(1), fp!(1).cneg()?);
+            assert_eq!(try_sum_of!(one, fp!(2.5))?, fp!(3.5));
+            assert_eq!(try_sum_of!(one, fp!(2.5), minus_one,)?, fp!(2.5));
+            assert_eq!(try_sum_of!(FixedPoint::MAX, one, minus_one), Err(ArithmeticError::Overflow));
+
+            // Arguments after the overflow aren't evaluated.
+            let mut evaluated = false;
+            let result = try_sum_of!(FixedPoint::MAX, one, {
+                evaluated = true;
+                minus_one
+            });
+            assert_eq!(result, Err(ArithmeticError::Overflow));
+            assert!(!evaluated);
+
+            // Consumes the iterator only until the first overflow.
+            let mut iter = [FixedPoint::MAX, one, minus_one].into_iter();
+            assert_eq!(FixedPoint::csum(iter.by_ref()), Err(ArithmeticError::Overflow));
+            assert_eq!(iter.next(), Some(minus_one));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn ratio() -> Result<()> {
+    test_fixed_point! {
+        case (x: FixedPoint, expected_clamped: FixedPoint) => {
+            assert_eq!(x.clamp01(), expected_clamped);
+            assert_eq!(x.is_ratio(), x == expected_clamped);
+            assert_eq!(UnitInterval::new(x).is_ok(), x.is_ratio());
+            assert_eq!(UnitInterval::new_clamped(x).get(), expected_clamped);
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(0.5), fp!(0.5));
+            (fp!(1), fp!(1));
+            (fp!(1.000000001), fp!(1));
+            (fp!(-0.000000001), fp!(0));
+            (FixedPoint::MAX, fp!(1));
+            (FixedPoint::MIN, fp!(0));
+        },
+    };
+    test_fixed_point! {
+        case (ratio: FixedPoint, x: FixedPoint) => {
+            let ratio = UnitInterval::new(ratio)?;
+            for mode in [Floor, Nearest, Ceil] {
+                assert_eq!(ratio.rmul(x, mode), x.rmul(ratio.get(), mode)?);
+                assert_eq!(ratio.complement().get(), FixedPoint::ONE.csub(ratio.get())?);
+            }
+        },
+        all {
+            (fp!(0), FixedPoint::MAX);
+            (fp!(1), FixedPoint::MAX);
+            (fp!(1), FixedPoint::MIN);
+            (fp!(0.999999999), FixedPoint::MAX);
+            (fp!(0.999999999), FixedPoint::MIN);
+            (fp!(0.5), FixedPoint::MIN);
+            (fp!(0.000000001), FixedPoint::EPSILON);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn select_and_abs_branchless() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint) => {
+            assert_eq!(FixedPoint::select(true, a, b), a);
+            assert_eq!(FixedPoint::select(false, a, b), b);
+            assert_eq!(a.abs_branchless(), a.abs());
+            assert_eq!(b.abs_branchless(), b.abs());
+        },
+        all {
+            (fp!(0), fp!(0));
+            (fp!(1.5), fp!(-2));
+            (fp!(-0.000000001), fp!(0.000000001));
+            (FixedPoint::MAX, FixedPoint::MIN);
+            (FixedPoint::MIN, FixedPoint::EPSILON);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn assert_fp_eq_ulps() -> Result<()> {
+    test_fixed_point! {
+        case (a: FixedPoint, b: FixedPoint, ulps: u32, expected: bool) => {
+            let within = std::panic::catch_unwind(|| fixnum::assert_fp_eq_ulps!(a, b, ulps)).is_ok();
+            assert_eq!(within, expected);
+        },
+        all {
+            (fp!(1), fp!(1), 0, true);
+            (FixedPoint::EPSILON, FixedPoint::EPSILON.cneg()?, 1, false);
+            (FixedPoint::EPSILON, FixedPoint::EPSILON.cneg()?, 2, true);
+            (FixedPoint::MIN, FixedPoint::MAX, u32::MAX, false);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn dot() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], weights: &[FixedPoint], mode: RoundMode, expected: Option<FixedPoint>) => {
+            assert_eq!(FixedPoint::dot(values, weights, mode).ok(), expected);
+
+            if let ([value], [weight]) = (values, weights) {
+                assert_eq!(value.rmul(*weight, mode).ok(), expected);
+            }
+        },
+        all {
+            (&[], &[], Nearest, Some(fp!(0)));
+            (&[fp!(1.5)], &[fp!(2)], Floor, Some(fp!(3)));
+            (&[fp!(0.5), fp!(-2)], &[fp!(3), fp!(0.25)], Ceil, Some(fp!(1)));
+            (&[fp!(0.000000001), fp!(0.000000001)], &[fp!(0.5), fp!(0.5)], Nearest, Some(fp!(0.000000001)));
+            (&[FixedPoint::MAX, FixedPoint::MAX], &[fp!(1), fp!(1)], Nearest, None);
+            // Intermediate sums are out of the layout's range.
+            (&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MAX], &[fp!(2), fp!(1), fp!(-2)], Nearest, Some(FixedPoint::MAX));
+        },
+        fp64 {
+            (&[fp!(0.000000001)], &[fp!(0.5)], Floor, Some(fp!(0)));
+            (&[fp!(0.000000001)], &[fp!(0.5)], Ceil, Some(fp!(0.000000001)));
+            (&[fp!(-0.000000001)], &[fp!(0.5)], Floor, Some(fp!(-0.000000001)));
+            (&[fp!(0.000000001), fp!(-0.000000001)], &[fp!(0.7), fp!(0.1)], Nearest, Some(fp!(0.000000001)));
+        },
+        fp128 {
+            (&[fp!(0.000000000000000001)], &[fp!(0.5)], Floor, Some(fp!(0)));
+            (&[fp!(0.000000000000000001)], &[fp!(0.5)], Ceil, Some(fp!(0.000000000000000001)));
+            (&[fp!(-0.000000000000000001)], &[fp!(0.5)], Floor, Some(fp!(-0.000000000000000001)));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let one = fp!(1);
+            assert_eq!(FixedPoint::dot(&[one], &[], Nearest), Err(ArithmeticError::DomainViolation));
+            assert_eq!(FixedPoint::dot(&[], &[one], Nearest), Err(ArithmeticError::DomainViolation));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn mean() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], mode: RoundMode, expected: Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(FixedPoint::mean(values, mode), expected);
+        },
+        all {
+            (&[], Nearest, Err(ArithmeticError::DivisionByZero));
+            (&[fp!(1.5)], Floor, Ok(fp!(1.5)));
+            (&[fp!(1), fp!(2)], Floor, Ok(fp!(1.5)));
+            (&[fp!(1), fp!(-2), fp!(4)], Ceil, Ok(fp!(1)));
+            (&[fp!(1), fp!(-2), fp!(-2)], Nearest, Ok(fp!(-1)));
+            (&[FixedPoint::MAX, FixedPoint::MAX], Nearest, Ok(FixedPoint::MAX));
+            // Intermediate sums are out of the layout's range.
+            (&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MIN], Floor, Ok(FixedPoint::EPSILON.cneg()?));
+            (&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MIN], Nearest, Ok(FixedPoint::EPSILON.cneg()?));
+            (&[FixedPoint::MAX, FixedPoint::MAX, FixedPoint::MIN, FixedPoint::MIN], Ceil, Ok(fp!(0)));
+        },
+        fp64 {
+            (&[fp!(1), fp!(1), fp!(2)], Floor, Ok(fp!(1.333333333)));
+            (&[fp!(1), fp!(1), fp!(2)], Ceil, Ok(fp!(1.333333334)));
+            (&[fp!(1), fp!(2), fp!(2)], Nearest, Ok(fp!(1.666666667)));
+            (&[fp!(0.000000001), fp!(0)], Nearest, Ok(fp!(0.000000001)));
+            (&[fp!(-0.000000001), fp!(0)], Floor, Ok(fp!(-0.000000001)));
+            (&[fp!(-0.000000001), fp!(0)], TowardZero, Ok(fp!(0)));
+        },
+        fp128 {
+            (&[fp!(1), fp!(1), fp!(2)], Floor, Ok(fp!(1.333333333333333333)));
+            (&[fp!(1), fp!(1), fp!(2)], Ceil, Ok(fp!(1.333333333333333334)));
+            (&[fp!(1), fp!(2), fp!(2)], Nearest, Ok(fp!(1.666666666666666667)));
+            (&[fp!(0.000000000000000001), fp!(0)], Nearest, Ok(fp!(0.000000000000000001)));
+            (&[fp!(-0.000000000000000001), fp!(0)], Floor, Ok(fp!(-0.000000000000000001)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn rpowi() -> Result<()> {
+    test_fixed_point! {
+        case (base: FixedPoint, n: u32, mode: RoundMode, expected: Result<FixedPoint, ArithmeticError>) => {
+            assert_eq!(base.rpowi(n, mode), expected);
+        },
+        all {
+            (fp!(0), 0, Nearest, Ok(fp!(1)));
+            (fp!(0), 5, Nearest, Ok(fp!(0)));
+            (fp!(1.5), 0, Floor, Ok(fp!(1)));
+            (fp!(1.5), 1, Floor, Ok(fp!(1.5)));
+            (fp!(2), 10, Floor, Ok(fp!(1024)));
+            (fp!(-2), 3, Ceil, Ok(fp!(-8)));
+            (fp!(-2), 4, Ceil, Ok(fp!(16)));
+            (fp!(0.5), 3, Floor, Ok(fp!(0.125)));
+            (fp!(1.1), 3, Floor, Ok(fp!(1.331)));
+            (fp!(1), u32::MAX, Floor, Ok(fp!(1)));
+            (fp!(-1), u32::MAX, Floor, Ok(fp!(-1)));
+            (fp!(-1), u32::MAX - 1, Floor, Ok(fp!(1)));
+            (FixedPoint::EPSILON, 2, Nearest, Ok(fp!(0)));
+            (FixedPoint::EPSILON, 2, Ceil, Ok(FixedPoint::EPSILON));
+            (FixedPoint::EPSILON, 3, Ceil, Ok(FixedPoint::EPSILON));
+            (FixedPoint::MAX, 1, Nearest, Ok(FixedPoint::MAX));
+            (FixedPoint::MAX, 2, Nearest, Err(ArithmeticError::Overflow));
+            (fp!(10), 40, Nearest, Err(ArithmeticError::Overflow));
+            (fp!(2), 68, Nearest, Err(ArithmeticError::Overflow));
+            (fp!(2), 33, Nearest, Ok(fp!(8589934592)));
+        },
+        fp64 {
+            (fp!(0.000000001), 2, Floor, Ok(fp!(0)));
+            (fp!(-0.000000001), 3, Floor, Ok(fp!(-0.000000001)));
+            (fp!(1.05), 10, Nearest, Ok(fp!(1.628894627)));
+            (fp!(1.0001), 365, Nearest, Ok(fp!(1.037172411)));
+            // Directed modes never cross the exact value 1.03717241130...
+            (fp!(1.0001), 365, Floor, Ok(fp!(1.037172411)));
+            (fp!(1.0001), 365, Ceil, Ok(fp!(1.037172412)));
+            (fp!(0.999), 1000, Nearest, Ok(fp!(0.367695425)));
+            // Guard digits are not enough for huge powers, but directed modes still never cross
+            // the exact value 2.71828182709...
+            (fp!(1.000000001), 1_000_000_000, Floor, Ok(fp!(2.718281819)));
+            (fp!(1.000000001), 1_000_000_000, Ceil, Ok(fp!(2.718281847)));
+            (fp!(2), 34, Nearest, Err(ArithmeticError::Overflow));
+        },
+        fp128 {
+            (fp!(0.000000000000000001), 2, Floor, Ok(fp!(0)));
+            (fp!(-0.000000000000000001), 3, Floor, Ok(fp!(-0.000000000000000001)));
+            (fp!(1.05), 10, Nearest, Ok(fp!(1.628894626777441406)));
+            (fp!(2), 67, Nearest, Ok(fp!(147573952589676412928)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn allocate() -> Result<()> {
+    test_fixed_point! {
+        case (amount: FixedPoint, weights: &[Layout; 3], expected: [FixedPoint; 3]) => {
+            let parts = amount.allocate(weights)?;
+            assert_eq!(parts, expected);
+            assert_eq!(FixedPoint::csum(parts)?, amount);
+
+            let negated = amount.cneg()?.allocate(weights)?;
+            assert_eq!(negated, [expected[0].cneg()?, expected[1].cneg()?, expected[2].cneg()?]);
+        },
+        all {
+            (fp!(100), &[3, 2, 1], [fp!(50), fp!(100).rdiv(3, Floor)?, fp!(100).rdiv(6, Ceil)?]);
+            (fp!(1), &[1, 0, 1], [fp!(0.5), fp!(0), fp!(0.5)]);
+            (fp!(0), &[1, 2, 3], [fp!(0), fp!(0), fp!(0)]);
+            // Ties are broken by position.
+            (FixedPoint::EPSILON, &[1, 1, 1], [FixedPoint::EPSILON, fp!(0), fp!(0)]);
+            (FixedPoint::EPSILON.cmul(2)?, &[1, 1, 1], [FixedPoint::EPSILON, FixedPoint::EPSILON, fp!(0)]);
+            (FixedPoint::MAX, &[1, 0, 0], [FixedPoint::MAX, fp!(0), fp!(0)]);
+            (FixedPoint::MAX, &[1, 1, 0], [FixedPoint::MAX.rdiv(2, Ceil)?, FixedPoint::MAX.rdiv(2, Floor)?, fp!(0)]);
+        },
+        fp64 {
+            (fp!(0.00000001), &[1, 1, 1], [fp!(0.000000004), fp!(0.000000003), fp!(0.000000003)]);
+            (fp!(1), &[2, 1, 4], [fp!(0.285714286), fp!(0.142857143), fp!(0.571428571)]);
+        },
+        fp128 {
+            (fp!(0.00000000000000001), &[1, 1, 1], [fp!(0.000000000000000004), fp!(0.000000000000000003), fp!(0.000000000000000003)]);
+            (fp!(1), &[2, 1, 4], [fp!(0.285714285714285714), fp!(0.142857142857142857), fp!(0.571428571428571429)]);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let one = fp!(1);
+            assert_eq!(one.allocate(&[1, -1]), Err(ArithmeticError::DomainViolation));
+            assert_eq!(one.allocate(&[0, 0]), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(one.allocate::<0>(&[]), Err(ArithmeticError::DivisionByZero));
+            assert_eq!(one.allocate(&[Layout::MAX, 1]), Err(ArithmeticError::Overflow));
+            assert_eq!(FixedPoint::MIN.allocate(&[1]), Ok([FixedPoint::MIN]));
+            assert_eq!(FixedPoint::MIN.allocate(&[1, 1]), Ok([FixedPoint::MIN.rdiv(2, Floor)?; 2]));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use fixnum::ops::One;
+
+#[test]
+fn too_large() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.compile_fail("tests/it/precision/too_large.rs");
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn too_large_for_layout() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.compile_fail("tests/it/precision/too_large_for_layout.rs");
+}
+
+#[cfg(feature = "i64")]
+#[test]
+fn one_half_for_zero_precision() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.compile_fail("tests/it/precision/one_half_for_zero_precision.rs");
+}
+
+#[test]
+fn max_precision() {
+    macro_rules! check {
+        ($layout:ty, $precision:ident) => {{
+            type F = fixnum::FixedPoint<$layout, typenum::$precision>;
+            assert_eq!(F::MAX_PRECISION, <$layout>::MAX.ilog10() as i32);
+            assert_eq!(F::MAX_PRECISION, F::PRECISION);
+            assert_eq!(*F::ONE.as_bits(), <$layout>::pow(10, F::PRECISION as u32));
+        }};
+    }
+
+    #[cfg(feature = "i16")]
+    check!(i16, U4);
+    #[cfg(feature = "i32")]
+    check!(i32, U9);
+    #[cfg(feature = "i64")]
+    check!(i64, U18);
+    #[cfg(feature = "i128")]
+    check!(i128, U38);
+    #[cfg(feature = "u16")]
+    check!(u16, U4);
+    #[cfg(feature = "u32")]
+    check!(u32, U9);
+    #[cfg(feature = "u64")]
+    check!(u64, U19);
+    #[cfg(feature = "u128")]
+    check!(u128, U38);
+}
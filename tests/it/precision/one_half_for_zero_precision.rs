@@ -0,0 +1,8 @@
+// Ensure that `ONE_HALF` is rejected at compile time if it isn't representable.
+
+use fixnum::{typenum::U0, FixedPoint};
+
+#[allow(dead_code)]
+const VALUE: FixedPoint<i64, U0> = FixedPoint::<i64, U0>::ONE_HALF;
+
+fn main() {}
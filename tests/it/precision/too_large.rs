@@ -0,0 +1,11 @@
+// Ensure that precisions which don't fit any layout are rejected at compile time.
+
+use fixnum::{typenum::U77, Precision};
+
+fn precision<P: Precision>() -> u32 {
+    P::U32
+}
+
+fn main() {
+    precision::<U77>();
+}
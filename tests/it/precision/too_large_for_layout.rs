@@ -0,0 +1,8 @@
+// Ensure that precisions which don't fit the layout are rejected at compile time.
+
+use fixnum::{ops::One, typenum::U19, FixedPoint};
+
+#[allow(dead_code)]
+const VALUE: FixedPoint<i64, U19> = FixedPoint::ONE;
+
+fn main() {}
@@ -0,0 +1,124 @@
+use anyhow::Result;
+use fixnum::{
+    ops::Bounded,
+    prost::{Decimal, Money},
+    ConvertErrorKind,
+};
+use prost::Message;
+
+#[test]
+fn money() -> Result<()> {
+    test_fixed_point! {
+        case (value: FixedPoint, units: i64, nanos: i32) => {
+            let money = value.to_money("USD")?;
+            assert_eq!(money.currency_code, "USD");
+            assert_eq!((money.units, money.nanos), (units, nanos));
+            assert_eq!(FixedPoint::try_from(&money)?, value);
+
+            let decoded = Money::decode(&*money.encode_to_vec())?;
+            assert_eq!(decoded, money);
+        },
+        all {
+            (fp!(0), 0, 0);
+            (fp!(5), 5, 0);
+            (fp!(-5), -5, 0);
+            (fp!(1.75), 1, 750_000_000);
+            (fp!(-1.75), -1, -750_000_000);
+            (fp!(-0.5), 0, -500_000_000);
+            (fp!(0.000000001), 0, 1);
+            (fp!(9223372036.854775807), 9_223_372_036, 854_775_807);
+        },
+        fp128 {
+            (fp!(9223372036854775807.999999999), i64::MAX, 999_999_999);
+            (fp!(-9223372036854775808.999999999), i64::MIN, -999_999_999);
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn money_errors() -> Result<()> {
+    test_fixed_point! {
+        case (units: i64, nanos: i32, expected: ConvertErrorKind) => {
+            let money = Money { currency_code: "EUR".into(), units, nanos };
+            assert_eq!(FixedPoint::try_from(&money).unwrap_err().kind(), expected);
+        },
+        all {
+            (0, 1_000_000_000, ConvertErrorKind::OutOfDomain);
+            (0, -1_000_000_000, ConvertErrorKind::OutOfDomain);
+            (0, i32::MIN, ConvertErrorKind::OutOfDomain);
+            (1, -1, ConvertErrorKind::OutOfDomain);
+            (-1, 1, ConvertErrorKind::OutOfDomain);
+        },
+        fp64 {
+            (i64::MAX, 0, ConvertErrorKind::IntegralOverflow);
+            (i64::MIN, -1, ConvertErrorKind::IntegralOverflow);
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            let money = |units, nanos| Money { currency_code: "EUR".into(), units, nanos };
+            assert_eq!(FixedPoint::try_from(&money(0, -1))?, fp!(0.000000001).cneg()?);
+
+            let kind = |value: FixedPoint| value.to_money("EUR").unwrap_err().kind();
+            assert_eq!(kind(FixedPoint::MAX), ConvertErrorKind::IntegralOverflow);
+            assert_eq!(kind(FixedPoint::MIN), ConvertErrorKind::IntegralOverflow);
+            assert_eq!(kind(fp!(0.000000000000000001)), ConvertErrorKind::FractionalPrecision);
+        },
+        fp128 {
+            ();
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn decimal() -> Result<()> {
+    test_fixed_point! {
+        case (value: &str, expected: FixedPoint) => {
+            let decimal = Decimal { value: value.into() };
+            assert_eq!(FixedPoint::try_from(&decimal)?, expected);
+            assert_eq!(FixedPoint::try_from(&Decimal::from(expected))?, expected);
+        },
+        all {
+            ("0", fp!(0));
+            ("-0", fp!(0));
+            ("0.00", fp!(0));
+            ("0e999999999999", fp!(0));
+            ("1.75", fp!(1.75));
+            ("+1.75", fp!(1.75));
+            ("-1.75", fp!(-1.75));
+            ("-175e-2", fp!(-1.75));
+            ("0.0175E+2", fp!(1.75));
+            (".5", fp!(0.5));
+            ("5.", fp!(5));
+            ("1e3", fp!(1000));
+            ("00120.500", fp!(120.5));
+            ("1e-9", fp!(0.000000001));
+        },
+        fp128 {
+            ("1e-18", fp!(0.000000000000000001));
+            ("1.5e20", fp!(150000000000000000000));
+        },
+    };
+    test_fixed_point! {
+        case () => {
+            assert_eq!(Decimal::from(fp!(1.75).cneg()?).value, "-1.75");
+            assert_eq!(Decimal::from(fp!(0.000000001)).value, "0.000000001");
+
+            let kind = |value: &str| {
+                FixedPoint::try_from(&Decimal { value: value.into() }).unwrap_err().kind()
+            };
+
+            assert_eq!(kind("1e-19"), ConvertErrorKind::FractionalPrecision);
+            assert_eq!(kind("1e-99999999999999999999"), ConvertErrorKind::FractionalPrecision);
+            assert_eq!(kind("1e39"), ConvertErrorKind::IntegralOverflow);
+            assert_eq!(kind("1e99999999999999999999"), ConvertErrorKind::IntegralOverflow);
+
+            for invalid in ["", "-", "+-1", "--1", ".", "e5", "1e", "1e+-5", "1e5.0", "1.2.3", "1_0", " 1", "0x10"] {
+                assert_eq!(kind(invalid), ConvertErrorKind::InvalidSyntax, "{:?}", invalid);
+            }
+        },
+    };
+    Ok(())
+}
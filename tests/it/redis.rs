@@ -0,0 +1,65 @@
+use anyhow::Result;
+use redis::{FromRedisValue, ToRedisArgs, Value};
+
+#[test]
+fn roundtrip() -> Result<()> {
+    test_fixed_point! {
+        case (value: FixedPoint, expected: &str) => {
+            assert_eq!(value.to_redis_args(), [expected.as_bytes()]);
+
+            let bulk = Value::BulkString(expected.into());
+            assert_eq!(FixedPoint::from_redis_value(bulk)?, value);
+            let simple = Value::SimpleString(expected.into());
+            assert_eq!(FixedPoint::from_redis_value(simple)?, value);
+        },
+        all {
+            (fp!(0), "0.0");
+            (fp!(42), "42.0");
+            (fp!(-1.75), "-1.75");
+            (fp!(0.000000001), "0.000000001");
+        },
+        fp128 {
+            (fp!(0.000000000000000001), "0.000000000000000001");
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn from_int() -> Result<()> {
+    test_fixed_point! {
+        case (value: i64, expected: FixedPoint) => {
+            assert_eq!(FixedPoint::from_redis_value(Value::Int(value))?, expected);
+        },
+        all {
+            (0, fp!(0));
+            (-42, fp!(-42));
+            (9_223_372_036, fp!(9223372036));
+        },
+        fp128 {
+            (i64::MAX, fp!(9223372036854775807));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    test_fixed_point! {
+        case (value: Value) => {
+            assert!(FixedPoint::from_redis_value(value).is_err());
+        },
+        all {
+            // Extra digits aren't rounded.
+            (Value::BulkString("0.0000000000000000001".into()));
+            (Value::BulkString("1.2.3".into()));
+            (Value::BulkString(vec![0xff]));
+            (Value::Double(1.5));
+            (Value::Nil);
+        },
+        fp64 {
+            (Value::Int(i64::MAX));
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use fixnum::{fixnum_type, register_type, typenum::U3, FixedPoint};
+
+fixnum_type! {
+    /// Signed fixed point number over 16 bits, 2 decimal places.
+    pub type Short = FixedPoint<i16, U2>;
+}
+
+#[cfg(feature = "u64")]
+type Unsigned = FixedPoint<u64, U3>;
+#[cfg(feature = "u64")]
+register_type!(Unsigned);
+
+#[test]
+fn introspect() {
+    let find = |name| fixnum::introspect().find(|info| info.name() == name);
+
+    let short = find("Short").unwrap();
+    assert_eq!((short.layout(), short.precision()), ("i16", 2));
+    assert_eq!((short.min_bits(), short.max_bits()), (-32768, 32767));
+    assert_eq!(
+        short.to_string(),
+        "Short: i16, 2 decimal places, [-327.68, 327.67]"
+    );
+
+    #[cfg(feature = "u64")]
+    {
+        let unsigned = find("Unsigned").unwrap();
+        assert_eq!(unsigned.layout(), "u64");
+        assert_eq!(unsigned.min_bits(), 0);
+        assert_eq!(
+            unsigned.to_string(),
+            "Unsigned: u64, 3 decimal places, [0, 18446744073709551.615]"
+        );
+    }
+
+    assert!(find("Missing").is_none());
+}
@@ -205,6 +205,256 @@ fn serde_with_option() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn scaled() -> Result<()> {
+    test_fixed_point! {
+        case (json: &str, expected: Option<FixedPoint>) => {
+            #[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::scaled")]
+                value: FixedPoint,
+            }
+
+            let actual = serde_json::from_str::<Sample>(json).ok().map(|s| s.value);
+            assert_eq!(actual, expected);
+
+            // Owned strings.
+            let actual = serde_json::from_reader::<_, Sample>(json.as_bytes()).ok().map(|s| s.value);
+            assert_eq!(actual, expected);
+
+            // Check round-trip.
+            if let Some(expected) = expected {
+                let json = serde_json::to_string(&Sample { value: expected }).unwrap();
+                let sample: Sample = serde_json::from_str(&json).unwrap();
+                assert_eq!(sample.value, expected);
+
+                let value = serde_json::to_value(Sample { value: expected }).unwrap();
+                let sample: Sample = serde_json::from_value(value).unwrap();
+                assert_eq!(sample.value, expected);
+            }
+        },
+        all {
+            (r#"{"value": {"value": "1.23", "scale": 9}}"#, Some(fp!(1.23)));
+            (r#"{"value": {"scale": 2, "value": "-1.23"}}"#, Some(fp!(-1.23)));
+            (r#"{"value": {"value": "42", "scale": 0}}"#, Some(fp!(42)));
+            (r#"{"value": {"value": "1.230000000000000000000", "scale": 21}}"#, Some(fp!(1.23)));
+            // More fractional digits than the scale.
+            (r#"{"value": {"value": "1.23", "scale": 1}}"#, None);
+            // Missing, duplicate and unknown fields.
+            (r#"{"value": {"value": "1.23"}}"#, None);
+            (r#"{"value": {"scale": 2}}"#, None);
+            (r#"{"value": {"value": "1.23", "value": "1.23", "scale": 2}}"#, None);
+            (r#"{"value": {"value": "1.23", "scale": 2, "extra": 1}}"#, None);
+            (r#"{"value": {"value": "abc", "scale": 2}}"#, None);
+            (r#"{"value": {"value": "0.000000001", "scale": 18}}"#, Some(fp!(0.000000001)));
+            (r#"{"value": {"value": "0.0000000000000000001", "scale": 19}}"#, None);
+        },
+        fp64 {
+            (r#"{"value": {"value": "0.0000000001", "scale": 18}}"#, None);
+        },
+        fp128 {
+            (r#"{"value": {"value": "0.0000000001", "scale": 18}}"#, Some(fp!(0.0000000001)));
+        },
+    };
+
+    #[cfg(feature = "i64")]
+    {
+        #[derive(Serialize)]
+        struct Sample {
+            #[serde(with = "fixnum::serde::scaled")]
+            value: fixnum::FixedPoint<i64, typenum::U9>,
+        }
+
+        let value = fixnum::fixnum!(-1.5, 9);
+        let json = serde_json::to_string(&Sample { value })?;
+        assert_eq!(json, r#"{"value":{"value":"-1.5","scale":9}}"#);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn as_scale() -> Result<()> {
+    test_fixed_point! {
+        case (value: FixedPoint, expected: &str) => {
+            #[derive(Debug, Serialize, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::as_scale::<2>")]
+                cents: FixedPoint,
+            }
+
+            let json = serde_json::to_string(&Sample { cents: value })?;
+            assert_eq!(json, format!(r#"{{"cents":"{}"}}"#, expected));
+        },
+        all {
+            (fp!(12.345), "12.35");
+            (fp!(-12.345), "-12.35");
+            (fp!(12.344), "12.34");
+            (fp!(1), "1.0");
+            (fp!(0.001), "0.0");
+        },
+    };
+
+    test_fixed_point! {
+        case (json: &str, expected: Option<FixedPoint>) => {
+            #[derive(Debug, Serialize, Deserialize)]
+            struct Sample {
+                #[serde(with = "fixnum::serde::as_scale::<2>")]
+                cents: FixedPoint,
+            }
+
+            let json = format!(r#"{{"cents":"{}"}}"#, json);
+            let actual = serde_json::from_str::<Sample>(&json).ok().map(|s| s.cents);
+            assert_eq!(actual, expected);
+        },
+        all {
+            ("12.3", Some(fp!(12.3)));
+            ("-12.34", Some(fp!(-12.34)));
+            ("12", Some(fp!(12)));
+            // More fractional digits than the scale.
+            ("12.345", None);
+            ("12.340", None);
+            ("abc", None);
+        },
+    };
+
+    test_fixed_point! {
+        case (json: &str, expected: Option<FixedPoint>) => {
+            #[derive(Debug, Serialize, Deserialize)]
+            struct Precise {
+                #[serde(with = "fixnum::serde::as_scale::<12>")]
+                value: FixedPoint,
+            }
+
+            let json = format!(r#"{{"value":"{}"}}"#, json);
+            let actual = serde_json::from_str::<Precise>(&json).ok().map(|s| s.value);
+            assert_eq!(actual, expected);
+        },
+        all {
+            ("1.5", Some(fp!(1.5)));
+            ("1.0000000000015", None);
+        },
+        fp64 {
+            // Rounded to the precision of the type.
+            ("0.0000000015", Some(fp!(0.000000002)));
+        },
+        fp128 {
+            ("0.0000000015", Some(fp!(0.0000000015)));
+        },
+    };
+
+    test_fixed_point! {
+        case (value: FixedPoint, expected: Option<&str>) => {
+            #[derive(Debug, Serialize, Deserialize)]
+            struct Precise {
+                #[serde(with = "fixnum::serde::as_scale::<12>")]
+                value: FixedPoint,
+            }
+
+            let json = serde_json::to_string(&Precise { value }).ok();
+            assert_eq!(json, expected.map(|expected| format!(r#"{{"value":"{}"}}"#, expected)));
+        },
+        all {
+            (fp!(0.000000001), Some("0.000000001"));
+        },
+        fp64 {
+            // The inner representation at 12 decimal places overflows.
+            (fp!(9223372036), None);
+        },
+        fp128 {
+            (fp!(0.000000000000000001), Some("0.0"));
+        },
+    };
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn exact() -> Result<()> {
+    test_fixed_point! {
+        case (toml: &str, expected: Option<FixedPoint>) => {
+            #[derive(Debug, Deserialize)]
+            struct Config {
+                #[serde(with = "fixnum::serde::exact")]
+                fee: FixedPoint,
+            }
+
+            let actual = toml::from_str::<Config>(&format!("fee = {}", toml)).ok().map(|c| c.fee);
+            assert_eq!(actual, expected);
+        },
+        all {
+            ("0.1", Some(fp!(0.1)));
+            ("-2.5", Some(fp!(-2.5)));
+            ("42", Some(fp!(42)));
+            ("1e-3", Some(fp!(0.001)));
+            ("0.100000000", Some(fp!(0.1)));
+            (r#""0.1""#, Some(fp!(0.1)));
+            (r#""1.2.3""#, None);
+            ("nan", None);
+            ("inf", None);
+            ("1e30", None);
+        },
+        fp64 {
+            ("0.0000000001", None);
+            ("0.1234567891", None);
+            (r#""0.0000000001""#, None);
+        },
+        fp128 {
+            ("0.0000000001", Some(fp!(0.0000000001)));
+            ("0.1234567891", Some(fp!(0.1234567891)));
+            (r#""0.0000000001""#, Some(fp!(0.0000000001)));
+        },
+    };
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn exact_or_warn() -> Result<()> {
+    use std::sync::Mutex;
+
+    static REPORTED: Mutex<Vec<(String, u32)>> = Mutex::new(Vec::new());
+
+    fixnum::serde::set_inexact_hook(|inexact| {
+        let reported = (inexact.input.to_string(), inexact.precision);
+        REPORTED.lock().unwrap().push(reported);
+    });
+
+    test_fixed_point! {
+        case (toml: &str, expected: FixedPoint, reported: Option<&str>) => {
+            #[derive(Debug, Deserialize)]
+            struct Config {
+                #[serde(with = "fixnum::serde::exact_or_warn")]
+                fee: FixedPoint,
+            }
+
+            REPORTED.lock().unwrap().clear();
+            let config: Config = toml::from_str(&format!("fee = {}", toml))?;
+            assert_eq!(config.fee, expected);
+
+            let precision = FixedPoint::PRECISION as u32;
+            let expected_reported: Vec<_> = reported.map(|r| (r.to_string(), precision)).into_iter().collect();
+            assert_eq!(*REPORTED.lock().unwrap(), expected_reported);
+        },
+        all {
+            ("0.1", fp!(0.1), None);
+            (r#""-0.25""#, fp!(-0.25), None);
+        },
+        fp64 {
+            ("0.1234567891", fp!(0.123456789), Some("0.1234567891"));
+            (r#""-0.0000000005""#, fp!(-0.000000001), Some("-0.0000000005"));
+        },
+        fp128 {
+            ("0.1234567891", fp!(0.1234567891), None);
+            (r#""-0.0000000005""#, fp!(-0.0000000005), None);
+        },
+    };
+
+    fixnum::serde::remove_inexact_hook();
+    Ok(())
+}
+
 #[cfg(feature = "quick-xml")]
 #[test]
 fn quickxml() -> Result<()> {
@@ -226,3 +476,201 @@ fn quickxml() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn map_keys() -> Result<()> {
+    use std::collections::{BTreeMap, HashMap};
+
+    test_fixed_point! {
+        case () => {
+            let map: HashMap<FixedPoint, u32> =
+                [(fp!(1.5), 1), (fp!(1).cneg()?, 2), (fp!(0), 3)].into_iter().collect();
+            let json = serde_json::to_string(&map)?;
+            assert!(json.contains("\"-1.0\":2"), "{}", json);
+
+            assert_eq!(serde_json::from_str::<HashMap<FixedPoint, u32>>(&json)?, map);
+            assert_eq!(serde_json::from_reader::<_, HashMap<FixedPoint, u32>>(json.as_bytes())?, map);
+            assert_eq!(serde_json::from_value::<HashMap<FixedPoint, u32>>(serde_json::to_value(&map)?)?, map);
+
+            let levels: BTreeMap<FixedPoint, u32> = map.into_iter().collect();
+            let json = serde_json::to_string(&levels)?;
+            assert_eq!(json, r#"{"-1.0":2,"0.0":3,"1.5":1}"#);
+            assert_eq!(serde_json::from_str::<BTreeMap<FixedPoint, u32>>(&json)?, levels);
+
+            assert!(serde_json::from_str::<BTreeMap<FixedPoint, u32>>(r#"{"abc":1}"#).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[test]
+fn owned_strings() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            #[derive(Debug, PartialEq, Deserialize)]
+            struct Strings {
+                #[serde(with = "fixnum::serde::str")]
+                str: FixedPoint,
+                #[serde(with = "fixnum::serde::str_option")]
+                str_option: Option<FixedPoint>,
+                #[serde(with = "fixnum::serde::as_scale::<2>")]
+                as_scale: FixedPoint,
+            }
+
+            let expected = Strings {
+                str: fp!(1.5),
+                str_option: Some(fp!(2.5)),
+                as_scale: fp!(3.25),
+            };
+            let value = serde_json::json!({"str": "1.5", "str_option": "2.5", "as_scale": "3.25"});
+            assert_eq!(serde_json::from_value::<Strings>(value)?, expected);
+
+            // Escaped strings can't be borrowed.
+            let json = r#"{"str": "1.\u0035", "str_option": "2.5", "as_scale": "3.25"}"#;
+            assert_eq!(serde_json::from_str::<Strings>(json)?, expected);
+            assert_eq!(serde_json::from_reader::<_, Strings>(json.as_bytes())?, expected);
+
+            let value = serde_json::json!({"str": "1.5", "str_option": null, "as_scale": "3.255"});
+            assert!(serde_json::from_value::<Strings>(value).is_err());
+        },
+    };
+    Ok(())
+}
+
+#[cfg(all(feature = "i16", feature = "i64", feature = "i128", feature = "u64"))]
+mod adversarial {
+    use proptest::prelude::*;
+    use serde::{
+        de::{value, IntoDeserializer},
+        Deserialize,
+    };
+
+    use fixnum::typenum::{U0, U18, U38, U4, U9};
+
+    type F16 = fixnum::FixedPoint<i16, U4>;
+    type F64 = fixnum::FixedPoint<i64, U9>;
+    type F64Int = fixnum::FixedPoint<i64, U0>;
+    type F128 = fixnum::FixedPoint<i128, U18>;
+    type F128Max = fixnum::FixedPoint<i128, U38>;
+    type U64 = fixnum::FixedPoint<u64, U9>;
+
+    /// Deserializes `json` in every supported way, results are ignored: only panics matter.
+    fn deserialize_json(json: &str) {
+        macro_rules! check {
+            ($($fp:ty),*) => {$({
+                #[derive(Deserialize)]
+                #[allow(dead_code)]
+                struct Sample {
+                    #[serde(default)]
+                    default: Option<$fp>,
+                    #[serde(default, with = "fixnum::serde::str_option")]
+                    str: Option<$fp>,
+                    #[serde(default, with = "fixnum::serde::float_option")]
+                    float: Option<$fp>,
+                    #[serde(default, with = "fixnum::serde::repr_option")]
+                    repr: Option<$fp>,
+                }
+
+                #[derive(Deserialize)]
+                #[allow(dead_code)]
+                struct Scaled(#[serde(with = "fixnum::serde::scaled")] $fp);
+
+                #[derive(Deserialize)]
+                #[allow(dead_code)]
+                struct AsScale(
+                    #[serde(with = "fixnum::serde::as_scale::<0>")] $fp,
+                    #[serde(with = "fixnum::serde::as_scale::<2>")] $fp,
+                    #[serde(with = "fixnum::serde::as_scale::<38>")] $fp,
+                    #[serde(with = "fixnum::serde::as_scale::<255>")] $fp,
+                );
+
+                let _ = serde_json::from_str::<$fp>(json);
+                let _ = serde_json::from_str::<Sample>(json);
+                let _ = serde_json::from_str::<Scaled>(json);
+                let _ = serde_json::from_str::<AsScale>(json);
+                let _ = serde_json::from_str::<std::collections::BTreeMap<$fp, u8>>(json);
+                let _ = serde_json::from_str::<serde_json::Value>(json)
+                    .map(serde_json::from_value::<$fp>);
+                let _ = quick_xml::de::from_str::<$fp>(json);
+            })*};
+        }
+
+        check!(F16, F64, F64Int, F128, F128Max, U64);
+    }
+
+    /// Deserializes primitives directly, bypassing limitations of JSON.
+    fn deserialize_primitives(i: i128, u: u128, f: f64) {
+        macro_rules! check {
+            ($($fp:ty),*) => {$({
+                let _ = <$fp>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(i));
+                let _ = <$fp>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(u));
+                let _ = <$fp>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(f));
+                let _ = <$fp>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(i as i64));
+                let _ = <$fp>::deserialize(IntoDeserializer::<value::Error>::into_deserializer(u as u64));
+            })*};
+        }
+
+        check!(F16, F64, F64Int, F128, F128Max, U64);
+    }
+
+    /// Strings looking like numbers with extreme lengths, exponents and signs.
+    fn number() -> impl Strategy<Value = String> {
+        prop_oneof![
+            "[-+ ]{0,2}[0-9]{0,45}(\\.[0-9]{0,45})?([eE][-+]?[0-9]{1,12})?[ ]?",
+            "[-+.0-9eE]{0,20}",
+            "\\PC{0,20}",
+        ]
+    }
+
+    fn json() -> impl Strategy<Value = String> {
+        let scale = prop_oneof![Just(0u64), Just(255), Just(u32::MAX as u64), any::<u64>()];
+        prop_oneof![
+            number().prop_map(|s| format!("{:?}", s)),
+            number(),
+            (number(), scale)
+                .prop_map(|(s, scale)| format!(r#"{{"value":{:?},"scale":{}}}"#, s, scale)),
+            (number(), number()).prop_map(|(a, b)| format!(
+                r#"{{"default":{},"str":{:?},"float":{},"repr":{}}}"#,
+                a, b, a, b
+            )),
+            (number(), number()).prop_map(|(a, b)| format!("[{:?},{:?},{:?},{:?}]", a, b, a, b)),
+            (number(), number()).prop_map(|(a, b)| format!(r#"{{{:?}:1,{:?}:2}}"#, a, b)),
+            number().prop_map(|s| format!("<a>{}</a>", s)),
+            number().prop_map(|s| format!("<a><b><c>{}</c></b></a>", s)),
+            number().prop_map(|s| format!(r#"<a value="{}"><value>{}</value></a>"#, s, s)),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2_000))]
+
+        #[test]
+        fn never_panics_on_strings(json in json()) {
+            deserialize_json(&json);
+        }
+
+        #[test]
+        fn never_panics_on_primitives(i in any::<i128>(), u in any::<u128>(), f in any::<f64>()) {
+            deserialize_primitives(i, u, f);
+        }
+    }
+
+    #[test]
+    fn never_panics_on_extremes() {
+        for i in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX] {
+            for u in [0, 1, u128::MAX] {
+                for f in [
+                    f64::NAN,
+                    f64::INFINITY,
+                    f64::NEG_INFINITY,
+                    f64::MIN,
+                    f64::MAX,
+                    -0.0,
+                    1e-300,
+                ] {
+                    deserialize_primitives(i, u, f);
+                }
+            }
+        }
+    }
+}
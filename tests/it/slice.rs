@@ -0,0 +1,37 @@
+use anyhow::Result;
+
+use fixnum::{ops::*, slice};
+
+#[test]
+fn top_k_and_bottom_k() -> Result<()> {
+    test_fixed_point! {
+        case (values: &[FixedPoint], k: usize, top: &[FixedPoint], bottom: &[FixedPoint]) => {
+            let mut actual = values.to_vec();
+            assert_eq!(slice::top_k(&mut actual, k), top);
+            let mut sorted = values.to_vec();
+            sorted.sort_unstable();
+            actual.sort_unstable();
+            assert_eq!(actual, sorted, "must be a permutation");
+
+            let mut actual = values.to_vec();
+            assert_eq!(slice::bottom_k(&mut actual, k), bottom);
+            actual.sort_unstable();
+            assert_eq!(actual, sorted, "must be a permutation");
+        },
+        all {
+            (&[], 3, &[], &[]);
+            (&[fp!(1), fp!(2)], 0, &[], &[]);
+            (&[fp!(1), fp!(3), fp!(2)], 1, &[fp!(3)], &[fp!(1)]);
+            (&[fp!(1), fp!(3), fp!(2)], 5, &[fp!(3), fp!(2), fp!(1)], &[fp!(1), fp!(2), fp!(3)]);
+            (&[fp!(0.5), fp!(-1.5), fp!(2), fp!(0.5), fp!(-0.25)], 2, &[fp!(2), fp!(0.5)], &[fp!(-1.5), fp!(-0.25)]);
+            (&[fp!(-1), fp!(-1), fp!(-1)], 2, &[fp!(-1), fp!(-1)], &[fp!(-1), fp!(-1)]);
+            (
+                &[FixedPoint::MAX, FixedPoint::MIN, FixedPoint::ZERO, FixedPoint::EPSILON],
+                2,
+                &[FixedPoint::MAX, FixedPoint::EPSILON],
+                &[FixedPoint::MIN, FixedPoint::ZERO],
+            );
+        },
+    };
+    Ok(())
+}
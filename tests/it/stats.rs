@@ -0,0 +1,56 @@
+use anyhow::Result;
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    stats, ArithmeticError,
+};
+
+#[test]
+fn normalize() -> Result<()> {
+    test_fixed_point! {
+        case (weights: &[FixedPoint], mode: RoundMode, expected: &[FixedPoint]) => {
+            let mut actual = weights.to_vec();
+            stats::normalize(&mut actual, mode)?;
+            assert_eq!(actual, expected);
+
+            let sum = actual.iter().try_fold(FixedPoint::ZERO, |sum, w| sum.cadd(*w))?;
+            assert_eq!(sum, FixedPoint::ONE);
+        },
+        all {
+            (&[fp!(5)], Floor, &[fp!(1)]);
+            (&[fp!(1), fp!(0), fp!(3)], Floor, &[fp!(0.25), fp!(0), fp!(0.75)]);
+            (&[fp!(0.1), fp!(0.2), fp!(0.2)], Ceil, &[fp!(0.2), fp!(0.4), fp!(0.4)]);
+            (&[fp!(1), fp!(2), fp!(2)], Ceil, &[fp!(0.2), fp!(0.4), fp!(0.4)]);
+        },
+        fp64 {
+            (&[fp!(1), fp!(1), fp!(1)], Floor, &[fp!(0.333333334), fp!(0.333333333), fp!(0.333333333)]);
+            (&[fp!(1), fp!(1), fp!(1)], Nearest, &[fp!(0.333333334), fp!(0.333333333), fp!(0.333333333)]);
+            (&[fp!(1), fp!(1), fp!(1)], Ceil, &[fp!(0.333333333), fp!(0.333333333), fp!(0.333333334)]);
+            (&[fp!(1), fp!(1), fp!(4)], Floor, &[fp!(0.166666667), fp!(0.166666666), fp!(0.666666667)]);
+            (&[fp!(2), fp!(2), fp!(2), fp!(2), fp!(2), fp!(1)], Ceil, &[
+                fp!(0.181818181), fp!(0.181818182), fp!(0.181818182),
+                fp!(0.181818182), fp!(0.181818182), fp!(0.090909091),
+            ]);
+            // Zero weights stay zero even if the dust exceeds the count of positive results.
+            (
+                &[fp!(0), fp!(1), fp!(0.000000001), fp!(0.000000001), fp!(0.000000001)],
+                Floor,
+                &[fp!(0), fp!(0.999999998), fp!(0.000000001), fp!(0.000000001), fp!(0)],
+            );
+        },
+    };
+    test_fixed_point! {
+        case (weights: &[FixedPoint], expected: ArithmeticError) => {
+            let mut actual = weights.to_vec();
+            assert_eq!(stats::normalize(&mut actual, Nearest), Err(expected));
+            assert_eq!(actual, weights);
+        },
+        all {
+            (&[], ArithmeticError::DivisionByZero);
+            (&[fp!(0), fp!(0)], ArithmeticError::DivisionByZero);
+            (&[fp!(1), fp!(-0.5)], ArithmeticError::DomainViolation);
+            (&[FixedPoint::MAX, fp!(1)], ArithmeticError::Overflow);
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anyhow::Result;
+
+use fixnum::{
+    ops::{Bounded, One, RoundMode::*, RoundingDiv, RoundingMul, Zero},
+    testgen::Generator,
+};
+
+#[test]
+fn deterministic() {
+    let mut a = Generator::new(7);
+    let mut b = Generator::new(7);
+    let mut c = Generator::new(8);
+
+    let xs: Vec<u64> = (0..16).map(|_| a.next_u64()).collect();
+    assert!(xs.iter().all(|&x| x == b.next_u64()));
+    assert!(xs.iter().any(|&x| x != c.next_u64()));
+}
+
+#[test]
+fn edge_cases() -> Result<()> {
+    test_fixed_point! {
+        case () => {
+            let mut gen = Generator::new(1);
+            let values: Vec<FixedPoint> = (0..10_000).map(|_| gen.generate()).collect();
+
+            for expected in [
+                FixedPoint::ZERO,
+                FixedPoint::ONE,
+                FixedPoint::ONE.cneg()?,
+                FixedPoint::MIN,
+                FixedPoint::MAX,
+                FixedPoint::EPSILON,
+                fp!(0.5),
+            ] {
+                assert!(values.contains(&expected), "{} isn't generated", expected);
+            }
+
+            for value in values {
+                assert_eq!(value.to_string().parse::<FixedPoint>()?, value);
+                assert_eq!(value.rmul(FixedPoint::ONE, Floor)?, value);
+                assert_eq!(value.rdiv(FixedPoint::ONE, Ceil)?, value);
+            }
+        },
+    };
+    Ok(())
+}
@@ -0,0 +1,243 @@
+use anyhow::Result;
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, ConvertErrorKind, FixedPoint,
+};
+
+#[cfg(feature = "u64")]
+type Fp64 = FixedPoint<u64, typenum::U9>;
+#[cfg(feature = "u128")]
+type Fp128 = FixedPoint<u128, typenum::U18>;
+
+#[test]
+#[cfg(feature = "u64")]
+fn parse_and_display_u64() -> Result<()> {
+    let max: Fp64 = "18446744073.709551615".parse()?;
+    assert_eq!(max, Fp64::MAX);
+    assert_eq!(max.to_string(), "18446744073.709551615");
+    assert_eq!(Fp64::MIN, Fp64::ZERO);
+
+    assert_eq!("0.5".parse::<Fp64>()?.to_string(), "0.5");
+    assert_eq!("+42".parse::<Fp64>()?.to_string(), "42.0");
+    assert_eq!("-0".parse::<Fp64>()?, Fp64::ZERO);
+
+    let kind = |s: &str| s.parse::<Fp64>().unwrap_err().kind();
+    assert_eq!(kind("-1"), ConvertErrorKind::IntegralOverflow);
+    assert_eq!(kind("-0.000000001"), ConvertErrorKind::IntegralOverflow);
+    assert_eq!(
+        kind("18446744073.709551616"),
+        ConvertErrorKind::IntegralOverflow
+    );
+    assert_eq!(kind("--1"), ConvertErrorKind::InvalidSyntax);
+    assert_eq!(
+        Fp64::from_str_exact("0.0000000001").unwrap_err().kind(),
+        ConvertErrorKind::FractionalPrecision
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "u64")]
+fn add_sub_u64() -> Result<()> {
+    let a: Fp64 = "1.5".parse()?;
+    let b: Fp64 = "2.25".parse()?;
+
+    assert_eq!(a.cadd(b)?, "3.75".parse()?);
+    assert_eq!(b.csub(a)?, "0.75".parse()?);
+    assert_eq!(a.csub(b), Err(ArithmeticError::Overflow));
+    assert_eq!(a.saturating_sub(b), Fp64::ZERO);
+    assert_eq!(
+        Fp64::MAX.cadd(Fp64::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(Fp64::csum([a, b, a])?, "5.25".parse()?);
+    assert_eq!(Fp64::csum([Fp64::MAX, a]), Err(ArithmeticError::Overflow));
+    assert_eq!(a.overflowing_add(b), ("3.75".parse()?, false));
+    assert_eq!(Fp64::MAX.overflowing_add(Fp64::EPSILON), (Fp64::ZERO, true));
+    assert_eq!(
+        a.overflowing_sub(b),
+        (Fp64::MAX.csub("0.749999999".parse()?)?, true)
+    );
+    assert_eq!(a.cmul(3)?, "4.5".parse()?);
+    assert_eq!(a.overflowing_mul(3), ("4.5".parse()?, false));
+    assert!(Fp64::MAX.overflowing_mul(2).1);
+    assert_eq!(a.min(b), a);
+    assert_eq!(a.max(b), b);
+    assert_eq!(b.clamp(Fp64::ZERO, a), a);
+    assert_eq!(a.clamp(b, Fp64::MAX), b);
+    assert_eq!(a.abs_diff(b), "0.75".parse()?);
+    assert_eq!(b.abs_diff(a), "0.75".parse()?);
+    assert!(Fp64::ZERO.is_zero() && !Fp64::ZERO.is_positive());
+    assert!(!a.is_zero() && a.is_positive());
+    assert_eq!(a.trunc(), Fp64::ONE);
+    assert!(!a.is_integer() && a.trunc().is_integer());
+    assert_eq!(Fp64::ONE_HALF.cmul(3)?, a);
+    assert_eq!(Fp64::TEN.cmul(10)?, Fp64::HUNDRED);
+    assert_eq!(
+        "2.345".parse::<Fp64>()?.round_to(2, HalfEven)?,
+        "2.34".parse()?
+    );
+    assert_eq!(Fp64::MAX.round_to(0, Ceil), Err(ArithmeticError::Overflow));
+    assert_eq!(a.fract(), "0.5".parse()?);
+    assert_eq!(a.apply_bps(50, Ceil)?, "1.5075".parse()?);
+    assert_eq!(a.apply_bps(-10000, Floor)?, Fp64::ZERO);
+    assert_eq!(a.apply_bps(-10001, Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(Fp64::MAX.abs_diff(Fp64::ZERO), Fp64::MAX);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "u64")]
+fn rmul_rdiv_u64() -> Result<()> {
+    let a: Fp64 = "0.000000005".parse()?;
+    let half: Fp64 = "0.5".parse()?;
+
+    assert_eq!(a.rmul(half, Floor)?, "0.000000002".parse()?);
+    assert_eq!(a.rmul(half, Nearest)?, "0.000000003".parse()?);
+    assert_eq!(a.rmul(half, Ceil)?, "0.000000003".parse()?);
+    assert_eq!(a.rmul(half, HalfEven)?, "0.000000002".parse()?);
+    assert_eq!(a.rdiv(2u64, HalfEven)?, "0.000000002".parse()?);
+    assert_eq!(7u64.rdiv(2, HalfEven)?, 4);
+    assert_eq!(
+        Fp64::MAX.rmul(Fp64::MAX, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+
+    let one = Fp64::ONE;
+    let three: Fp64 = "3".parse()?;
+    assert_eq!(one.rdiv(three, Floor)?, "0.333333333".parse()?);
+    assert_eq!(one.rdiv(three, Nearest)?, "0.333333333".parse()?);
+    assert_eq!(one.rdiv(three, Ceil)?, "0.333333334".parse()?);
+    assert_eq!(three.recip(Nearest)?, "0.333333333".parse()?);
+    assert_eq!(
+        one.rdiv(Fp64::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(one.rdiv(2u64, Floor)?, half);
+    assert_eq!(one.saturating_rdiv(three, Ceil), "0.333333334".parse()?);
+    assert_eq!(Fp64::MAX.saturating_rdiv(half, Floor), Fp64::MAX);
+    assert_eq!(u64::MAX.saturating_rdiv(three, Floor), Fp64::MAX);
+    assert_eq!(Fp64::from_ratio(2, 3, Nearest)?, "0.666666667".parse()?);
+    let x: Fp64 = "7.5".parse()?;
+    assert_eq!(x.crem("2".parse::<Fp64>()?)?, "1.5".parse()?);
+    assert_eq!(x.rem_euclid(2)?, "1.5".parse()?);
+    assert_eq!(x.div_euclid(2)?, 3);
+    assert_eq!(x.crem(Fp64::ZERO), Err(ArithmeticError::DivisionByZero));
+    assert_eq!(one.rmul_div(half, three, Ceil)?, "0.166666667".parse()?);
+    assert_eq!(Fp64::MAX.rmul_div(Fp64::MAX, Fp64::MAX, Floor)?, Fp64::MAX);
+    assert_eq!(
+        one.rmul_div(one, Fp64::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "u64")]
+fn rsqrt_and_integral_u64() -> Result<()> {
+    let two: Fp64 = "2".parse()?;
+    assert_eq!(two.rsqrt(Floor)?, "1.414213562".parse()?);
+    assert_eq!(two.rsqrt(Nearest)?, "1.414213562".parse()?);
+    assert_eq!(two.rsqrt(Ceil)?, "1.414213563".parse()?);
+    assert_eq!(Fp64::ZERO.rsqrt(Ceil)?, Fp64::ZERO);
+    assert_eq!(Fp64::MAX.rsqrt(Floor)?, "135818.791312945".parse()?);
+
+    let x: Fp64 = "2.5".parse()?;
+    assert_eq!(x.integral(Floor), 2);
+    assert_eq!(x.integral(Nearest), 3);
+    assert_eq!(x.integral(Ceil), 3);
+    assert_eq!(x.integral(HalfEven), 2);
+    assert_eq!(x.integral(TowardZero), 2);
+    assert_eq!(x.integral(AwayFromZero), 3);
+    assert_eq!(x.floor(), two);
+    assert_eq!(x.to_decimal(0), (25, -1));
+    assert_eq!(f64::from(x), 2.5);
+    assert_eq!(Fp64::try_from(2.5f64)?, x);
+    assert!(Fp64::try_from(-1i32).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "u64")]
+fn half_sum_u64() {
+    let fp = |ulps: u64| Fp64::from_bits(ulps);
+    let modes = [Floor, Ceil, Nearest, HalfEven, TowardZero, AwayFromZero];
+    for (a, b, expected) in [
+        (1, 2, [1, 2, 2, 2, 1, 2]),
+        (1, 4, [2, 3, 3, 2, 2, 3]),
+        (4, 5, [4, 5, 5, 4, 4, 5]),
+        (5, 6, [5, 6, 6, 6, 5, 6]),
+        (3, 3, [3, 3, 3, 3, 3, 3]),
+    ] {
+        for (mode, expected) in modes.into_iter().zip(expected) {
+            assert_eq!(
+                Fp64::half_sum(fp(a), fp(b), mode),
+                fp(expected),
+                "{:?}",
+                mode
+            );
+            assert_eq!(
+                Fp64::half_sum(fp(b), fp(a), mode),
+                fp(expected),
+                "{:?}",
+                mode
+            );
+        }
+    }
+
+    let below_max = fp(u64::MAX - 1);
+    assert_eq!(Fp64::half_sum(Fp64::MAX, below_max, HalfEven), below_max);
+    assert_eq!(Fp64::half_sum(Fp64::MAX, below_max, Ceil), Fp64::MAX);
+}
+
+#[test]
+#[cfg(feature = "u64")]
+fn parts_u64() -> Result<()> {
+    let value: Fp64 = "5.25".parse()?;
+    assert_eq!(value.to_parts(), (false, 5, 250_000_000));
+    assert_eq!(Fp64::from_parts(false, 5, 250_000_000)?, value);
+    assert_eq!(Fp64::MAX.to_parts(), (false, 18_446_744_073, 709_551_615));
+    assert_eq!(
+        Fp64::from_parts(false, 18_446_744_073, 709_551_615)?,
+        Fp64::MAX
+    );
+    assert_eq!(Fp64::from_parts(true, 0, 0)?, Fp64::ZERO);
+
+    let kind = |negative, integral, fractional| {
+        Fp64::from_parts(negative, integral, fractional)
+            .unwrap_err()
+            .kind()
+    };
+    assert_eq!(kind(true, 0, 1), ConvertErrorKind::IntegralOverflow);
+    assert_eq!(
+        kind(false, 18_446_744_073, 709_551_616),
+        ConvertErrorKind::IntegralOverflow
+    );
+    assert_eq!(kind(false, 0, 1_000_000_000), ConvertErrorKind::OutOfDomain);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "u128")]
+fn ops_u128() -> Result<()> {
+    let max: Fp128 = "340282366920938463463.374607431768211455".parse()?;
+    assert_eq!(max, Fp128::MAX);
+    assert_eq!(max.to_string(), "340282366920938463463.374607431768211455");
+
+    // Goes through the `u256` promotion.
+    let half: Fp128 = "0.5".parse()?;
+    assert_eq!(max.rmul(half, Floor)?, max.rdiv(2u128, Floor)?);
+    assert_eq!(max.rmul(max, Floor), Err(ArithmeticError::Overflow));
+    assert_eq!(max.rdiv(Fp128::ONE, Floor)?, max);
+    assert_eq!(Fp128::ONE.rdiv(max, Ceil)?, Fp128::EPSILON);
+
+    let two: Fp128 = "2".parse()?;
+    assert_eq!(two.rsqrt(Floor)?, "1.414213562373095048".parse()?);
+    assert_eq!(two.rsqrt(Ceil)?, "1.414213562373095049".parse()?);
+    assert_eq!(
+        Fp128::ZERO.csub(Fp128::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use fixnum::{typenum, FixedPoint};
+use serde_json::json;
+use utoipa::{PartialSchema, ToSchema};
+
+#[test]
+fn schema() {
+    let schema = |schema| serde_json::to_value(schema).unwrap();
+
+    assert_eq!(
+        schema(FixedPoint::<i64, typenum::U9>::schema()),
+        json!({
+            "type": "string",
+            "pattern": r"^-?[0-9]+(\.[0-9]{1,9})?$",
+            "examples": ["42.5"],
+        })
+    );
+    assert_eq!(
+        schema(FixedPoint::<i64, typenum::U0>::schema()),
+        json!({
+            "type": "string",
+            "pattern": "^-?[0-9]+$",
+            "examples": ["42"],
+        })
+    );
+    assert_eq!(FixedPoint::<i64, typenum::U18>::name(), "FixedPoint");
+}
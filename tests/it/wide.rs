@@ -0,0 +1,318 @@
+use anyhow::Result;
+use typenum::{U0, U30};
+
+use fixnum::{
+    ops::{RoundMode::*, *},
+    ArithmeticError, ConvertErrorKind, FixedPoint, I256,
+};
+
+type Fp256 = FixedPoint<I256, U30>;
+
+#[test]
+fn parse_and_display() -> Result<()> {
+    for (input, expected) in [
+        ("0", "0.0"),
+        ("-0", "0.0"),
+        ("+1.5", "1.5"),
+        (
+            "-0.000000000000000000000000000001",
+            "-0.000000000000000000000000000001",
+        ),
+        (
+            "12345678901234567890123456789.123456789012345678901234567890",
+            "12345678901234567890123456789.12345678901234567890123456789",
+        ),
+    ] {
+        assert_eq!(input.parse::<Fp256>()?.to_string(), expected, "{}", input);
+    }
+
+    // MAX = (2 ^ 255 - 1) / 10 ^ 30
+    let max = "57896044618658097711785492504343953926634992332.820282019728792003956564819967";
+    assert_eq!(max.parse::<Fp256>()?, Fp256::MAX);
+    assert_eq!(Fp256::MAX.to_string(), max);
+    assert_eq!(
+        format!("{:?}", Fp256::MIN),
+        format!("-{}8", &max[..max.len() - 1])
+    );
+
+    let kind = |s: &str| s.parse::<Fp256>().unwrap_err().kind();
+    assert_eq!(
+        kind("57896044618658097711785492504343953926634992333"),
+        ConvertErrorKind::IntegralOverflow
+    );
+    assert_eq!(kind("1."), ConvertErrorKind::InvalidSyntax);
+    assert_eq!(kind("1.2a"), ConvertErrorKind::InvalidSyntax);
+    assert_eq!(kind("--1"), ConvertErrorKind::InvalidSyntax);
+
+    let digits = "0.0000000000000000000000000000015";
+    assert_eq!(
+        digits.parse::<Fp256>()?,
+        "0.000000000000000000000000000002".parse()?
+    );
+    assert_eq!(
+        Fp256::from_str_exact(digits).unwrap_err().kind(),
+        ConvertErrorKind::FractionalPrecision
+    );
+    let zeros = "1.0000000000000000000000000000000";
+    assert_eq!(zeros.parse::<Fp256>()?, Fp256::ONE);
+    assert_eq!(
+        Fp256::from_str_exact(zeros).unwrap_err().kind(),
+        ConvertErrorKind::FractionalPrecision
+    );
+
+    type Integer = FixedPoint<I256, U0>;
+    assert_eq!("42".parse::<Integer>()?.to_string(), "42.0");
+    assert_eq!("41.5".parse::<Integer>()?.to_string(), "42.0");
+    Ok(())
+}
+
+#[test]
+fn rmul_rdiv() -> Result<()> {
+    let a: Fp256 = "12345678901234567890.123456789012345678901234567891".parse()?;
+    let b: Fp256 = "-0.5".parse()?;
+
+    assert_eq!(
+        a.rmul(b, Floor)?,
+        "-6172839450617283945.061728394506172839450617283946".parse()?
+    );
+    assert_eq!(
+        a.rmul(b, Ceil)?,
+        "-6172839450617283945.061728394506172839450617283945".parse()?
+    );
+    assert_eq!(
+        a.rmul(b, Nearest)?,
+        "-6172839450617283945.061728394506172839450617283946".parse()?
+    );
+    assert_eq!(a.rmul(b, Floor)?, a.rdiv(Fp256::try_from(-2)?, Floor)?);
+    assert_eq!(a.rmul_div(b, Fp256::ONE, Floor)?, a.rmul(b, Floor)?);
+    assert_eq!(
+        Fp256::MAX.rmul_div(Fp256::MAX, Fp256::MAX, Floor)?,
+        Fp256::MAX
+    );
+
+    let three = Fp256::try_from(3)?;
+    assert_eq!(
+        Fp256::ONE.rdiv(three, Floor)?,
+        "0.333333333333333333333333333333".parse()?
+    );
+    assert_eq!(
+        Fp256::ONE.rdiv(three, Ceil)?,
+        "0.333333333333333333333333333334".parse()?
+    );
+    assert_eq!(
+        Fp256::ONE.rdiv(three.cneg()?, Floor)?,
+        "-0.333333333333333333333333333334".parse()?
+    );
+    assert_eq!(
+        Fp256::ONE.rdiv(Fp256::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(
+        Fp256::ONE.rmul_div(three, Fp256::ZERO, Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+
+    assert_eq!(Fp256::MAX.rmul(Fp256::ONE, Floor)?, Fp256::MAX);
+    assert_eq!(Fp256::MIN.rdiv(Fp256::ONE, Floor)?, Fp256::MIN);
+    assert_eq!(
+        Fp256::MAX.rmul(three, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        Fp256::MIN.rdiv(Fp256::ONE.cneg()?, Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    Ok(())
+}
+
+#[test]
+fn other_ops() -> Result<()> {
+    let a: Fp256 = "-2.5".parse()?;
+    assert_eq!(a.cadd(Fp256::ONE)?, "-1.5".parse()?);
+    assert_eq!(a.csub(Fp256::ONE)?, "-3.5".parse()?);
+    assert_eq!(a.cmul(I256::from(4))?, "-10".parse()?);
+    assert_eq!(a.cneg()?, "2.5".parse()?);
+    assert_eq!(a.abs()?, "2.5".parse()?);
+    assert_eq!(a.signum(), I256::from(-1));
+    assert_eq!(a.signum_fp(), "-1".parse()?);
+    assert!(a.is_negative() && !a.is_positive() && !a.is_zero());
+    assert!(Fp256::ZERO.is_zero());
+    assert!(!a.is_integer() && Fp256::ONE.cneg()?.is_integer());
+    assert_eq!(a.copysign(Fp256::ONE)?, "2.5".parse()?);
+    assert_eq!(a.copysign(Fp256::ONE.cneg()?)?, a);
+    assert_eq!(a.integral(Floor), I256::from(-3));
+    assert_eq!(a.integral(Nearest), I256::from(-3));
+    assert_eq!(a.integral(Ceil), I256::from(-2));
+    let max_integral = I256::MAX / I256::from(10).pow(30);
+    assert_eq!(Fp256::MAX.integral(Ceil), max_integral + I256::from(1));
+    assert_eq!(Fp256::MAX.integral(Floor), max_integral);
+    assert_eq!(Fp256::MIN.integral(Floor), -max_integral - I256::from(1));
+    assert_eq!(Fp256::MIN.integral(Nearest), -max_integral - I256::from(1));
+    assert_eq!(
+        Fp256::MAX.cadd(Fp256::EPSILON),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(Fp256::MIN.cneg(), Err(ArithmeticError::Overflow));
+    assert_eq!(Fp256::csum([a, Fp256::ONE, a])?, "-4".parse()?);
+    assert_eq!(a.overflowing_add(Fp256::ONE), ("-1.5".parse()?, false));
+    assert_eq!(
+        Fp256::MIN.overflowing_sub(Fp256::EPSILON),
+        (Fp256::MAX, true)
+    );
+    assert_eq!(Fp256::MIN.overflowing_neg(), (Fp256::MIN, true));
+    assert_eq!(a.overflowing_mul(I256::from(-2)), ("5".parse()?, false));
+    assert_eq!(
+        Fp256::MIN.overflowing_mul(I256::from(-1)),
+        (Fp256::MIN, true)
+    );
+    Ok(())
+}
+
+#[test]
+fn saturating_ops() -> Result<()> {
+    let a: Fp256 = "-2.5".parse()?;
+    let two = I256::from(2);
+    assert_eq!(a.saturating_add(Fp256::ONE), "-1.5".parse()?);
+    assert_eq!(Fp256::MAX.saturating_add(Fp256::EPSILON), Fp256::MAX);
+    assert_eq!(Fp256::MIN.saturating_sub(Fp256::EPSILON), Fp256::MIN);
+    assert_eq!(a.saturating_mul(two), "-5".parse()?);
+    assert_eq!(CheckedMul::saturating_mul(two, a), "-5".parse()?);
+    assert_eq!(Fp256::MAX.saturating_mul(-two), Fp256::MIN);
+    assert_eq!(a.saturating_rmul(Fp256::MAX, Floor), Fp256::MIN);
+    assert_eq!(
+        Fp256::MIN.saturating_rdiv(Fp256::EPSILON, Floor),
+        Fp256::MIN
+    );
+    assert_eq!(
+        Fp256::MIN.saturating_rdiv(a, Floor),
+        Fp256::MIN.rdiv(a, Floor)?
+    );
+    assert_eq!(
+        Fp256::MIN.saturating_rdiv(-two, Floor),
+        Fp256::MAX.rdiv(two, Ceil)?
+    );
+
+    assert_eq!(a.rdiv(two, Floor)?, "-1.25".parse()?);
+    assert_eq!(
+        a.rdiv(I256::from(0), Floor),
+        Err(ArithmeticError::DivisionByZero)
+    );
+    assert_eq!(
+        Fp256::MIN.rdiv(I256::from(-1), Floor),
+        Err(ArithmeticError::Overflow)
+    );
+    assert_eq!(
+        Fp256::MIN.saturating_rdiv(I256::from(-1), Floor),
+        Fp256::MAX
+    );
+    assert_eq!(
+        I256::from(5).rdiv(Fp256::try_from(two)?, Floor)?,
+        "2.5".parse()?
+    );
+    assert_eq!(I256::MAX.saturating_rdiv(Fp256::ONE, Floor), Fp256::MAX);
+    Ok(())
+}
+
+#[test]
+fn fixed_point_ops() -> Result<()> {
+    fn hypot<F: FixedPointOps>(a: F, b: F) -> Result<F, ArithmeticError> {
+        a.rmul(a, Floor)?.cadd(b.rmul(b, Floor)?)?.rsqrt(Floor)
+    }
+
+    let a: Fp256 = "0.3".parse()?;
+    assert_eq!(hypot(a, "0.4".parse()?)?, "0.5".parse()?);
+
+    let two: Fp256 = "2".parse()?;
+    let sqrt2 = "1.414213562373095048801688724209";
+    assert_eq!(two.rsqrt(Floor)?, sqrt2.parse()?);
+    assert_eq!(two.rsqrt(Ceil)?, "1.41421356237309504880168872421".parse()?);
+    assert_eq!(two.rsqrt(Nearest)?, two.rsqrt(Ceil)?);
+    assert_eq!(
+        two.cneg()?.rsqrt(Floor),
+        Err(ArithmeticError::DomainViolation)
+    );
+    let max_sqrt = "240615969168004511545033.772477625056927114980741063148";
+    assert_eq!(Fp256::MAX.rsqrt(Floor)?, max_sqrt.parse()?);
+
+    let a: Fp256 = "-7.5".parse()?;
+    assert_eq!(a.crem(two)?, "-1.5".parse()?);
+    assert_eq!(a.rem_euclid(two)?, "0.5".parse()?);
+    assert_eq!(a.div_euclid(I256::from(2))?, I256::from(-4));
+    assert_eq!(a.crem(Fp256::ZERO), Err(ArithmeticError::DivisionByZero));
+
+    assert_eq!(a.floor(), "-8".parse()?);
+    assert_eq!(a.ceil(), "-7".parse()?);
+    assert_eq!(a.round(), "-8".parse()?);
+    assert_eq!(Fp256::MIN.floor(), Fp256::MIN.ceil());
+    assert_eq!(two.recip(Floor)?, "0.5".parse()?);
+    assert_eq!(
+        Fp256::from_ratio(I256::from(-2), I256::from(3), Nearest)?,
+        "-0.666666666666666666666666666667".parse()?
+    );
+    assert_eq!(Fp256::half_sum(Fp256::MAX, Fp256::MAX, Floor), Fp256::MAX);
+    assert_eq!(
+        Fp256::half_sum(Fp256::MIN, Fp256::MAX, Floor),
+        Fp256::ZERO.csub(Fp256::EPSILON)?
+    );
+
+    assert_eq!(a.to_decimal(0), (I256::from(-75), -1));
+    assert_eq!(a.to_decimal(-2), (I256::from(-750), -2));
+    assert_eq!(
+        "500".parse::<Fp256>()?.to_decimal(i32::MAX),
+        (I256::from(5), 2)
+    );
+    assert_eq!(Fp256::ZERO.to_decimal(5), (I256::from(0), 0));
+    assert_eq!(Fp256::from_decimal(I256::from(-75), -1)?, a);
+    assert_eq!(Fp256::from_decimal(I256::from(5), 2)?, "500".parse()?);
+    assert_eq!(
+        Fp256::from_decimal(I256::from(5), 11).unwrap_err().kind(),
+        ConvertErrorKind::UnsupportedExponent
+    );
+
+    assert_eq!(Fp256::from(true), Fp256::ONE);
+    assert_eq!(f64::from(a), -7.5);
+    assert_eq!(f64::from(Fp256::MAX), 5.78960446186581e46);
+    Ok(())
+}
+
+#[test]
+fn half_sum_ties() {
+    let fp = |ulps: i8| Fp256::from_bits(I256::from(ulps));
+    let modes = [Floor, Ceil, Nearest, HalfEven, TowardZero, AwayFromZero];
+    for (a, b, expected) in [
+        (-3, -2, [-3, -2, -3, -2, -2, -3]),
+        (-5, -6, [-6, -5, -6, -6, -5, -6]),
+        (-1, 0, [-1, 0, -1, 0, 0, -1]),
+        (-8, 3, [-3, -2, -3, -2, -2, -3]),
+        (1, 4, [2, 3, 3, 2, 2, 3]),
+        (5, 6, [5, 6, 6, 6, 5, 6]),
+    ] {
+        for (mode, expected) in modes.into_iter().zip(expected) {
+            assert_eq!(
+                Fp256::half_sum(fp(a), fp(b), mode),
+                fp(expected),
+                "{:?}",
+                mode
+            );
+            assert_eq!(
+                Fp256::half_sum(fp(b), fp(a), mode),
+                fp(expected),
+                "{:?}",
+                mode
+            );
+        }
+    }
+
+    let below_max = Fp256::MAX.csub(Fp256::EPSILON).unwrap();
+    assert_eq!(Fp256::half_sum(Fp256::MAX, below_max, Floor), below_max);
+    assert_eq!(Fp256::half_sum(Fp256::MAX, below_max, Ceil), Fp256::MAX);
+    let above_min = Fp256::MIN.cadd(Fp256::EPSILON).unwrap();
+    assert_eq!(
+        Fp256::half_sum(Fp256::MIN, above_min, TowardZero),
+        above_min
+    );
+    assert_eq!(
+        Fp256::half_sum(Fp256::MIN, above_min, AwayFromZero),
+        Fp256::MIN
+    );
+}